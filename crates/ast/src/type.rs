@@ -54,13 +54,21 @@ impl Type<'_> {
     pub fn is_nonnull(&self) -> bool {
         matches!(self, Type::NonNull(_))
     }
+    /// Returns the number of list wrappers in self.
+    pub fn list_depth(&self) -> usize {
+        match self {
+            Type::Named(_) => 0,
+            Type::NonNull(inner) => inner.r#type.list_depth(),
+            Type::List(inner) => inner.r#type.list_depth() + 1,
+        }
+    }
 }
 
 impl Display for Type<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Type::NonNull(inner) => write!(f, "{}!", &inner.r#type),
-            Type::List(inner) => write!(f, "[{}]", &inner.r#type),
+            Type::NonNull(inner) => write!(f, "{}!", inner.r#type),
+            Type::List(inner) => write!(f, "[{}]", inner.r#type),
             Type::Named(name) => write!(f, "{}", name.name.name),
         }
     }
@@ -2,6 +2,7 @@ use super::{
     base::{HasPos, Ident, Keyword, Pos},
     directive::Directive,
     operation::OperationType,
+    operation_ext::ImportDefinition,
     r#type::Type,
     value::{StringValue, Value},
 };
@@ -409,6 +410,8 @@ impl HasPos for InputObjectTypeExtension<'_> {
 #[derive(Clone, Debug)]
 pub struct TypeSystemOrExtensionDocument<'a> {
     pub definitions: Vec<TypeSystemDefinitionOrExtension<'a>>,
+    /// `#import` statements in the document.
+    pub imports: Vec<ImportDefinition<'a>>,
 }
 
 impl<'a> Extend<TypeSystemDefinitionOrExtension<'a>> for TypeSystemOrExtensionDocument<'a> {
@@ -420,8 +423,15 @@ impl<'a> Extend<TypeSystemDefinitionOrExtension<'a>> for TypeSystemOrExtensionDo
 impl TypeSystemOrExtensionDocument<'_> {
     /// Merges multiple documents into one.
     pub fn merge(docs: impl IntoIterator<Item = Self>) -> Self {
+        let mut definitions = vec![];
+        let mut imports = vec![];
+        for doc in docs {
+            definitions.extend(doc.definitions);
+            imports.extend(doc.imports);
+        }
         TypeSystemOrExtensionDocument {
-            definitions: docs.into_iter().flat_map(|doc| doc.definitions).collect(),
+            definitions,
+            imports,
         }
     }
 }
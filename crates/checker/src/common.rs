@@ -8,6 +8,7 @@ use nitrogql_ast::{
     variable::{Variable, VariableDefinition, VariablesDefinition},
 };
 use nitrogql_semantics::type_system_utils::convert_type;
+use nitrogql_utils::did_you_mean;
 
 use super::error::{CheckError, CheckErrorMessage};
 
@@ -24,6 +25,10 @@ pub fn check_directives<'src, S: Text<'src>>(
             None => result.push(
                 CheckErrorMessage::UnknownDirective {
                     name: d.name.to_string(),
+                    suggestions: did_you_mean(
+                        d.name.name,
+                        definitions.iter_directives().map(|(name, _)| name.as_ref()),
+                    ),
                 }
                 .with_pos(d.name.position),
             ),
@@ -64,6 +69,19 @@ pub fn check_directives<'src, S: Text<'src>>(
     }
 }
 
+/// Suggests type names that look like given unknown type name.
+pub fn type_name_suggestions<'src, S: Text<'src>>(
+    definitions: &Schema<S, Pos>,
+    name: &str,
+) -> Vec<String> {
+    did_you_mean(
+        name,
+        definitions
+            .iter_types()
+            .map(|(type_name, _)| type_name.as_ref()),
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn check_arguments<'src, S: Text<'src>>(
     definitions: &Schema<S, Pos>,
@@ -141,6 +159,12 @@ pub fn check_arguments<'src, S: Text<'src>>(
                         result.push(
                             CheckErrorMessage::UnknownArgument {
                                 name: arg_name.to_string(),
+                                suggestions: did_you_mean(
+                                    arg_name.name,
+                                    arguments_definition
+                                        .iter()
+                                        .map(|arg_def| arg_def.name.inner_ref().as_ref()),
+                                ),
                             }
                             .with_pos(arg_name.position),
                         );
@@ -201,6 +225,7 @@ pub fn check_value<'src, S: Text<'src>>(
                                 *expected_name.original_node_ref(),
                                 CheckErrorMessage::UnknownType {
                                     name: expected_name.to_string(),
+                                    suggestions: vec![],
                                 },
                             )]),
                     );
@@ -266,6 +291,13 @@ fn is_value_compatible_type_def<'src, S: Text<'src>>(
                         CheckErrorMessage::UnknownEnumMember {
                             member: enum_name.to_owned(),
                             r#enum: enum_def.name.to_string(),
+                            suggestions: did_you_mean(
+                                enum_name,
+                                enum_def
+                                    .members
+                                    .iter()
+                                    .map(|member| member.name.inner_ref().as_ref()),
+                            ),
                         }
                         .with_pos(value.position)
                         .with_additional_info(vec![(
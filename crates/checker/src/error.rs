@@ -3,7 +3,7 @@ use std::fmt::Display;
 use thiserror::Error;
 
 use nitrogql_ast::{base::Pos, operation::OperationType};
-use nitrogql_error::PositionedError;
+use nitrogql_error::{ErrorCategory, ErrorCode, PositionedError, codes};
 
 #[derive(Debug)]
 pub struct CheckError {
@@ -20,13 +20,27 @@ impl CheckError {
         self.additional_info.extend(infos);
         self
     }
+
+    /// Converts this error into a PositionedError categorized by where it was found,
+    /// since errors like unknown types may be found in both schema and operations.
+    /// Errors from plugins are always categorized as plugin errors.
+    pub fn into_positioned_error(self, category: ErrorCategory) -> PositionedError {
+        let category = match self.message {
+            CheckErrorMessage::Plugin { .. } => ErrorCategory::Plugin,
+            _ => category,
+        };
+        PositionedError::from(self).with_category(category)
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum CheckErrorMessage {
     // errors for both
     #[error("Directive '{name}' is not defined")]
-    UnknownDirective { name: String },
+    UnknownDirective {
+        name: String,
+        suggestions: Vec<String>,
+    },
     #[error("Directive '{name}' is not allowed for this location")]
     DirectiveLocationNotAllowed { name: String },
     #[error("Repeated application of directive '{name}' is not allowed")]
@@ -40,9 +54,16 @@ pub enum CheckErrorMessage {
     #[error("Variable '${name}' is not defined")]
     UnknownVariable { name: String },
     #[error("'{member}' is a member of enum {enum}")]
-    UnknownEnumMember { member: String, r#enum: String },
+    UnknownEnumMember {
+        member: String,
+        r#enum: String,
+        suggestions: Vec<String>,
+    },
     #[error("Argument '{name}' is not defined")]
-    UnknownArgument { name: String },
+    UnknownArgument {
+        name: String,
+        suggestions: Vec<String>,
+    },
     #[error("Field '{name}' is required")]
     RequiredFieldNotSpecified { name: String },
     #[error("Field '{name}' is not defined")]
@@ -53,7 +74,10 @@ pub enum CheckErrorMessage {
     #[error("Name '{name}' is duplicated")]
     DuplicatedName { name: String },
     #[error("Type '{name}' is not defined")]
-    UnknownType { name: String },
+    UnknownType {
+        name: String,
+        suggestions: Vec<String>,
+    },
     #[error("Directive '{name}' is recursing")]
     RecursingDirective { name: String },
     #[error("Output type '{name}' is not allowed here")]
@@ -94,6 +118,10 @@ pub enum CheckErrorMessage {
     OneOfFieldNotNullable,
     #[error("Field of @oneOf input object must not have a default value")]
     OneOfFieldWithDefaultValue,
+    #[error("Level {level} of @semanticNonNull does not exist in type '{type}'")]
+    SemanticNonNullLevelOutOfRange { level: String, r#type: String },
+    #[error("@brand cannot be applied to a field of non-scalar type '{name}'")]
+    BrandOnNonScalarType { name: String },
     // errors for operation
     #[error("Unnamed operation must be the only operation in this document")]
     UnNamedOperationMustBeSingle,
@@ -111,13 +139,17 @@ pub enum CheckErrorMessage {
     FieldNotFound {
         field_name: String,
         type_name: String,
+        suggestions: Vec<String>,
     },
     #[error("Duplicated variable name '{name}'")]
     DuplicatedVariableName { name: String },
     #[error("Type '{name}' is not a valid fragment target")]
     InvalidFragmentTarget { name: String },
     #[error("Fragment '{name}' is not defined")]
-    UnknownFragment { name: String },
+    UnknownFragment {
+        name: String,
+        suggestions: Vec<String>,
+    },
     #[error("Fragment condition '{condition}' never matches scope type '{scope}'")]
     FragmentConditionNeverMatches { condition: String, scope: String },
     #[error("Recursing application of fragment '{name}'")]
@@ -155,11 +187,184 @@ impl CheckErrorMessage {
             additional_info: vec![],
         }
     }
+
+    /// Returns the stable code of this error.
+    /// Messages only used as additional info have no code.
+    pub fn code(&self) -> Option<ErrorCode> {
+        use CheckErrorMessage::*;
+        let code = match self {
+            UnknownDirective { .. } => codes::UNKNOWN_DIRECTIVE,
+            DirectiveLocationNotAllowed { .. } => codes::DIRECTIVE_LOCATION_NOT_ALLOWED,
+            RepeatedDirective { .. } => codes::REPEATED_DIRECTIVE,
+            ArgumentsNotNeeded { .. } => codes::ARGUMENTS_NOT_NEEDED,
+            RequiredArgumentNotSpecified { .. } => codes::REQUIRED_ARGUMENT_NOT_SPECIFIED,
+            TypeMismatch { .. } => codes::TYPE_MISMATCH,
+            UnknownVariable { .. } => codes::UNKNOWN_VARIABLE,
+            UnknownEnumMember { .. } => codes::UNKNOWN_ENUM_MEMBER,
+            UnknownArgument { .. } => codes::UNKNOWN_ARGUMENT,
+            RequiredFieldNotSpecified { .. } => codes::REQUIRED_FIELD_NOT_SPECIFIED,
+            UnknownField { .. } => codes::UNKNOWN_FIELD,
+            UnscoUnsco => codes::RESERVED_NAME,
+            DuplicatedName { .. } => codes::DUPLICATED_NAME,
+            UnknownType { .. } => codes::UNKNOWN_TYPE,
+            RecursingDirective { .. } => codes::RECURSING_DIRECTIVE,
+            NoOutputType { .. } => codes::NO_OUTPUT_TYPE,
+            NoInputType { .. } => codes::NO_INPUT_TYPE,
+            NotInterface { .. } => codes::NOT_INTERFACE,
+            InterfaceNotImplemented { .. } => codes::INTERFACE_NOT_IMPLEMENTED,
+            NoImplementSelf => codes::NO_IMPLEMENT_SELF,
+            InterfaceFieldNotImplemented { .. } => codes::INTERFACE_FIELD_NOT_IMPLEMENTED,
+            FieldTypeMisMatchWithInterface { .. } => codes::FIELD_TYPE_MISMATCH_WITH_INTERFACE,
+            InterfaceArgumentNotImplemented { .. } => codes::INTERFACE_ARGUMENT_NOT_IMPLEMENTED,
+            ArgumentTypeMisMatchWithInterface { .. } => {
+                codes::ARGUMENT_TYPE_MISMATCH_WITH_INTERFACE
+            }
+            ArgumentTypeNonNullAgainstInterface { .. } => {
+                codes::ARGUMENT_TYPE_NON_NULL_AGAINST_INTERFACE
+            }
+            NonObjectTypeUnionMember { .. } => codes::NON_OBJECT_TYPE_UNION_MEMBER,
+            OneOfFieldNotNullable => codes::ONE_OF_FIELD_NOT_NULLABLE,
+            OneOfFieldWithDefaultValue => codes::ONE_OF_FIELD_WITH_DEFAULT_VALUE,
+            SemanticNonNullLevelOutOfRange { .. } => codes::SEMANTIC_NON_NULL_LEVEL_OUT_OF_RANGE,
+            BrandOnNonScalarType { .. } => codes::BRAND_ON_NON_SCALAR_TYPE,
+            UnNamedOperationMustBeSingle => codes::UNNAMED_OPERATION_MUST_BE_SINGLE,
+            DuplicateOperationName { .. } => codes::DUPLICATE_OPERATION_NAME,
+            DuplicateFragmentName { .. } => codes::DUPLICATE_FRAGMENT_NAME,
+            NoRootType { .. } => codes::NO_ROOT_TYPE,
+            SelectionOnInvalidType { .. } => codes::SELECTION_ON_INVALID_TYPE,
+            MustSpecifySelectionSet { .. } => codes::MUST_SPECIFY_SELECTION_SET,
+            FieldNotFound { .. } => codes::FIELD_NOT_FOUND,
+            DuplicatedVariableName { .. } => codes::DUPLICATED_VARIABLE_NAME,
+            InvalidFragmentTarget { .. } => codes::INVALID_FRAGMENT_TARGET,
+            UnknownFragment { .. } => codes::UNKNOWN_FRAGMENT,
+            FragmentConditionNeverMatches { .. } => codes::FRAGMENT_CONDITION_NEVER_MATCHES,
+            RecursingFragmentSpread { .. } => codes::RECURSING_FRAGMENT_SPREAD,
+            SubscriptionMustHaveExactlyOneRootField => {
+                codes::SUBSCRIPTION_MUST_HAVE_EXACTLY_ONE_ROOT_FIELD
+            }
+            OneOfInputNotExactlyOneField { .. } => codes::ONE_OF_INPUT_NOT_EXACTLY_ONE_FIELD,
+            OneOfInputNullValue { .. } => codes::ONE_OF_INPUT_NULL_VALUE,
+            OneOfInputNullableVariable { .. } => codes::ONE_OF_INPUT_NULLABLE_VARIABLE,
+            TypeSystemError => codes::TYPE_SYSTEM_ERROR,
+            AnotherDefinitionPos { .. } | DefinitionPos { .. } | RootTypesAreDefinedHere => {
+                return None;
+            }
+            Plugin { .. } => codes::PLUGIN_ERROR,
+        };
+        Some(code)
+    }
+
+    /// Returns structured data that describes this error.
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        use CheckErrorMessage::*;
+        match self {
+            UnknownDirective { name, .. }
+            | DirectiveLocationNotAllowed { name }
+            | RepeatedDirective { name }
+            | RequiredArgumentNotSpecified { name }
+            | UnknownVariable { name }
+            | UnknownArgument { name, .. }
+            | RequiredFieldNotSpecified { name }
+            | UnknownField { name }
+            | DuplicatedName { name }
+            | UnknownType { name, .. }
+            | RecursingDirective { name }
+            | NoOutputType { name }
+            | NoInputType { name }
+            | NotInterface { name }
+            | InterfaceNotImplemented { name }
+            | MustSpecifySelectionSet { name }
+            | DuplicatedVariableName { name }
+            | InvalidFragmentTarget { name }
+            | UnknownFragment { name, .. }
+            | RecursingFragmentSpread { name }
+            | OneOfInputNotExactlyOneField { name }
+            | OneOfInputNullableVariable { name }
+            | BrandOnNonScalarType { name } => vec![("name", name.clone())],
+            ArgumentsNotNeeded { kind } => vec![("kind", kind.to_string())],
+            TypeMismatch { r#type } => vec![("type", r#type.clone())],
+            UnknownEnumMember { member, r#enum, .. } => {
+                vec![("member", member.clone()), ("enum", r#enum.clone())]
+            }
+            InterfaceFieldNotImplemented {
+                field_name,
+                interface_name,
+            } => vec![
+                ("fieldName", field_name.clone()),
+                ("interfaceName", interface_name.clone()),
+            ],
+            InterfaceArgumentNotImplemented {
+                argument_name,
+                interface_name,
+            } => vec![
+                ("argumentName", argument_name.clone()),
+                ("interfaceName", interface_name.clone()),
+            ],
+            FieldTypeMisMatchWithInterface { interface_name }
+            | ArgumentTypeMisMatchWithInterface { interface_name }
+            | ArgumentTypeNonNullAgainstInterface { interface_name } => {
+                vec![("interfaceName", interface_name.clone())]
+            }
+            NonObjectTypeUnionMember { member_name } => {
+                vec![("memberName", member_name.clone())]
+            }
+            DuplicateOperationName { operation_type } | NoRootType { operation_type } => {
+                vec![("operationType", operation_type.as_str().to_owned())]
+            }
+            SelectionOnInvalidType { kind, name } => {
+                vec![("kind", kind.to_string()), ("name", name.clone())]
+            }
+            FieldNotFound {
+                field_name,
+                type_name,
+                ..
+            } => vec![
+                ("fieldName", field_name.clone()),
+                ("typeName", type_name.clone()),
+            ],
+            FragmentConditionNeverMatches { condition, scope } => {
+                vec![("condition", condition.clone()), ("scope", scope.clone())]
+            }
+            OneOfInputNullValue { field } => vec![("field", field.clone())],
+            SemanticNonNullLevelOutOfRange { level, r#type } => {
+                vec![("level", level.clone()), ("type", r#type.clone())]
+            }
+            AnotherDefinitionPos { name } | DefinitionPos { name } => {
+                vec![("name", name.clone())]
+            }
+            UnscoUnsco
+            | NoImplementSelf
+            | OneOfFieldNotNullable
+            | OneOfFieldWithDefaultValue
+            | UnNamedOperationMustBeSingle
+            | DuplicateFragmentName { .. }
+            | SubscriptionMustHaveExactlyOneRootField
+            | TypeSystemError
+            | RootTypesAreDefinedHere
+            | Plugin { .. } => vec![],
+        }
+    }
+
+    /// Returns names suggested as a fix for this error.
+    pub fn suggestions(&self) -> &[String] {
+        match self {
+            CheckErrorMessage::UnknownDirective { suggestions, .. }
+            | CheckErrorMessage::UnknownEnumMember { suggestions, .. }
+            | CheckErrorMessage::UnknownArgument { suggestions, .. }
+            | CheckErrorMessage::UnknownType { suggestions, .. }
+            | CheckErrorMessage::FieldNotFound { suggestions, .. }
+            | CheckErrorMessage::UnknownFragment { suggestions, .. } => suggestions,
+            _ => &[],
+        }
+    }
 }
 
 impl From<CheckError> for PositionedError {
     fn from(value: CheckError) -> Self {
-        PositionedError::new(
+        let code = value.message.code();
+        let fields = value.message.fields();
+        let suggestions = value.message.suggestions().to_vec();
+        let error = PositionedError::new(
             value.message.into(),
             Some(value.position),
             value
@@ -168,6 +373,12 @@ impl From<CheckError> for PositionedError {
                 .map(|(pos, err)| (pos, err.to_string()))
                 .collect(),
         )
+        .with_fields(fields)
+        .with_suggestions(suggestions);
+        match code {
+            Some(code) => error.with_code(code),
+            None => error,
+        }
     }
 }
 
@@ -19,11 +19,12 @@ use self::{
 };
 
 use super::{
-    common::{check_arguments, check_directives},
+    common::{check_arguments, check_directives, type_name_suggestions},
     error::{CheckError, CheckErrorMessage, TypeKind},
     types::inout_kind_of_type,
 };
 use nitrogql_semantics::direct_fields_of_output_type;
+use nitrogql_utils::did_you_mean;
 
 mod context;
 mod count_selection_set_fields;
@@ -41,10 +42,16 @@ pub fn check_operation_document<'src, S: Text<'src>>(
 
     let fragment_map = generate_fragment_map(document);
 
+    // Operations imported from other files are not counted.
     let operation_num = document
         .definitions
         .iter()
-        .filter(|def| matches!(def, ExecutableDefinition::OperationDefinition(_)))
+        .filter(|def| match def {
+            ExecutableDefinition::OperationDefinition(op) => {
+                op.position.file == document.position.file
+            }
+            ExecutableDefinition::FragmentDefinition(_) => false,
+        })
         .count();
 
     for (idx, def) in document.definitions.iter().enumerate() {
@@ -152,6 +159,7 @@ fn check_operation<'src, S: Text<'src>>(
             result.push(
                 CheckErrorMessage::UnknownType {
                     name: root_type_name.to_string(),
+                    suggestions: vec![],
                 }
                 .with_pos(op.position),
             );
@@ -211,6 +219,7 @@ fn check_fragment_definition<'src, S: Text<'src>>(
         result.push(
             CheckErrorMessage::UnknownType {
                 name: op.type_condition.name.to_owned(),
+                suggestions: type_name_suggestions(context.definitions, op.type_condition.name),
             }
             .with_pos(op.type_condition.position),
         );
@@ -260,6 +269,10 @@ fn check_variables_definition<'src, S: Text<'src>>(
                 result.push(
                     CheckErrorMessage::UnknownType {
                         name: v.r#type.unwrapped_type().name.to_string(),
+                        suggestions: type_name_suggestions(
+                            context.definitions,
+                            v.r#type.unwrapped_type().name.name,
+                        ),
                     }
                     .with_pos(*v.r#type.position()),
                 );
@@ -368,6 +381,15 @@ fn check_selection_field<'src, S: Text<'src>, F: Borrow<Field<S, Pos>>>(
             CheckErrorMessage::FieldNotFound {
                 field_name: field_selection.name.to_string(),
                 type_name: root_type_name.to_owned(),
+                suggestions: did_you_mean(
+                    selection_name,
+                    root_fields.iter().map(|field| {
+                        <F as Borrow<Field<_, _>>>::borrow(field)
+                            .name
+                            .inner_ref()
+                            .as_ref()
+                    }),
+                ),
             }
             .with_pos(*field_selection.name.position())
             .with_additional_info(vec![(
@@ -456,6 +478,10 @@ fn check_fragment_spread<'src, S: Text<'src>>(
         result.push(
             CheckErrorMessage::UnknownFragment {
                 name: fragment_spread.fragment_name.to_string(),
+                suggestions: did_you_mean(
+                    fragment_spread.fragment_name.name,
+                    fragment_map.keys().copied(),
+                ),
             }
             .with_pos(fragment_spread.fragment_name.position),
         );
@@ -504,6 +530,7 @@ fn check_inline_fragment<'src, S: Text<'src>>(
                 result.push(
                     CheckErrorMessage::UnknownType {
                         name: type_cond.name.to_owned(),
+                        suggestions: type_name_suggestions(context.definitions, type_cond.name),
                     }
                     .with_pos(type_cond.position),
                 );
@@ -1142,6 +1142,104 @@ mod one_of {
     }
 }
 
+mod suggestions {
+    use std::borrow::Cow;
+
+    use graphql_type_system::Schema;
+    use insta::assert_debug_snapshot;
+    use nitrogql_semantics::ast_to_type_system;
+
+    use nitrogql_ast::base::Pos;
+    use nitrogql_parser::parse_operation_document;
+
+    use super::{parse_to_type_system_document, test_check};
+
+    fn type_system() -> Schema<Cow<'static, str>, Pos> {
+        let doc = parse_to_type_system_document(
+            "
+            type Query {
+                user(userId: ID!, role: Role): User
+            }
+            type User {
+                id: ID!
+                userName: String!
+                email: String!
+            }
+            enum Role {
+                ADMIN
+                MEMBER
+            }
+        ",
+        );
+        ast_to_type_system(&doc)
+    }
+
+    #[test]
+    fn misspelled_field() {
+        let schema = type_system();
+        let doc = parse_operation_document(
+            "
+            query {
+                user(userId: \"1\") { usrName }
+            }
+        ",
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(test_check(schema, doc));
+    }
+
+    #[test]
+    fn misspelled_argument_and_enum_member() {
+        let schema = type_system();
+        let doc = parse_operation_document(
+            "
+            query {
+                user(userID: \"1\", role: ADMNI) { id }
+            }
+        ",
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(test_check(schema, doc));
+    }
+
+    #[test]
+    fn misspelled_type_fragment_and_directive() {
+        let schema = type_system();
+        let doc = parse_operation_document(
+            "
+            query {
+                user(userId: \"1\") @skipp(if: true) {
+                    ...UserFragmnt
+                }
+            }
+            fragment UserFragment on Usr {
+                id
+            }
+        ",
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(test_check(schema, doc));
+    }
+
+    #[test]
+    fn no_similar_name() {
+        let schema = type_system();
+        let doc = parse_operation_document(
+            "
+            query {
+                user(userId: \"1\") { somethingElse }
+            }
+        ",
+        )
+        .unwrap();
+
+        assert_debug_snapshot!(test_check(schema, doc));
+    }
+}
+
 fn parse_to_type_system_document(source: &str) -> TypeSystemDocument<'_> {
     let mut doc = parse_type_system_document(source).unwrap();
     doc.extend(generate_builtins());
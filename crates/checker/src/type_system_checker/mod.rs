@@ -1,9 +1,12 @@
 use nitrogql_ast::{
     base::{HasPos, Ident},
+    directive::Directive,
+    r#type::Type,
     type_system::{
-        ArgumentsDefinition, DirectiveDefinition, EnumTypeDefinition, InputObjectTypeDefinition,
-        InterfaceTypeDefinition, ObjectTypeDefinition, ScalarTypeDefinition, SchemaDefinition,
-        TypeDefinition, TypeSystemDefinition, TypeSystemDocument, UnionTypeDefinition,
+        ArgumentsDefinition, DirectiveDefinition, EnumTypeDefinition, FieldDefinition,
+        InputObjectTypeDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+        ScalarTypeDefinition, SchemaDefinition, TypeDefinition, TypeSystemDefinition,
+        TypeSystemDocument, UnionTypeDefinition,
     },
 };
 
@@ -12,11 +15,13 @@ use self::{
 };
 
 use super::{
-    common::check_directives,
+    common::{check_directives, type_name_suggestions},
     error::{CheckError, CheckErrorMessage},
     types::inout_kind_of_type,
 };
-use nitrogql_semantics::{DefinitionMap, generate_definition_map};
+use nitrogql_semantics::{
+    DefinitionMap, brand_of, generate_definition_map, semantic_non_null_levels,
+};
 
 mod check_directive_recursion;
 mod interfaces;
@@ -149,6 +154,8 @@ fn check_object(
             "FIELD_DEFINITION",
             result,
         );
+        check_semantic_non_null(f, result);
+        check_brand(&f.directives, &f.r#type, definitions, result);
 
         match inout_kind_of_type(
             &definitions.type_system,
@@ -169,6 +176,10 @@ fn check_object(
                 result.push(
                     CheckErrorMessage::UnknownType {
                         name: f.r#type.unwrapped_type().name.to_string(),
+                        suggestions: type_name_suggestions(
+                            &definitions.type_system,
+                            f.r#type.unwrapped_type().name.name,
+                        ),
                     }
                     .with_pos(*f.r#type.position()),
                 );
@@ -183,6 +194,7 @@ fn check_object(
             result.push(
                 CheckErrorMessage::UnknownType {
                     name: interface.name.to_owned(),
+                    suggestions: type_name_suggestions(&definitions.type_system, interface.name),
                 }
                 .with_pos(*interface.position()),
             );
@@ -244,9 +256,11 @@ fn check_interface(
             &definitions.type_system,
             None,
             &f.directives,
-            "FIELD",
+            "FIELD_DEFINITION",
             result,
         );
+        check_semantic_non_null(f, result);
+        check_brand(&f.directives, &f.r#type, definitions, result);
 
         if inout_kind_of_type(
             &definitions.type_system,
@@ -274,6 +288,10 @@ fn check_interface(
             result.push(
                 CheckErrorMessage::UnknownType {
                     name: other_interface.name.to_owned(),
+                    suggestions: type_name_suggestions(
+                        &definitions.type_system,
+                        other_interface.name,
+                    ),
                 }
                 .with_pos(*other_interface.position()),
             );
@@ -334,6 +352,7 @@ fn check_union(
                 result.push(
                     CheckErrorMessage::UnknownType {
                         name: member.name.to_owned(),
+                        suggestions: type_name_suggestions(&definitions.type_system, member.name),
                     }
                     .with_pos(member.position),
                 );
@@ -390,6 +409,47 @@ fn check_enum(
     }
 }
 
+fn check_semantic_non_null(field: &FieldDefinition, result: &mut Vec<CheckError>) {
+    let Some(levels) = semantic_non_null_levels(&field.directives) else {
+        return;
+    };
+    let max_level = field.r#type.list_depth();
+    for level in levels {
+        if level.level().is_none_or(|l| l > max_level) {
+            result.push(
+                CheckErrorMessage::SemanticNonNullLevelOutOfRange {
+                    level: level.value.to_owned(),
+                    r#type: field.r#type.to_string(),
+                }
+                .with_pos(level.position),
+            );
+        }
+    }
+}
+
+fn check_brand(
+    directives: &[Directive],
+    r#type: &Type,
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckError>,
+) {
+    let Some(brand) = brand_of(directives) else {
+        return;
+    };
+    let type_name = r#type.unwrapped_type().name.name;
+    // unknown types are reported elsewhere
+    if let Some(def) = definitions.type_system.get_type(type_name)
+        && !matches!(**def, graphql_type_system::TypeDefinition::Scalar(_))
+    {
+        result.push(
+            CheckErrorMessage::BrandOnNonScalarType {
+                name: type_name.to_owned(),
+            }
+            .with_pos(brand.position),
+        );
+    }
+}
+
 fn check_input_object(
     input: &InputObjectTypeDefinition,
     definitions: &DefinitionMap,
@@ -429,6 +489,7 @@ fn check_input_object(
             "INPUT_FIELD_DEFINITION",
             result,
         );
+        check_brand(&f.directives, &f.r#type, definitions, result);
 
         let type_is_not_input_type = inout_kind_of_type(
             &definitions.type_system,
@@ -440,6 +501,10 @@ fn check_input_object(
                 result.push(
                     CheckErrorMessage::UnknownType {
                         name: f.r#type.unwrapped_type().name.to_string(),
+                        suggestions: type_name_suggestions(
+                            &definitions.type_system,
+                            f.r#type.unwrapped_type().name.name,
+                        ),
                     }
                     .with_pos(*f.r#type.position()),
                 );
@@ -500,6 +565,10 @@ fn check_arguments_definition(
                 result.push(
                     CheckErrorMessage::UnknownType {
                         name: v.r#type.unwrapped_type().name.to_string(),
+                        suggestions: type_name_suggestions(
+                            &definitions.type_system,
+                            v.r#type.unwrapped_type().name.name,
+                        ),
                     }
                     .with_pos(*v.r#type.position()),
                 );
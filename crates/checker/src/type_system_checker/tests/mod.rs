@@ -506,6 +506,7 @@ mod objects {
                 },
                 message: UnknownType {
                     name: "AAAAA",
+                    suggestions: [],
                 },
                 additional_info: [],
             },
@@ -606,6 +607,7 @@ mod objects {
                 },
                 message: UnknownType {
                     name: "Input",
+                    suggestions: [],
                 },
                 additional_info: [],
             },
@@ -757,6 +759,7 @@ mod objects {
                 },
                 message: UnknownDirective {
                     name: "wow",
+                    suggestions: [],
                 },
                 additional_info: [],
             },
@@ -785,6 +788,7 @@ mod objects {
                 },
                 message: UnknownDirective {
                     name: "wow",
+                    suggestions: [],
                 },
                 additional_info: [],
             },
@@ -1142,6 +1146,36 @@ mod interfaces {
         ]
         "###);
     }
+    #[test]
+    fn field_directive_location() {
+        let doc = parse_to_type_system_document(
+            "
+            directive @def on FIELD_DEFINITION
+            directive @sel on FIELD
+            interface I {
+                a: Int! @def
+                b: Int! @sel
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @r###"
+        [
+            CheckError {
+                position: Pos {
+                    line: 5,
+                    column: 24,
+                    file: 0,
+                    builtin: false,
+                },
+                message: DirectiveLocationNotAllowed {
+                    name: "sel",
+                },
+                additional_info: [],
+            },
+        ]
+        "###);
+    }
 }
 
 #[cfg(test)]
@@ -1228,6 +1262,10 @@ mod unions {
                 },
                 message: UnknownType {
                     name: "C",
+                    suggestions: [
+                        "A",
+                        "B",
+                    ],
                 },
                 additional_info: [],
             },
@@ -1683,6 +1721,202 @@ mod one_of {
     }
 }
 
+#[cfg(test)]
+mod semantic_non_null {
+    use insta::assert_debug_snapshot;
+
+    use crate::type_system_checker::{
+        check_type_system_document, tests::parse_with_nitrogql_directives,
+    };
+
+    #[test]
+    fn valid_semantic_non_null() {
+        let doc = parse_with_nitrogql_directives(
+            "
+            type User {
+                name: String @semanticNonNull
+                friends: [User] @semanticNonNull(levels: [0, 1])
+                tags: [[String]!] @semanticNonNull(levels: [2])
+            }
+            interface Node {
+                id: ID @semanticNonNull(levels: [0])
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @"[]");
+    }
+
+    #[test]
+    fn level_exceeds_list_depth() {
+        let doc = parse_with_nitrogql_directives(
+            "
+            type User {
+                name: String @semanticNonNull(levels: [1])
+                friends: [User!] @semanticNonNull(levels: [0, 2])
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            CheckError {
+                position: Pos {
+                    line: 2,
+                    column: 55,
+                    file: 0,
+                    builtin: false,
+                },
+                message: SemanticNonNullLevelOutOfRange {
+                    level: "1",
+                    type: "String",
+                },
+                additional_info: [],
+            },
+            CheckError {
+                position: Pos {
+                    line: 3,
+                    column: 62,
+                    file: 0,
+                    builtin: false,
+                },
+                message: SemanticNonNullLevelOutOfRange {
+                    level: "2",
+                    type: "[User!]",
+                },
+                additional_info: [],
+            },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn negative_level() {
+        let doc = parse_with_nitrogql_directives(
+            "
+            interface Node {
+                id: ID @semanticNonNull(levels: [-1])
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            CheckError {
+                position: Pos {
+                    line: 2,
+                    column: 49,
+                    file: 0,
+                    builtin: false,
+                },
+                message: SemanticNonNullLevelOutOfRange {
+                    level: "-1",
+                    type: "ID",
+                },
+                additional_info: [],
+            },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn semantic_non_null_wrong_location() {
+        let doc = parse_with_nitrogql_directives(
+            "
+            input UserInput {
+                name: String @semanticNonNull
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            CheckError {
+                position: Pos {
+                    line: 2,
+                    column: 29,
+                    file: 0,
+                    builtin: false,
+                },
+                message: DirectiveLocationNotAllowed {
+                    name: "semanticNonNull",
+                },
+                additional_info: [],
+            },
+        ]
+        "#);
+    }
+}
+
+mod brand {
+    use insta::assert_debug_snapshot;
+
+    use crate::type_system_checker::{
+        check_type_system_document, tests::parse_with_nitrogql_directives,
+    };
+
+    #[test]
+    fn valid_brand() {
+        let doc = parse_with_nitrogql_directives(
+            "
+            scalar Date
+            type User {
+                id: ID! @brand(name: \"UserId\")
+                friendIds: [ID!]! @brand(name: \"UserId\")
+                createdAt: Date @brand(name: \"CreatedAt\")
+            }
+            input UserInput {
+                id: ID! @brand(name: \"UserId\")
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @"[]");
+    }
+
+    #[test]
+    fn brand_on_non_scalar_type() {
+        let doc = parse_with_nitrogql_directives(
+            "
+            enum Role { ADMIN USER }
+            type User {
+                role: Role @brand(name: \"Role\")
+                friends: [User!]! @brand(name: \"Friends\")
+            }
+        ",
+        );
+        let errors = check_type_system_document(&doc);
+        assert_debug_snapshot!(errors, @r#"
+        [
+            CheckError {
+                position: Pos {
+                    line: 3,
+                    column: 27,
+                    file: 0,
+                    builtin: false,
+                },
+                message: BrandOnNonScalarType {
+                    name: "Role",
+                },
+                additional_info: [],
+            },
+            CheckError {
+                position: Pos {
+                    line: 4,
+                    column: 34,
+                    file: 0,
+                    builtin: false,
+                },
+                message: BrandOnNonScalarType {
+                    name: "User",
+                },
+                additional_info: [],
+            },
+        ]
+        "#);
+    }
+}
+
 fn parse_to_type_system_document(source: &str) -> TypeSystemDocument<'_> {
     use graphql_builtins::generate_builtins;
 
@@ -1691,3 +1925,23 @@ fn parse_to_type_system_document(source: &str) -> TypeSystemDocument<'_> {
 
     resolve_schema_extensions(doc).unwrap()
 }
+
+/// Definitions of directives that the CLI adds in addition to the spec built-ins.
+const NITROGQL_DIRECTIVES: &str = "
+directive @semanticNonNull(levels: [Int] = [0]) on FIELD_DEFINITION
+directive @brand(name: String!) on FIELD_DEFINITION | INPUT_FIELD_DEFINITION
+";
+
+fn parse_with_nitrogql_directives(source: &str) -> TypeSystemDocument<'_> {
+    use graphql_builtins::generate_builtins;
+
+    let mut doc = parse_type_system_document(source).unwrap();
+    doc.extend(generate_builtins());
+    doc.extend(
+        parse_type_system_document(NITROGQL_DIRECTIVES)
+            .unwrap()
+            .definitions,
+    );
+
+    resolve_schema_extensions(doc).unwrap()
+}
@@ -1,17 +1,20 @@
 use nitrogql_ast::{
     TypeSystemDocument,
     base::{Ident, Keyword, Pos},
-    r#type::{NamedType, NonNullType, Type},
+    directive::Directive,
+    r#type::{ListType, NamedType, NonNullType, Type},
     type_system::{
-        ArgumentsDefinition, DirectiveDefinition, InputValueDefinition, ScalarTypeDefinition,
+        ArgumentsDefinition, DirectiveDefinition, FieldDefinition, InputObjectTypeDefinition,
+        InputValueDefinition, InterfaceTypeDefinition, ObjectTypeDefinition, ScalarTypeDefinition,
         TypeDefinition, TypeSystemDefinition, TypeSystemDefinitionOrExtension,
     },
+    value::{IntValue, ListValue, Value},
 };
 
 /// Build nitrogql-specific built-in definitions.
 pub fn nitrogql_builtins() -> Vec<TypeSystemDefinitionOrExtension<'static>> {
-    vec![TypeSystemDefinitionOrExtension::DirectiveDefinition(
-        DirectiveDefinition {
+    vec![
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(DirectiveDefinition {
             directive_keyword: keyword("directive"),
             position: Pos::builtin(),
             name: ident("nitrogql_ts_type"),
@@ -40,8 +43,57 @@ pub fn nitrogql_builtins() -> Vec<TypeSystemDefinitionOrExtension<'static>> {
             }),
             repeatable: None,
             locations: vec![ident("SCALAR")],
-        },
-    )]
+        }),
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(DirectiveDefinition {
+            directive_keyword: keyword("directive"),
+            position: Pos::builtin(),
+            name: ident("semanticNonNull"),
+            description: None,
+            arguments: Some(ArgumentsDefinition {
+                input_values: vec![InputValueDefinition {
+                    description: None,
+                    position: Pos::builtin(),
+                    name: ident("levels"),
+                    r#type: Type::List(Box::new(ListType {
+                        position: Pos::builtin(),
+                        r#type: Type::Named(NamedType { name: ident("Int") }),
+                    })),
+                    default_value: Some(Value::ListValue(ListValue {
+                        position: Pos::builtin(),
+                        values: vec![Value::IntValue(IntValue {
+                            position: Pos::builtin(),
+                            value: "0",
+                        })],
+                    })),
+                    directives: vec![],
+                }],
+            }),
+            repeatable: None,
+            locations: vec![ident("FIELD_DEFINITION")],
+        }),
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(DirectiveDefinition {
+            directive_keyword: keyword("directive"),
+            position: Pos::builtin(),
+            name: ident("brand"),
+            description: None,
+            arguments: Some(ArgumentsDefinition {
+                input_values: vec![InputValueDefinition {
+                    description: None,
+                    position: Pos::builtin(),
+                    name: ident("name"),
+                    r#type: Type::NonNull(Box::new(NonNullType {
+                        r#type: Type::Named(NamedType {
+                            name: ident("String"),
+                        }),
+                    })),
+                    default_value: None,
+                    directives: vec![],
+                }],
+            }),
+            repeatable: None,
+            locations: vec![ident("FIELD_DEFINITION"), ident("INPUT_FIELD_DEFINITION")],
+        }),
+    ]
 }
 
 fn ident(name: &str) -> Ident<'_> {
@@ -58,6 +110,10 @@ fn keyword(name: &str) -> Keyword<'_> {
     }
 }
 
+/// Directives that only affect generated types.
+/// They are removed from the schema given to servers together with their usages.
+const TYPE_ONLY_DIRECTIVES: &[&str] = &["nitrogql_ts_type", "brand"];
+
 /// Removes built-in definitions from the schema.
 pub fn remove_builtins<'src>(schema: &TypeSystemDocument<'src>) -> TypeSystemDocument<'src> {
     let definitions = schema
@@ -65,25 +121,91 @@ pub fn remove_builtins<'src>(schema: &TypeSystemDocument<'src>) -> TypeSystemDoc
         .iter()
         .cloned()
         .filter_map(|d| match d {
-            TypeSystemDefinition::DirectiveDefinition(def) => (def.name.name != "nitrogql_ts_type")
-                .then_some(TypeSystemDefinition::DirectiveDefinition(def)),
+            TypeSystemDefinition::DirectiveDefinition(def) => (!TYPE_ONLY_DIRECTIVES
+                .contains(&def.name.name))
+            .then_some(TypeSystemDefinition::DirectiveDefinition(def)),
             TypeSystemDefinition::SchemaDefinition(_) => Some(d),
-            TypeSystemDefinition::TypeDefinition(def) => {
-                if let TypeDefinition::Scalar(def) = def {
-                    return Some(TypeSystemDefinition::TypeDefinition(
-                        TypeDefinition::Scalar(ScalarTypeDefinition {
-                            directives: def
-                                .directives
-                                .into_iter()
-                                .filter(|d| d.name.name != "nitrogql_ts_type")
-                                .collect(),
-                            ..def
-                        }),
-                    ));
-                }
-                Some(TypeSystemDefinition::TypeDefinition(def))
-            }
+            TypeSystemDefinition::TypeDefinition(def) => Some(
+                TypeSystemDefinition::TypeDefinition(remove_type_only_directives(def)),
+            ),
         })
         .collect();
     TypeSystemDocument { definitions }
 }
+
+/// Removes usages of type-only directives from given type definition.
+fn remove_type_only_directives(def: TypeDefinition) -> TypeDefinition {
+    fn filter(directives: Vec<Directive>) -> Vec<Directive> {
+        directives
+            .into_iter()
+            .filter(|d| !TYPE_ONLY_DIRECTIVES.contains(&d.name.name))
+            .collect()
+    }
+    fn filter_field(field: FieldDefinition) -> FieldDefinition {
+        FieldDefinition {
+            directives: filter(field.directives),
+            ..field
+        }
+    }
+    fn filter_input_value(value: InputValueDefinition) -> InputValueDefinition {
+        InputValueDefinition {
+            directives: filter(value.directives),
+            ..value
+        }
+    }
+    match def {
+        TypeDefinition::Scalar(def) => TypeDefinition::Scalar(ScalarTypeDefinition {
+            directives: filter(def.directives),
+            ..def
+        }),
+        TypeDefinition::Object(def) => TypeDefinition::Object(ObjectTypeDefinition {
+            fields: def.fields.into_iter().map(filter_field).collect(),
+            ..def
+        }),
+        TypeDefinition::Interface(def) => TypeDefinition::Interface(InterfaceTypeDefinition {
+            fields: def.fields.into_iter().map(filter_field).collect(),
+            ..def
+        }),
+        TypeDefinition::InputObject(def) => {
+            TypeDefinition::InputObject(InputObjectTypeDefinition {
+                fields: def.fields.into_iter().map(filter_input_value).collect(),
+                ..def
+            })
+        }
+        def => def,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nitrogql_parser::parse_type_system_document;
+    use nitrogql_printer::GraphQLPrinter;
+    use nitrogql_semantics::resolve_schema_extensions;
+    use sourcemap_writer::JustWriter;
+
+    use super::{nitrogql_builtins, remove_builtins};
+
+    #[test]
+    fn remove_type_only_directives() {
+        let mut doc = parse_type_system_document(
+            r#"
+            scalar Date @nitrogql_ts_type(resolverInput: "string", resolverOutput: "string", operationInput: "string", operationOutput: "string")
+            type User {
+                id: ID! @brand(name: "UserId")
+                name: String @semanticNonNull
+            }
+            input UserInput {
+                id: ID! @brand(name: "UserId")
+            }
+            "#,
+        )
+        .unwrap();
+        doc.extend(nitrogql_builtins());
+        let doc = resolve_schema_extensions(doc).unwrap();
+        let mut printed = String::new();
+        remove_builtins(&doc).print_graphql(&mut JustWriter::new(&mut printed));
+        assert!(!printed.contains("nitrogql_ts_type"));
+        assert!(!printed.contains("brand"));
+        assert!(printed.contains("name: String @semanticNonNull"));
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
 
@@ -14,18 +14,23 @@ use nitrogql_checker::{
     CheckError, CheckErrorMessage, OperationCheckContext, check_operation_document,
     check_type_system_document,
 };
-use nitrogql_error::{PositionedError, Result};
+use nitrogql_error::{ErrorCategory, PositionedError, Result};
 use nitrogql_plugin::Plugin;
+use nitrogql_printer::OperationJSPrinterOptions;
 use nitrogql_semantics::{
-    OperationExtension, OperationResolver, ast_to_type_system, resolve_operation_extensions,
-    resolve_operation_imports, resolve_schema_extensions,
+    ImportCheckOptions, ImportPathError, ImportPathResolver, OperationExtension, OperationResolver,
+    ReexportOptions, ast_to_type_system, check_operation_imports, check_reexported_operations,
+    resolve_operation_extensions, resolve_operation_imports, resolve_schema_extensions,
 };
 
-use crate::{output::InputFileKind, schema_loader::LoadedSchema};
+use crate::{
+    file_store::FileKind, import_loader::FileSystemHost, output::InputFileKind,
+    schema_loader::LoadedSchema,
+};
 
 use super::{CliContext, error::CliError};
 
-pub fn run_check(context: CliContext) -> Result<CliContext> {
+pub fn run_check<'a, 'src>(context: CliContext<'a, 'src>) -> Result<CliContext<'a, 'src>> {
     debug!("Checking");
     match context {
         CliContext::SchemaUnresolved {
@@ -36,15 +41,43 @@ pub fn run_check(context: CliContext) -> Result<CliContext> {
             output,
         } => {
             output.command_run("check".to_owned());
+            // Files outside `documents` are not checked for unused imports.
+            let imported_files = operations
+                .iter()
+                .map(|(_, _, file_by_index)| *file_by_index)
+                .filter(|file_by_index| file_store[*file_by_index].2 == FileKind::ImportedOperation)
+                .collect::<HashSet<_>>();
+            let reexport_options = operations
+                .iter()
+                .filter(|(_, _, file_by_index)| !imported_files.contains(file_by_index))
+                .map(|(path, _, file_by_index)| {
+                    let generate = config.config.generate_for_file(&config.root_dir, path);
+                    let options = OperationJSPrinterOptions::from_config(&generate).base_options;
+                    (
+                        *file_by_index,
+                        ReexportOptions {
+                            default_export_for_operation: options.default_export_for_operation,
+                            named_export_for_operation: options.named_export_for_operation,
+                        },
+                    )
+                })
+                .collect();
             let result = check_impl(CheckImplInput {
                 schema,
                 operations,
                 plugins: &config.plugins,
+                import_path_resolver: &config.import_path_resolver(),
+                import_check_options: ImportCheckOptions {
+                    no_import_cycles: config.config.check.no_import_cycles,
+                    no_unused_imports: config.config.check.no_unused_imports,
+                },
+                imported_files,
+                reexport_options,
             });
             match result {
                 CheckImplOutput::Ok { schema, operations } => {
                     info!("Check succeeded");
-                    eprintln!("'check' finished");
+                    output.command_finished("check".to_owned());
                     Ok(CliContext::SchemaResolved {
                         schema: *schema,
                         operations,
@@ -70,6 +103,12 @@ struct CheckImplInput<'src, 'a> {
     pub schema: LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
     pub operations: Vec<(PathBuf, OperationDocumentExt<'src>, usize)>,
     pub plugins: &'a [Plugin<'src>],
+    pub import_path_resolver: &'a ImportPathResolver,
+    pub import_check_options: ImportCheckOptions,
+    /// Indices of files that are loaded only because they are imported.
+    pub imported_files: HashSet<usize>,
+    /// Options of generated code for each file that is not in `imported_files`.
+    pub reexport_options: HashMap<usize, ReexportOptions>,
 }
 
 enum CheckImplOutput<'src> {
@@ -92,6 +131,10 @@ fn check_impl<'src>(input: CheckImplInput<'src, '_>) -> CheckImplOutput<'src> {
         schema,
         operations,
         plugins,
+        import_path_resolver,
+        import_check_options,
+        imported_files,
+        reexport_options,
     } = input;
 
     let loaded_schema = match resolve_schema(schema, plugins) {
@@ -107,8 +150,14 @@ fn check_impl<'src>(input: CheckImplInput<'src, '_>) -> CheckImplOutput<'src> {
     };
     let schema = loaded_schema.map_into(|doc| Cow::Owned(ast_to_type_system(doc)), Cow::Borrowed);
 
-    let operations = match resolve_operations(operations) {
-        Ok(operations) => operations,
+    let (operations, import_errors) = match resolve_operations(
+        operations,
+        import_path_resolver,
+        import_check_options,
+        &imported_files,
+        &reexport_options,
+    ) {
+        Ok(result) => result,
         Err(errors) => {
             return CheckImplOutput::Err {
                 errors: errors
@@ -120,20 +169,21 @@ fn check_impl<'src>(input: CheckImplInput<'src, '_>) -> CheckImplOutput<'src> {
     };
 
     let context = OperationCheckContext::new(&schema);
-    let errors = operations
-        .iter()
-        .flat_map(|(_, doc, _, file_by_index)| {
-            check_operation_document(doc, &context)
-                .into_iter()
-                .map(move |err| (err, file_by_index))
-        })
-        .collect::<Vec<_>>();
+    let errors = import_errors
+        .into_iter()
+        .chain(
+            operations
+                .iter()
+                .flat_map(|(_, doc, _, _)| check_operation_document(doc, &context))
+                .map(|err| err.into_positioned_error(ErrorCategory::Operation)),
+        )
+        .collect::<Vec<PositionedError>>();
 
     if !errors.is_empty() {
         CheckImplOutput::Err {
             errors: errors
                 .into_iter()
-                .map(|(err, _)| (InputFileKind::Operation, err.into()))
+                .map(|err| (InputFileKind::Operation, err))
                 .collect(),
         }
     } else {
@@ -179,7 +229,10 @@ fn resolve_schema<'src>(
             }
 
             if !errors.is_empty() {
-                return Err(errors.into_iter().map(|err| err.into()).collect());
+                return Err(errors
+                    .into_iter()
+                    .map(|err| err.into_positioned_error(ErrorCategory::Schema))
+                    .collect());
             }
             Ok(LoadedSchema::GraphQL(resolved))
         }
@@ -187,19 +240,27 @@ fn resolve_schema<'src>(
     }
 }
 
+/// Resolved operations and errors found in `#import` statements.
 type ResolveOperationsResult<'src> = std::result::Result<
-    Vec<(
-        PathBuf,
-        OperationDocument<'src>,
-        OperationExtension<'src>,
-        usize,
-    )>,
+    (
+        Vec<(
+            PathBuf,
+            OperationDocument<'src>,
+            OperationExtension<'src>,
+            usize,
+        )>,
+        Vec<PositionedError>,
+    ),
     Vec<PositionedError>,
 >;
 
-fn resolve_operations(
-    operations: Vec<(PathBuf, OperationDocumentExt, usize)>,
-) -> ResolveOperationsResult {
+fn resolve_operations<'src>(
+    operations: Vec<(PathBuf, OperationDocumentExt<'src>, usize)>,
+    import_path_resolver: &ImportPathResolver,
+    import_check_options: ImportCheckOptions,
+    imported_files: &HashSet<usize>,
+    reexport_options: &HashMap<usize, ReexportOptions>,
+) -> ResolveOperationsResult<'src> {
     let (operations, resolve_errors): (Vec<_>, Vec<_>) = operations
         .into_iter()
         .map(|(path, doc, file_by_index)| -> std::result::Result<_, _> {
@@ -212,8 +273,8 @@ fn resolve_operations(
         return Err(resolve_errors);
     }
 
-    let operation_resolver = Operations::new(&operations);
-    let (operations, resolve_errors): (Vec<_>, Vec<_>) = operations
+    let operation_resolver = Operations::new(&operations, import_path_resolver);
+    let (resolved_operations, resolve_errors): (Vec<_>, Vec<_>) = operations
         .iter()
         .map(
             |(path, doc, ext, file_by_index)| -> std::result::Result<_, _> {
@@ -225,11 +286,29 @@ fn resolve_operations(
     if !resolve_errors.is_empty() {
         return Err(resolve_errors);
     }
-    Ok(operations)
+    let mut import_errors = check_operation_imports(
+        operations
+            .iter()
+            .filter(|(_, _, _, file_by_index)| !imported_files.contains(file_by_index))
+            .map(|(path, doc, ext, _)| (path.as_path(), doc, ext)),
+        &operation_resolver,
+        import_check_options,
+    );
+    for (path, _, ext, file_by_index) in operations.iter() {
+        if let Some(options) = reexport_options.get(file_by_index) {
+            import_errors.extend(check_reexported_operations(
+                (path, ext),
+                &operation_resolver,
+                *options,
+            ));
+        }
+    }
+    Ok((resolved_operations, import_errors))
 }
 
 struct Operations<'a, 'src> {
     file_by_path: HashMap<&'a Path, (&'a OperationDocument<'src>, &'a OperationExtension<'src>)>,
+    import_path_resolver: &'a ImportPathResolver,
 }
 
 impl<'a, 'src> Operations<'a, 'src> {
@@ -240,12 +319,16 @@ impl<'a, 'src> Operations<'a, 'src> {
             OperationExtension<'src>,
             usize,
         )],
+        import_path_resolver: &'a ImportPathResolver,
     ) -> Self {
         let file_by_path = operations
             .iter()
             .map(|(path, doc, ext, _)| (path.as_path(), (doc, ext)))
             .collect();
-        Self { file_by_path }
+        Self {
+            file_by_path,
+            import_path_resolver,
+        }
     }
 }
 
@@ -256,4 +339,13 @@ impl<'src> OperationResolver<'src> for Operations<'_, 'src> {
     ) -> Option<(&OperationDocument<'src>, &OperationExtension<'src>)> {
         self.file_by_path.get(path).copied()
     }
+
+    fn resolve_import_path(
+        &self,
+        from_file: &Path,
+        import_path: &str,
+    ) -> std::result::Result<PathBuf, ImportPathError> {
+        self.import_path_resolver
+            .resolve(from_file, import_path, &FileSystemHost)
+    }
 }
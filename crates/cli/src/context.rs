@@ -7,18 +7,18 @@ use nitrogql_ast::{
 };
 use nitrogql_config_file::Config;
 use nitrogql_plugin::Plugin;
-use nitrogql_semantics::OperationExtension;
+use nitrogql_semantics::{ImportPathResolver, OperationExtension};
 use thiserror::Error;
 
 use crate::{file_store::FileStore, output::CliOutput, schema_loader::LoadedSchema};
 
-pub enum CliContext<'src> {
+pub enum CliContext<'a, 'src> {
     SchemaUnresolved {
         config: CliConfig<'src>,
         schema: LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
         operations: Vec<(PathBuf, OperationDocumentExt<'src>, usize)>,
-        file_store: &'src mut FileStore,
-        output: &'src mut CliOutput,
+        file_store: &'a FileStore<'src>,
+        output: &'a mut CliOutput,
     },
     SchemaResolved {
         config: CliConfig<'src>,
@@ -29,8 +29,8 @@ pub enum CliContext<'src> {
             OperationExtension<'src>,
             usize,
         )>,
-        file_store: &'src FileStore,
-        output: &'src mut CliOutput,
+        file_store: &'a FileStore<'src>,
+        output: &'a mut CliOutput,
     },
 }
 
@@ -43,11 +43,19 @@ pub struct CliConfig<'file_store> {
     pub plugins: Vec<Plugin<'file_store>>,
 }
 
+impl CliConfig<'_> {
+    /// Creates a resolver of paths in `#import`.
+    pub fn import_path_resolver(&self) -> ImportPathResolver {
+        ImportPathResolver::new(self.root_dir.clone(), &self.config.paths)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum OutputFormat {
     Human,
     Json,
     Rdjson,
+    Sarif,
 }
 
 #[derive(Debug, Error)]
@@ -62,6 +70,7 @@ impl FromStr for OutputFormat {
             "human" => Ok(OutputFormat::Human),
             "json" => Ok(OutputFormat::Json),
             "rdjson" => Ok(OutputFormat::Rdjson),
+            "sarif" => Ok(OutputFormat::Sarif),
             s => Err(FromStrError(s.to_owned())),
         }
     }
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use nitrogql_error::{ErrorCode, codes};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -26,6 +27,38 @@ pub enum CliError {
     FailedToCalculateSourceMapFileName { path: PathBuf },
     #[error("{0}")]
     GlobError(String),
+    #[error("Pattern '{0}' matches no files")]
+    PatternMatchesNoFiles(String),
+    #[error("Unknown error code '{0}'")]
+    UnknownErrorCode(String),
     #[error("Command not successful: {0}")]
     CommandNotSuccessful(String),
+    #[error("No project is defined in the config file")]
+    NoProjectDefined,
+    #[error("Project '{name}' is not found in the config file. Available projects: {available}")]
+    ProjectNotFound { name: String, available: String },
+}
+
+impl CliError {
+    /// Returns the error code of this error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            CliError::NoCommandSpecified => codes::NO_COMMAND_SPECIFIED,
+            CliError::UnknownCommand(_) => codes::UNKNOWN_COMMAND,
+            CliError::InvalidCommand(_) => codes::INVALID_COMMAND,
+            CliError::CannotLoadPlugin(_) => codes::CANNOT_LOAD_PLUGIN,
+            CliError::NoSchemaSpecified => codes::NO_SCHEMA_SPECIFIED,
+            CliError::IntrospectionOnce => codes::INTROSPECTION_ONCE,
+            CliError::MixGraphQLAndIntrospection => codes::MIX_GRAPHQL_AND_INTROSPECTION,
+            CliError::OptionRequired { .. } => codes::OPTION_REQUIRED,
+            CliError::CannotEmitRuntimeToDts => codes::CANNOT_EMIT_RUNTIME_TO_DTS,
+            CliError::FailedToCalculateSourceMapFileName { .. } => codes::SOURCE_MAP_FILE_NAME,
+            CliError::GlobError(_) => codes::INVALID_GLOB,
+            CliError::PatternMatchesNoFiles(_) => codes::PATTERN_MATCHES_NO_FILES,
+            CliError::UnknownErrorCode(_) => codes::UNKNOWN_ERROR_CODE,
+            CliError::CommandNotSuccessful(_) => codes::COMMAND_NOT_SUCCESSFUL,
+            CliError::NoProjectDefined => codes::NO_PROJECT_DEFINED,
+            CliError::ProjectNotFound { .. } => codes::PROJECT_NOT_FOUND,
+        }
+    }
 }
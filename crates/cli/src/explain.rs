@@ -0,0 +1,24 @@
+use nitrogql_error::{ErrorCode, ErrorCodeInfo};
+
+use crate::{error::CliError, output::CliOutput};
+
+/// Looks up explanations of given error codes.
+pub fn run_explain(codes: &[String], output: &mut CliOutput) -> Result<(), CliError> {
+    if codes.is_empty() {
+        return Err(CliError::OptionRequired {
+            option: "<code>".to_owned(),
+            command: "explain".to_owned(),
+        });
+    }
+    let infos = codes
+        .iter()
+        .map(|code| {
+            code.parse::<ErrorCode>()
+                .ok()
+                .and_then(|code| code.info())
+                .ok_or_else(|| CliError::UnknownErrorCode(code.clone()))
+        })
+        .collect::<Result<Vec<&'static ErrorCodeInfo>, _>>()?;
+    output.explained(infos);
+    Ok(())
+}
@@ -1,25 +1,37 @@
 use std::{
+    fmt::Debug,
     ops::Index,
     path::{Path, PathBuf},
 };
 
+use typed_arena::Arena;
+
+/// Arena that owns the contents of loaded files.
+/// Files borrowed from a FileStore are valid as long as the arena lives.
+pub type SourceArena = Arena<String>;
+
 /// Struct that holds files loaded by CLI.
-#[derive(Debug)]
-pub struct FileStore {
-    schema_files: Vec<(PathBuf, &'static str, FileKind)>,
-    operation_files: Vec<(PathBuf, &'static str, FileKind)>,
+pub struct FileStore<'src> {
+    sources: &'src SourceArena,
+    schema_files: Vec<(PathBuf, &'src str, FileKind)>,
+    operation_files: Vec<(PathBuf, &'src str, FileKind)>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum FileKind {
     Schema,
     Operation,
+    /// Operation file that is not in `documents` but imported from another operation.
+    ImportedOperation,
+    /// Config file. Only added for reporting errors in it.
+    Config,
 }
 
-impl FileStore {
-    /// Create a new file store.
-    pub fn new() -> Self {
+impl<'src> FileStore<'src> {
+    /// Create a new file store whose contents are owned by given arena.
+    pub fn new(sources: &'src SourceArena) -> Self {
         Self {
+            sources,
             schema_files: vec![],
             operation_files: vec![],
         }
@@ -30,26 +42,25 @@ impl FileStore {
     /// Once the index is issued, it will not change.
     /// After an operation is added, schema cannot be added.
     pub fn add_file(&mut self, path: PathBuf, content: String, kind: FileKind) -> usize {
-        if !self.operation_files.is_empty() && kind == FileKind::Schema {
+        if !self.operation_files.is_empty() && matches!(kind, FileKind::Schema | FileKind::Config) {
             panic!("Cannot add schema file after operation file is added");
         }
         let schema_len = self.schema_files.len();
+        let content = self.sources.alloc(content).as_str();
         match kind {
-            FileKind::Schema => {
-                self.schema_files
-                    .push((path, Box::leak(content.into_boxed_str()), kind));
+            FileKind::Schema | FileKind::Config => {
+                self.schema_files.push((path, content, kind));
                 schema_len
             }
-            FileKind::Operation => {
-                self.operation_files
-                    .push((path, Box::leak(content.into_boxed_str()), kind));
+            FileKind::Operation | FileKind::ImportedOperation => {
+                self.operation_files.push((path, content, kind));
                 schema_len + self.operation_files.len() - 1
             }
         }
     }
 
     /// Get a file by index.
-    pub fn get_file(&self, index: usize) -> Option<&(PathBuf, &'static str, FileKind)> {
+    pub fn get_file(&self, index: usize) -> Option<&(PathBuf, &'src str, FileKind)> {
         if index < self.schema_files.len() {
             self.schema_files.get(index)
         } else {
@@ -58,7 +69,7 @@ impl FileStore {
     }
 
     /// Iterate over all files.
-    pub fn iter(&self) -> impl Iterator<Item = (usize, (&Path, &'static str, FileKind))> {
+    pub fn iter(&self) -> impl Iterator<Item = (usize, (&Path, &'src str, FileKind))> {
         self.schema_files
             .iter()
             .chain(self.operation_files.iter())
@@ -75,8 +86,17 @@ impl FileStore {
     }
 }
 
-impl Index<usize> for FileStore {
-    type Output = (PathBuf, &'static str, FileKind);
+impl Debug for FileStore<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStore")
+            .field("schema_files", &self.schema_files)
+            .field("operation_files", &self.operation_files)
+            .finish()
+    }
+}
+
+impl<'src> Index<usize> for FileStore<'src> {
+    type Output = (PathBuf, &'src str, FileKind);
 
     fn index(&self, index: usize) -> &Self::Output {
         self.get_file(index).expect("File index out of range")
@@ -4,25 +4,29 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use log::{debug, info};
-use nitrogql_semantics::{ast_to_type_system, type_system_to_ast};
+use nitrogql_semantics::{add_typename, ast_to_type_system, type_system_to_ast};
 
 use crate::builtins::remove_builtins;
 use crate::error::CliError;
 use crate::file_store::{FileKind, FileStore};
 use crate::output::{CliOutput, OutputFileKind};
 use crate::schema_loader::LoadedSchema;
-use nitrogql_config_file::{Config, GenerateMode};
-use nitrogql_error::Result;
+use nitrogql_config_file::{GenerateConfig, GenerateMode};
+use nitrogql_error::{PositionedError, Result, codes};
 use nitrogql_printer::{
-    GraphQLPrinter, OperationTypePrinterOptions, ResolverTypePrinter, ResolverTypePrinterOptions,
-    SchemaTypePrinter, SchemaTypePrinterOptions, print_types_for_operation_document,
+    ClientPresetDocument, ClientPresetError, GraphQLPrinter, OperationTypePrinterOptions,
+    ResolverTypePrinter, ResolverTypePrinterOptions, SchemaTypePrinter, SchemaTypePrinterOptions,
+    ValidatorOperationDocument, ValidatorPrinter, ValidatorPrinterError, ValidatorPrinterOptions,
+    print_client_preset_module, print_types_for_operation_document,
 };
 use nitrogql_utils::relative_path;
-use sourcemap_writer::{JsStringWriter, SourceWriter, SourceWriterBuffers, print_source_map_json};
+use sourcemap_writer::{
+    JsStringWriter, JustWriter, SourceWriter, SourceWriterBuffers, print_source_map_json,
+};
 
 use super::{check::run_check, context::CliContext};
 
-pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
+pub fn run_generate<'a, 'src>(mut context: CliContext<'a, 'src>) -> Result<CliContext<'a, 'src>> {
     if let CliContext::SchemaUnresolved { .. } = context {
         // Seems like check is not run
         context = run_check(context)?;
@@ -53,7 +57,7 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                 .as_ref()
                 .map(|schema_output| config.root_dir.join(schema_output));
             // If output contains runtime, prevent .d.ts from being generated
-            if config.config.generate.emit_schema_runtime
+            if config.config.generate.schema_has_runtime()
                 && schema_output
                     .as_ref()
                     .and_then(|schema_output| schema_output.file_name())
@@ -215,10 +219,99 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                 )?;
             }
 
+            if let Some(validators_output) = config
+                .config
+                .generate
+                .validators_output
+                .as_ref()
+                .map(|validators_output| config.root_dir.join(validators_output))
+            {
+                info!("Processing validators");
+                let mut options = ValidatorPrinterOptions::from_config(&config.config);
+                options.schema_source = config
+                    .config
+                    .generate
+                    .schema_module_specifier
+                    .clone()
+                    .unwrap_or_else(|| {
+                        path_to_ts(relative_path(
+                            validators_output.as_ref(),
+                            schema_output
+                                .as_ref()
+                                .expect("This should be prevented by config validation"),
+                        ))
+                        .to_string_lossy()
+                        .to_string()
+                    });
+                // Validators are generated only for variables of files in `documents`
+                let generate_configs = operations
+                    .iter()
+                    .filter(|(_, _, _, file_index)| {
+                        file_store[*file_index].2 != FileKind::ImportedOperation
+                    })
+                    .map(|(path, doc, _, _)| {
+                        (doc, config.config.generate_for_file(&config.root_dir, path))
+                    })
+                    .collect::<Vec<_>>();
+                let documents = generate_configs
+                    .iter()
+                    .map(|(doc, generate)| ValidatorOperationDocument {
+                        document: doc,
+                        generate: generate.as_ref(),
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut buffer = String::new();
+                let mut writer = JustWriter::new(&mut buffer);
+                let mut printer = ValidatorPrinter::new(options, &mut writer);
+                match schema {
+                    LoadedSchema::GraphQL(ref schema) => {
+                        printer
+                            .print_document(schema, &documents)
+                            .map_err(validator_printer_error)?;
+                    }
+                    LoadedSchema::Introspection(ref schema) => {
+                        let ast = type_system_to_ast(schema);
+                        printer
+                            .print_document(&ast, &documents)
+                            .map_err(validator_printer_error)?;
+                    }
+                }
+                drop(writer);
+                write_file_without_sourcemap(
+                    output,
+                    OutputFileKind::Validators,
+                    &validators_output,
+                    buffer,
+                )?;
+            }
+
             let mapped_schema =
                 schema.map_into(|doc| Cow::Owned(ast_to_type_system(doc)), Cow::Borrowed);
-
-            for (path, doc, _, file_index) in operations.iter() {
+            let root_types = mapped_schema.root_types().unwrap_or_default();
+            let root_type_names = [
+                &**root_types.query_type,
+                &**root_types.mutation_type,
+                &**root_types.subscription_type,
+            ];
+            // documents that go into the client preset module
+            let mut client_preset_documents = vec![];
+
+            for (path, doc, extension, file_index) in operations.iter() {
+                if file_store[*file_index].2 == FileKind::ImportedOperation {
+                    // Types are generated only for files in `documents`
+                    continue;
+                }
+                let generate = config.config.generate_for_file(&config.root_dir, path);
+                let doc = if generate.add_typename {
+                    Cow::Owned(add_typename(doc.clone(), &root_type_names))
+                } else {
+                    Cow::Borrowed(doc)
+                };
+                if generate.mode == GenerateMode::ClientPreset {
+                    client_preset_documents.push((*file_index, doc, generate));
+                    continue;
+                }
                 debug!("Processing {}", path.to_string_lossy());
                 let file_map = FileMap {
                     file_store,
@@ -238,10 +331,11 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
 
                 let decl_file_path = {
                     let mut path = path.clone();
-                    path.set_extension(match config.config.generate.mode {
+                    path.set_extension(match generate.mode {
                         GenerateMode::WithLoaderTS5_0 => "d.graphql.ts",
                         GenerateMode::WithLoaderTS4_0 => "graphql.d.ts",
                         GenerateMode::StandaloneTS4_0 => "graphql.ts",
+                        GenerateMode::ClientPreset => unreachable!(),
                     });
                     path
                 };
@@ -249,7 +343,7 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                 let mut writer = SourceWriter::new();
                 writer.set_file_index_mapper(file_map.file_indices.clone());
                 let printer_options = generate_operation_type_printer_options(
-                    &config.config,
+                    &generate,
                     &decl_file_path,
                     schema_output.as_deref(),
                 );
@@ -257,7 +351,8 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                 print_types_for_operation_document(
                     printer_options,
                     &mapped_schema,
-                    doc,
+                    &doc,
+                    extension,
                     &mut writer,
                 );
 
@@ -271,7 +366,70 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                     buffers,
                 )?;
             }
-            eprintln!("'generate' finished");
+            if !client_preset_documents.is_empty() {
+                debug!("Processing client preset module");
+                let Some(client_preset_output) = config
+                    .config
+                    .generate
+                    .client_preset_output
+                    .as_ref()
+                    .map(|client_preset_output| config.root_dir.join(client_preset_output))
+                else {
+                    return Err(CliError::OptionRequired {
+                        option: String::from("clientPresetOutput"),
+                        command: String::from("generate"),
+                    }
+                    .into());
+                };
+                // source map covers the schema and all documents in the module
+                let mut next_index = file_store.schema_len();
+                let file_map = FileMap {
+                    file_store,
+                    file_indices: file_store
+                        .iter()
+                        .map(|(idx, (_, _, kind))| {
+                            if kind == FileKind::Schema {
+                                idx
+                            } else if client_preset_documents.iter().any(|(i, _, _)| *i == idx) {
+                                next_index += 1;
+                                next_index - 1
+                            } else {
+                                usize::MAX
+                            }
+                        })
+                        .collect(),
+                };
+
+                let mut writer = SourceWriter::new();
+                writer.set_file_index_mapper(file_map.file_indices.clone());
+                let documents = client_preset_documents
+                    .iter()
+                    .map(|(file_index, doc, generate)| ClientPresetDocument {
+                        source: file_store[*file_index].1,
+                        document: doc,
+                        options: generate_operation_type_printer_options(
+                            generate,
+                            &client_preset_output,
+                            schema_output.as_deref(),
+                        ),
+                    })
+                    .collect::<Vec<_>>();
+                let warnings = print_client_preset_module(&mapped_schema, &documents, &mut writer)
+                    .map_err(client_preset_error)?;
+                for warning in warnings {
+                    output.warning(client_preset_error(warning));
+                }
+
+                let buffers = writer.into_buffers();
+                write_file_and_sourcemap(
+                    &file_map,
+                    output,
+                    OutputFileKind::OperationTypeDefinition,
+                    &client_preset_output,
+                    buffers,
+                )?;
+            }
+            output.command_finished("generate".to_owned());
             Ok(CliContext::SchemaResolved {
                 config,
                 schema,
@@ -283,32 +441,82 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
     }
 }
 
+/// Converts an error found in documents of the client preset module.
+fn client_preset_error(error: ClientPresetError) -> PositionedError {
+    match error {
+        ClientPresetError::OperationNameConflict {
+            ref name,
+            position,
+            other_position,
+        }
+        | ClientPresetError::FragmentNameConflict {
+            ref name,
+            position,
+            other_position,
+        } => {
+            let additional_info = vec![(other_position, format!("Another definition of '{name}'"))];
+            let fields = vec![("name", name.clone())];
+            PositionedError::new(error.into(), Some(position), additional_info)
+                .with_code(codes::CLIENT_PRESET_NAME_CONFLICT)
+                .with_fields(fields)
+        }
+        ClientPresetError::NoLookupTarget { position } => {
+            PositionedError::new(error.into(), Some(position), vec![])
+                .with_code(codes::CLIENT_PRESET_NO_LOOKUP_TARGET)
+        }
+    }
+}
+
+/// Converts an error found while generating validators.
+fn validator_printer_error(error: ValidatorPrinterError) -> PositionedError {
+    match error {
+        ValidatorPrinterError::MissingScalarValidator {
+            ref name,
+            position,
+            definition,
+        } => {
+            let additional_info = vec![(definition, format!("Scalar '{name}' is defined here"))];
+            let fields = vec![("name", name.clone())];
+            PositionedError::new(error.into(), Some(position), additional_info)
+                .with_code(codes::MISSING_SCALAR_VALIDATOR)
+                .with_fields(fields)
+        }
+        ValidatorPrinterError::ValidatorNameConflict {
+            ref name,
+            position,
+            other_position,
+        } => {
+            let additional_info = vec![(other_position, format!("Another definition of '{name}'"))];
+            let fields = vec![("name", name.clone())];
+            PositionedError::new(error.into(), Some(position), additional_info)
+                .with_code(codes::VALIDATOR_NAME_CONFLICT)
+                .with_fields(fields)
+        }
+    }
+}
+
 fn generate_operation_type_printer_options(
-    config: &Config,
+    generate: &GenerateConfig,
     decl_file_path: &Path,
     schema_output: Option<&Path>,
 ) -> OperationTypePrinterOptions {
-    let mut printer_options = OperationTypePrinterOptions::from_config(config);
-    printer_options.schema_source = config
-        .generate
-        .schema_module_specifier
-        .clone()
-        .unwrap_or_else(|| {
-            path_to_ts(relative_path(
-                decl_file_path,
-                schema_output
-                    .as_ref()
-                    .expect("This should be prevented by config validation"),
-            ))
-            .to_string_lossy()
-            .to_string()
-        });
+    let mut printer_options = OperationTypePrinterOptions::from_config(generate);
+    printer_options.schema_source = generate.schema_module_specifier.clone().unwrap_or_else(|| {
+        path_to_ts(relative_path(
+            decl_file_path,
+            schema_output
+                .as_ref()
+                .expect("This should be prevented by config validation"),
+        ))
+        .to_string_lossy()
+        .to_string()
+    });
     printer_options
 }
 
 #[derive(Debug)]
 struct FileMap<'src> {
-    pub file_store: &'src FileStore,
+    pub file_store: &'src FileStore<'src>,
     /// Mapping from file index in file_store to source map index.
     pub file_indices: Vec<usize>,
 }
@@ -359,7 +567,7 @@ fn write_file_and_sourcemap(
     }
     let mut output_file = File::create(output_file_path)?;
 
-    writeln!(&mut output_file, "{}", &buffers.buffer)?;
+    writeln!(&mut output_file, "{}", buffers.buffer)?;
     writeln!(
         &mut output_file,
         "//# sourceMappingURL={}",
@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use glob::{MatchOptions, Pattern};
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Decides whether files are ignored by `.gitignore` files.
+/// `.gitignore` files are read from the root of the git repository down to each file.
+pub struct Gitignore {
+    /// Root of the git repository.
+    root: PathBuf,
+    /// Rules of `.gitignore` in each directory.
+    rules: HashMap<PathBuf, Vec<Rule>>,
+}
+
+/// One line of a `.gitignore` file.
+struct Rule {
+    pattern: Pattern,
+    /// Whether the pattern is matched against the whole relative path instead of the file name.
+    anchored: bool,
+    negated: bool,
+    dir_only: bool,
+}
+
+impl Gitignore {
+    /// Creates a Gitignore for files in `dir`.
+    /// Root of the repository is the nearest ancestor of `dir` that contains `.git`.
+    pub fn new(dir: &Path) -> Self {
+        let root = dir
+            .ancestors()
+            .find(|ancestor| ancestor.join(".git").exists())
+            .unwrap_or(dir)
+            .to_owned();
+        Self {
+            root,
+            rules: HashMap::new(),
+        }
+    }
+
+    /// Returns whether given file is ignored.
+    pub fn is_ignored(&mut self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        let components = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        // A file cannot be re-included if its parent directory is ignored.
+        (1..=components.len()).any(|len| {
+            let is_dir = len < components.len();
+            self.is_ignored_entry(&components[..len], is_dir)
+        })
+    }
+
+    /// Decides whether an entry is ignored by rules of itself.
+    /// `components` is the path of the entry relative to the root.
+    fn is_ignored_entry(&mut self, components: &[String], is_dir: bool) -> bool {
+        let mut ignored = false;
+        // rules in deeper .gitignore files take precedence
+        for depth in 0..components.len() {
+            let dir = components[..depth]
+                .iter()
+                .fold(self.root.clone(), |dir, c| dir.join(c));
+            let relative = components[depth..].join("/");
+            let name = &components[components.len() - 1];
+            for rule in self.rules_in(dir) {
+                if rule.dir_only && !is_dir {
+                    continue;
+                }
+                let target = if rule.anchored { &relative } else { name };
+                if rule.pattern.matches_with(target, MATCH_OPTIONS) {
+                    ignored = !rule.negated;
+                }
+            }
+        }
+        ignored
+    }
+
+    fn rules_in(&mut self, dir: PathBuf) -> &[Rule] {
+        self.rules.entry(dir).or_insert_with_key(|dir| {
+            fs::read_to_string(dir.join(".gitignore"))
+                .map(|source| parse_gitignore(&source))
+                .unwrap_or_default()
+        })
+    }
+}
+
+fn parse_gitignore(source: &str) -> Vec<Rule> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let (negated, line) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let (dir_only, line) = match line.strip_suffix('/') {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let anchored = line.contains('/');
+            let line = line.strip_prefix('/').unwrap_or(line);
+            let pattern = Pattern::new(line).ok()?;
+            Some(Rule {
+                pattern,
+                anchored,
+                negated,
+                dir_only,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use super::Gitignore;
+
+    /// Creates a repository with given `.gitignore` files.
+    fn repository(name: &str, gitignores: &[(&str, &str)]) -> PathBuf {
+        let root = env::temp_dir().join(format!("nitrogql-gitignore-{name}-{}", process::id()));
+        fs::create_dir_all(root.join(".git")).unwrap();
+        for (dir, source) in gitignores {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            fs::write(root.join(dir).join(".gitignore"), source).unwrap();
+        }
+        root
+    }
+
+    fn ignored(root: &Path, files: &[&str]) -> Vec<bool> {
+        let mut gitignore = Gitignore::new(root);
+        files
+            .iter()
+            .map(|file| gitignore.is_ignored(&root.join(file)))
+            .collect()
+    }
+
+    #[test]
+    fn negation() {
+        let root = repository("negation", &[("", "*.graphql\n!keep.graphql\n")]);
+        assert_eq!(
+            ignored(&root, &["a.graphql", "keep.graphql", "src/keep.graphql"]),
+            vec![true, false, false]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn negation_under_ignored_directory() {
+        // a file cannot be re-included if its parent directory is ignored
+        let root = repository("negation-dir", &[("", "build/\n!build/keep.graphql\n")]);
+        assert_eq!(ignored(&root, &["build/keep.graphql"]), vec![true]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn anchoring() {
+        let root = repository(
+            "anchoring",
+            &[("", "/root.graphql\nsrc/generated.graphql\nany.graphql\n")],
+        );
+        assert_eq!(
+            ignored(
+                &root,
+                &[
+                    "root.graphql",
+                    "src/root.graphql",
+                    "src/generated.graphql",
+                    "lib/src/generated.graphql",
+                    "any.graphql",
+                    "lib/src/any.graphql",
+                ]
+            ),
+            vec![true, false, true, false, true, true]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn directory_only() {
+        let root = repository("directory-only", &[("", "generated/\n")]);
+        assert_eq!(
+            ignored(&root, &["generated/a.graphql", "src/generated"]),
+            vec![true, false]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn nested_gitignore() {
+        let root = repository("nested", &[("", "*.graphql\n"), ("src", "!/a.graphql\n")]);
+        assert_eq!(
+            ignored(&root, &["a.graphql", "src/a.graphql", "src/lib/a.graphql"]),
+            vec![true, false, true]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
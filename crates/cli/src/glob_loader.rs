@@ -0,0 +1,108 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use glob::{MatchOptions, Pattern};
+use globmatch::wrappers::{build_matchers, match_paths};
+use log::info;
+use nitrogql_config_file::Config;
+use nitrogql_error::PositionedError;
+use nitrogql_utils::normalize_path;
+
+use crate::{CommandError, error::CliError, gitignore::Gitignore, output::CliOutput};
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: !cfg!(windows),
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Loads files matched by given patterns.
+/// Patterns starting with `!` and patterns in `exclude` of config exclude files matched by them.
+/// Files ignored by git are also excluded if configured.
+/// A positive pattern that matches no files is reported as a warning.
+pub fn load_glob_files(
+    root: &Path,
+    patterns: &[String],
+    config: &Config,
+    output: &mut CliOutput,
+) -> Result<Vec<(PathBuf, String)>, CommandError> {
+    let (excludes, includes): (Vec<_>, Vec<_>) = patterns
+        .iter()
+        .map(|pattern| pattern.as_str())
+        .partition(|pattern| pattern.starts_with('!'));
+    if includes.is_empty() {
+        return Ok(vec![]);
+    }
+    info!("load_glob_files {} {}", root.display(), patterns.join(" "));
+
+    let excludes = excludes
+        .into_iter()
+        .map(|pattern| &pattern[1..])
+        .chain(config.exclude.iter().map(|pattern| pattern.as_str()))
+        .map(|pattern| absolute_pattern(root, pattern))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut gitignore = config.respect_gitignore.then(|| Gitignore::new(root));
+    let mut is_excluded = |path: &Path| {
+        excludes
+            .iter()
+            .any(|pattern| pattern.matches_path_with(path, MATCH_OPTIONS))
+            || gitignore
+                .as_mut()
+                .is_some_and(|gitignore| gitignore.is_ignored(path))
+    };
+
+    let matchers = build_matchers(&includes, root).map_err(CliError::GlobError)?;
+    let mut paths = vec![];
+    for (pattern, matcher) in includes.into_iter().zip(matchers) {
+        let (matched, _) = match_paths(vec![matcher], None, None);
+        let matched = matched
+            .into_iter()
+            .map(|path| normalize_path(&path))
+            .filter(|path| !is_excluded(path))
+            .collect::<Vec<_>>();
+        if matched.is_empty() {
+            let warning = CliError::PatternMatchesNoFiles(pattern.to_owned());
+            let code = warning.code();
+            output.warning(PositionedError::from(warning).with_code(code));
+        }
+        paths.extend(matched);
+    }
+    paths.sort_unstable();
+    paths.dedup();
+    info!("match_paths {paths:?}");
+
+    paths
+        .into_iter()
+        .map(|path| {
+            info!("loading {}", path.to_string_lossy());
+            fs::read_to_string(&path).map(|res| (path, res))
+        })
+        .collect::<std::io::Result<_>>()
+        .map_err(|err| err.into())
+}
+
+/// Converts a pattern relative to `root` into an absolute pattern.
+fn absolute_pattern(root: &Path, pattern: &str) -> Result<Pattern, CommandError> {
+    // leading relative components are applied to root so that root itself is not a pattern
+    let mut base = root.to_owned();
+    let mut rest = pattern;
+    loop {
+        if let Some(r) = rest.strip_prefix("./") {
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("../") {
+            base.pop();
+            rest = r;
+        } else {
+            break;
+        }
+    }
+    let pattern = if Path::new(rest).is_absolute() {
+        rest.to_owned()
+    } else {
+        format!("{}/{rest}", Pattern::escape(&base.to_string_lossy()))
+    };
+    Pattern::new(&pattern)
+        .map_err(|err| CliError::GlobError(format!("'{pattern}': {}", err.msg)).into())
+}
@@ -0,0 +1,103 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use nitrogql_ast::{
+    OperationDocumentExt, operation_ext::ExecutableDefinitionExt,
+    type_system::TypeSystemOrExtensionDocument,
+};
+use nitrogql_semantics::{
+    FileLookup, ImportPathError, ImportPathResolver, ResolutionHost, SchemaResolver,
+};
+
+thread_local! {
+    /// Contents of package.json files read so far, keyed by path.
+    /// None means that the file does not exist.
+    static PACKAGE_JSONS: RefCell<HashMap<PathBuf, Option<String>>> = RefCell::new(HashMap::new());
+}
+
+/// Resolution host backed by the file system.
+/// package.json files are read once per package, as every import from a package needs them.
+pub struct FileSystemHost;
+
+impl ResolutionHost for FileSystemHost {
+    fn lookup(&self, path: &Path) -> FileLookup<'_> {
+        let content = if path.file_name().is_some_and(|name| name == "package.json") {
+            PACKAGE_JSONS.with(|cache| {
+                cache
+                    .borrow_mut()
+                    .entry(path.to_owned())
+                    .or_insert_with(|| fs::read_to_string(path).ok())
+                    .clone()
+            })
+        } else {
+            fs::read_to_string(path).ok()
+        };
+        match content {
+            Some(content) => FileLookup::Found(Cow::Owned(content)),
+            None => FileLookup::NotFound,
+        }
+    }
+}
+
+/// Lists files imported from given document.
+/// Paths that cannot be resolved are skipped; they are reported when checking.
+pub fn imported_paths(
+    document_path: &Path,
+    document: &OperationDocumentExt,
+    resolver: &ImportPathResolver,
+) -> Vec<PathBuf> {
+    document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            ExecutableDefinitionExt::Import(import) => resolver
+                .resolve(document_path, &import.path.value, &FileSystemHost)
+                .ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Lists files imported from given schema document.
+/// Paths that cannot be resolved are skipped; they are reported when resolving schema imports.
+pub fn schema_imported_paths(
+    document_path: &Path,
+    document: &TypeSystemOrExtensionDocument,
+    resolver: &ImportPathResolver,
+) -> Vec<PathBuf> {
+    document
+        .imports
+        .iter()
+        .filter_map(|import| {
+            resolver
+                .resolve(document_path, &import.path.value, &FileSystemHost)
+                .ok()
+        })
+        .collect()
+}
+
+/// Schema files loaded by following `#import` from the schema entry.
+pub struct SchemaModules<'a, 'src> {
+    pub documents: HashMap<PathBuf, TypeSystemOrExtensionDocument<'src>>,
+    pub import_path_resolver: &'a ImportPathResolver,
+}
+
+impl<'src> SchemaResolver<'src> for SchemaModules<'_, 'src> {
+    fn resolve(&self, path: &Path) -> Option<&TypeSystemOrExtensionDocument<'src>> {
+        self.documents.get(path)
+    }
+
+    fn resolve_import_path(
+        &self,
+        from_file: &Path,
+        import_path: &str,
+    ) -> Result<PathBuf, ImportPathError> {
+        self.import_path_resolver
+            .resolve(from_file, import_path, &FileSystemHost)
+    }
+}
@@ -1,5 +1,7 @@
 use std::{
-    fs,
+    collections::{HashMap, HashSet},
+    env, fs,
+    io::{IsTerminal, stderr},
     mem::ManuallyDrop,
     path::{Path, PathBuf},
     process,
@@ -9,19 +11,19 @@ use anyhow::Result;
 use builtins::nitrogql_builtins;
 use clap::Parser;
 use context::OutputFormat;
-use file_store::FileStore;
-use globmatch::wrappers::{build_matchers, match_paths};
+use file_store::{FileStore, SourceArena};
 use graphql_builtins::generate_builtins;
 use graphql_type_system::Schema;
 use itertools::Itertools;
 use log::info;
 use nitrogql_ast::{
     OperationDocumentExt, set_current_file_of_pos, type_system::TypeSystemOrExtensionDocument,
+    value::StringValue,
 };
 use nitrogql_introspection::schema_from_introspection_json;
 use nitrogql_plugin::{Plugin, PluginSchemaExtensions};
 use nitrogql_utils::{get_cwd, normalize_path};
-use output::CliOutput;
+use output::{CliOutput, human_output, json_output, rdjson_output, sarif_output};
 use plugin_host::PluginHost;
 use schema_loader::LoadedSchema;
 
@@ -29,26 +31,38 @@ use crate::{
     context::CliContext,
     error::CliError,
     file_store::FileKind,
+    glob_loader::load_glob_files,
+    import_loader::{FileSystemHost, SchemaModules, imported_paths, schema_imported_paths},
     load_plugins::load_plugins,
     schema_loader::{LoadSchemaJsResult, SchemaFileKind, load_schema_js, schema_kind_by_path},
 };
-use nitrogql_config_file::load_config;
+use nitrogql_config_file::{
+    Config, ConfigFileError, DEFAULT_PROJECT_NAME, LoadedConfig, Project, load_config,
+};
 
 use nitrogql_error::{PositionedError, print_positioned_error};
 use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_semantics::{
+    ImportPathResolver, SchemaImportError, SchemaImportErrorMessage, resolve_schema_imports,
+};
 
-use self::{check::run_check, context::CliConfig, generate::run_generate};
+use self::{check::run_check, context::CliConfig, explain::run_explain, generate::run_generate};
 
 mod builtins;
 mod check;
 mod context;
 mod error;
+mod explain;
 mod file_store;
 mod generate;
+mod gitignore;
+mod glob_loader;
+mod import_loader;
 mod load_plugins;
 mod output;
 mod plugin_host;
 mod schema_loader;
+mod tests;
 
 #[derive(Parser, Debug)]
 struct Args {
@@ -64,6 +78,10 @@ struct Args {
     #[arg(long)]
     /// Path to save schema type definition file.
     schema_output: Option<PathBuf>,
+    #[arg(long)]
+    /// Name of the project in the config file to run commands for.
+    /// All projects are run by default.
+    project: Option<String>,
     /// Output format of CLI.
     #[arg(long, default_value = "human")]
     output_format: OutputFormat,
@@ -82,86 +100,234 @@ pub async fn run_cli(args: impl IntoIterator<Item = String>) {
         .env()
         .init()
         .unwrap();
-    let mut output = CliOutput::new();
-    let file_store = Box::leak(Box::new(FileStore::new()));
+    let sources = SourceArena::new();
     let args = Args::parse_from(args);
     let output_format = args.output_format;
-    let res = run_cli_impl(args, file_store, &mut output).await;
-    let code = match res {
-        Ok(()) => 0,
-        Err(err) => {
-            let message = err
-                .inner
-                .into_iter()
-                .map(|e| {
-                    if e.has_position() {
-                        print_positioned_error(&e, file_store)
-                    } else {
-                        format!("{}", e.into_inner())
-                    }
-                })
-                .join("\n");
-            output.command_error(err.command, message);
-            1
-        }
+    // Diagnostics are colored only when they are printed to a terminal for humans.
+    if env::var_os("CLICOLOR_FORCE").is_none()
+        && (!matches!(output_format, OutputFormat::Human) || !stderr().is_terminal())
+    {
+        colored::control::set_override(false);
+    }
+    let runs = run_cli_impl(args, &sources).await;
+    let code = if runs.iter().any(|run| run.output.has_error()) {
+        1
+    } else {
+        0
     };
+    let outputs = runs
+        .into_iter()
+        .map(|run| (run.output, run.file_store))
+        .collect();
 
     match output_format {
-        OutputFormat::Human => {
-            output.human_output(file_store);
-        }
-        OutputFormat::Json => {
-            output.json_output(file_store);
-        }
-        OutputFormat::Rdjson => {
-            output.rdjson_output(file_store);
-        }
+        OutputFormat::Human => human_output(outputs),
+        OutputFormat::Json => json_output(outputs),
+        OutputFormat::Rdjson => rdjson_output(outputs),
+        OutputFormat::Sarif => sarif_output(outputs),
     }
 
     process::exit(code);
 }
 
-async fn run_cli_impl(
-    args: Args,
-    file_store: &mut FileStore,
-    output: &mut CliOutput,
-) -> Result<(), CommandError> {
+/// Result of running commands for one project.
+struct ProjectRun<'src> {
+    output: CliOutput,
+    /// Files loaded for the project.
+    file_store: FileStore<'src>,
+}
+
+impl<'src> ProjectRun<'src> {
+    fn new(sources: &'src SourceArena, project: Option<String>) -> Self {
+        let mut output = CliOutput::new();
+        output.project(project);
+        Self {
+            output,
+            file_store: FileStore::new(sources),
+        }
+    }
+
+    /// Records an error that stopped the commands.
+    fn fail(&mut self, err: CommandError) {
+        let errors = err
+            .inner
+            .into_iter()
+            .map(|e| match e.downcast_ref::<CliError>() {
+                Some(cli_error) if e.code().is_none() => {
+                    let code = cli_error.code();
+                    e.with_code(code)
+                }
+                _ => e,
+            })
+            .collect::<Vec<_>>();
+        let code = errors.iter().find_map(|e| e.code());
+        let message = errors
+            .iter()
+            .map(|e| print_positioned_error(e, &self.file_store))
+            .join("\n");
+        self.output.command_error(err.command, code, message);
+    }
+}
+
+/// Runs given commands for each selected project.
+async fn run_cli_impl(args: Args, sources: &SourceArena) -> Vec<ProjectRun<'_>> {
+    let mut global = ProjectRun::new(sources, None);
     if args.commands.is_empty() {
-        return Err(CliError::NoCommandSpecified.into());
+        global.fail(CliError::NoCommandSpecified.into());
+        return vec![global];
+    }
+    if args.commands[0] == "explain" {
+        // explain does not need config or schema
+        global.output.command_run("explain".to_owned());
+        if let Err(err) = run_explain(&args.commands[1..], &mut global.output) {
+            global.fail(CommandError::new(vec![err.into()], "explain".to_owned()));
+        }
+        return vec![global];
+    }
+    let (root_dir, projects) = match load_projects(&args, &mut global.file_store).await {
+        Ok(result) => result,
+        Err(err) => {
+            global.fail(err);
+            return vec![global];
+        }
+    };
+    let mut runs = vec![];
+    for project in projects {
+        let mut run = ProjectRun::new(sources, project.name);
+        if let Err(err) = run_project(
+            &args,
+            root_dir.clone(),
+            project.config,
+            &mut run.file_store,
+            &mut run.output,
+        )
+        .await
+        {
+            run.fail(err);
+        }
+        runs.push(run);
     }
+    runs
+}
+
+/// Loads config file and returns the root directory and projects to run.
+/// If the config file is invalid, it is added to `file_store` for reporting the error.
+async fn load_projects(
+    args: &Args,
+    file_store: &mut FileStore<'_>,
+) -> Result<(PathBuf, Vec<Project>), CommandError> {
     let cwd = get_cwd()?;
-    let config_file = load_config(&cwd, args.config_file.as_deref()).await?;
-    let (root_dir, mut config) = if let Some((config_path, config_file)) = config_file {
-        info!("Loaded config file from {}", config_path.display());
+    let config_file = match load_config(&cwd, args.config_file.as_deref()).await {
+        Ok(config_file) => config_file,
+        Err(ConfigFileError::Validation {
+            path,
+            content,
+            error,
+        }) => {
+            let file_idx = file_store.add_file(path, content, FileKind::Config);
+            set_current_file_of_pos(file_idx);
+            return Err((*error).into());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let (root_dir, projects) = if let Some(LoadedConfig { files, projects }) = config_file {
+        info!(
+            "Loaded config file from {}",
+            files
+                .iter()
+                .map(|file| file.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        );
         (
-            normalize_path(config_path.parent().unwrap_or(Path::new(""))),
-            config_file,
+            normalize_path(files[0].parent().unwrap_or(Path::new(""))),
+            projects,
         )
     } else {
-        (get_cwd()?, Default::default())
+        (
+            cwd,
+            vec![Project {
+                name: None,
+                config: Default::default(),
+            }],
+        )
+    };
+    if projects.is_empty() {
+        return Err(CliError::NoProjectDefined.into());
+    }
+    let Some(name) = args.project.as_ref() else {
+        return Ok((root_dir, projects));
     };
+    let project_names = projects
+        .iter()
+        .map(|project| {
+            project
+                .name
+                .as_deref()
+                .unwrap_or(DEFAULT_PROJECT_NAME)
+                .to_owned()
+        })
+        .collect::<Vec<_>>();
+    match projects
+        .into_iter()
+        .find(|project| project.name.as_deref().unwrap_or(DEFAULT_PROJECT_NAME) == name)
+    {
+        Some(project) => Ok((root_dir, vec![project])),
+        None => Err(CliError::ProjectNotFound {
+            name: name.clone(),
+            available: project_names.join(", "),
+        }
+        .into()),
+    }
+}
+
+/// Runs given commands for a project.
+async fn run_project(
+    args: &Args,
+    root_dir: PathBuf,
+    mut config: Config,
+    file_store: &mut FileStore<'_>,
+    output: &mut CliOutput,
+) -> Result<(), CommandError> {
     // Override config with args
     if !args.schema.is_empty() {
-        config.schema = args.schema;
+        config.schema = args.schema.clone();
+        config.schema_entry = None;
     }
     if !args.operation.is_empty() {
-        config.operations = args.operation;
+        config.operations = args.operation.clone();
     }
-    if let Some(path) = args.schema_output {
-        config.generate.schema_output = Some(path);
+    if let Some(path) = args.schema_output.as_ref() {
+        config.generate.schema_output = Some(path.clone());
     }
     info!("Loaded config {config:?}");
     info!("root_dir {}", root_dir.display());
+    output.root_dir(&root_dir);
 
     let mut plugins = load_plugins(&config.plugins)?;
 
-    if config.schema.is_empty() {
-        return Err(CliError::NoSchemaSpecified.into());
-    }
-
-    let schema_files = load_glob_files(&root_dir, &config.schema)?;
-    let mut schema_docs = vec![];
+    let import_path_resolver = ImportPathResolver::new(root_dir.clone(), &config.paths);
+    let schema_files = match config.schema_entry {
+        Some(_) => vec![],
+        None if config.schema.is_empty() => {
+            return Err(CliError::NoSchemaSpecified.into());
+        }
+        None => load_glob_files(&root_dir, &config.schema, &config, output)?,
+    };
+    let mut schema_docs = match config.schema_entry {
+        Some(ref entry) => load_schema_modules(
+            normalize_path(&root_dir.join(entry)),
+            file_store,
+            &import_path_resolver,
+        )?,
+        None => vec![],
+    };
     let mut schema_errors = vec![];
+    let mut loaded_schema_paths = schema_files
+        .iter()
+        .map(|(path, _)| path.clone())
+        .collect::<HashSet<_>>();
+    let mut schema_imports = vec![];
     for (path, buf) in schema_files {
         let res = match schema_kind_by_path(&path) {
             SchemaFileKind::GraphQL => {
@@ -170,6 +336,11 @@ async fn run_cli_impl(
                 info!("parsing(schema) {} {}", path.to_string_lossy(), file_idx);
                 set_current_file_of_pos(file_idx);
                 let doc = parse_type_system_document(buf)?;
+                schema_imports.extend(
+                    doc.imports
+                        .iter()
+                        .map(|import| (path.clone(), import.path.clone())),
+                );
                 Ok(LoadedSchema::GraphQL(doc))
             }
             SchemaFileKind::IntrospectionJson => {
@@ -202,6 +373,15 @@ async fn run_cli_impl(
             Err(err) => schema_errors.push(err),
         }
     }
+    match load_imported_schema_files(
+        schema_imports,
+        &mut loaded_schema_paths,
+        file_store,
+        &import_path_resolver,
+    ) {
+        Ok(docs) => schema_docs.extend(docs),
+        Err(err) => schema_errors.push(err),
+    }
     if !schema_errors.is_empty() {
         return Err(CommandError::merge(schema_errors));
     }
@@ -217,25 +397,53 @@ async fn run_cli_impl(
         merged_schema_doc
     };
 
-    let operation_files = load_glob_files(&config.root_dir, &config.config.operations)?;
-
-    let (operation_docs, operation_errors): (Vec<_>, Vec<_>) = operation_files
+    let operation_files = load_glob_files(
+        &config.root_dir,
+        &config.config.operations,
+        &config.config,
+        output,
+    )?;
+
+    let mut parse_operation = |path: PathBuf,
+                               buf: String,
+                               kind: FileKind|
+     -> Result<(PathBuf, OperationDocumentExt, usize), CommandError> {
+        info!("parsing(operation) {}", path.to_string_lossy());
+        let file_idx = file_store.add_file(path.clone(), buf, kind);
+        let (_, buf, _) = file_store.get_file(file_idx).unwrap();
+        set_current_file_of_pos(file_idx);
+
+        let doc = parse_operation_document(buf)?;
+        Ok((path, doc, file_idx))
+    };
+    let mut loaded_paths = operation_files
+        .iter()
+        .map(|(path, _)| path.clone())
+        .collect::<HashSet<_>>();
+    let (mut operation_docs, operation_errors): (Vec<_>, Vec<_>) = operation_files
         .into_iter()
-        .map(
-            |(path, buf)| -> Result<(PathBuf, OperationDocumentExt, usize), CommandError> {
-                info!("parsing(operation) {}", path.to_string_lossy());
-                let file_idx = file_store.add_file(path.clone(), buf, FileKind::Operation);
-                let (_, buf, _) = file_store.get_file(file_idx).unwrap();
-                set_current_file_of_pos(file_idx);
-
-                let doc = parse_operation_document(buf)?;
-                Ok((path, doc, file_idx))
-            },
-        )
+        .map(|(path, buf)| parse_operation(path, buf, FileKind::Operation))
         .partition_result();
     if !operation_errors.is_empty() {
         return Err(CommandError::merge(operation_errors));
     }
+    // Load imported files that are not included in `documents` (e.g. from packages)
+    let mut checked_docs = 0;
+    while checked_docs < operation_docs.len() {
+        let (path, doc, _) = &operation_docs[checked_docs];
+        checked_docs += 1;
+        for imported_path in imported_paths(path, doc, &import_path_resolver) {
+            if !loaded_paths.insert(imported_path.clone()) {
+                continue;
+            }
+            let Ok(buf) = fs::read_to_string(&imported_path) else {
+                // Reported as a missing file when checking
+                continue;
+            };
+            let imported_doc = parse_operation(imported_path, buf, FileKind::ImportedOperation)?;
+            operation_docs.push(imported_doc);
+        }
+    }
 
     let mut context = CliContext::SchemaUnresolved {
         config,
@@ -286,10 +494,10 @@ impl<E: Into<PositionedError>> From<E> for CommandError {
     }
 }
 
-fn run_command<'a>(
+fn run_command<'a, 'src>(
     command: &str,
-    context: CliContext<'a>,
-) -> nitrogql_error::Result<CliContext<'a>> {
+    context: CliContext<'a, 'src>,
+) -> nitrogql_error::Result<CliContext<'a, 'src>> {
     match command {
         "check" => run_check(context),
         "generate" => run_generate(context),
@@ -297,28 +505,117 @@ fn run_command<'a>(
     }
 }
 
-fn load_glob_files<'a, S: AsRef<str> + 'a>(
-    root: &Path,
-    globs: impl IntoIterator<Item = &'a S>,
-) -> Result<Vec<(PathBuf, String)>> {
-    let path_strs: Vec<&str> = globs.into_iter().map(|s| s.as_ref()).collect();
-    if path_strs.is_empty() {
-        return Ok(vec![]);
+/// Loads schema files reachable from `entry` by following `#import`.
+/// Returned documents are ordered so that imported files come first.
+fn load_schema_modules<'src>(
+    entry: PathBuf,
+    file_store: &mut FileStore<'src>,
+    import_path_resolver: &ImportPathResolver,
+) -> Result<Vec<LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>>, CommandError> {
+    let mut documents = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut queue = vec![entry.clone()];
+    while let Some(path) = queue.pop() {
+        if !visited.insert(path.clone()) {
+            continue;
+        }
+        let Ok(buf) = fs::read_to_string(&path) else {
+            // Reported as a missing file when resolving imports
+            continue;
+        };
+        let file_idx = file_store.add_file(path.clone(), buf, FileKind::Schema);
+        let buf: &'src str = file_store.get_file(file_idx).unwrap().1;
+        info!("parsing(schema) {} {}", path.to_string_lossy(), file_idx);
+        set_current_file_of_pos(file_idx);
+        let doc = parse_type_system_document(buf)?;
+        queue.extend(schema_imported_paths(&path, &doc, import_path_resolver));
+        documents.insert(path, doc);
     }
-
-    info!("load_glob_files {} {}", root.display(), path_strs.join(" "));
-    let schema_matchers = build_matchers(&path_strs, root).map_err(CliError::GlobError)?;
-    let (paths, _) = match_paths(schema_matchers, None, None);
-    info!("match_paths {paths:?}");
-    let results = paths
+    let modules = SchemaModules {
+        documents,
+        import_path_resolver,
+    };
+    let paths = resolve_schema_imports(&entry, &modules).map_err(|inner| CommandError {
+        inner,
+        command: None,
+    })?;
+    let mut documents = modules.documents;
+    Ok(paths
         .into_iter()
-        .map(|path| {
-            info!("loading {}", path.to_string_lossy());
-            fs::read_to_string(&path).map(|res| (path, res))
-        })
-        .collect::<std::io::Result<_>>();
+        .filter_map(|path| documents.remove(&path))
+        .map(LoadedSchema::GraphQL)
+        .collect())
+}
 
-    results.map_err(|err| err.into())
+/// Loads schema files imported from schema files loaded by the `schema` option.
+/// Files already in `loaded_paths` are not loaded again. Names in imports are not checked.
+fn load_imported_schema_files<'src>(
+    mut imports: Vec<(PathBuf, StringValue)>,
+    loaded_paths: &mut HashSet<PathBuf>,
+    file_store: &mut FileStore<'src>,
+    import_path_resolver: &ImportPathResolver,
+) -> Result<Vec<LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>>, CommandError> {
+    let mut documents = vec![];
+    let mut errors: Vec<PositionedError> = vec![];
+    let mut checked_imports = 0;
+    while checked_imports < imports.len() {
+        let (from, import_path) = imports[checked_imports].clone();
+        checked_imports += 1;
+        let path = match import_path_resolver.resolve(&from, &import_path.value, &FileSystemHost) {
+            Ok(path) => path,
+            Err(err) => {
+                errors.push(
+                    SchemaImportError {
+                        message: SchemaImportErrorMessage::UnresolvedImport {
+                            file: import_path.value,
+                            reason: err.to_string(),
+                            position: import_path.position,
+                        },
+                    }
+                    .into(),
+                );
+                continue;
+            }
+        };
+        if !loaded_paths.insert(path.clone()) {
+            continue;
+        }
+        let Ok(buf) = fs::read_to_string(&path) else {
+            errors.push(
+                SchemaImportError {
+                    message: SchemaImportErrorMessage::FileNotFound {
+                        file: import_path.value,
+                        position: import_path.position,
+                    },
+                }
+                .into(),
+            );
+            continue;
+        };
+        let file_idx = file_store.add_file(path.clone(), buf, FileKind::Schema);
+        let buf: &'src str = file_store.get_file(file_idx).unwrap().1;
+        info!("parsing(schema) {} {}", path.to_string_lossy(), file_idx);
+        set_current_file_of_pos(file_idx);
+        match parse_type_system_document(buf) {
+            Ok(doc) => {
+                imports.extend(
+                    doc.imports
+                        .iter()
+                        .map(|import| (path.clone(), import.path.clone())),
+                );
+                documents.push(LoadedSchema::GraphQL(doc));
+            }
+            Err(err) => errors.push(err.into()),
+        }
+    }
+    if errors.is_empty() {
+        Ok(documents)
+    } else {
+        Err(CommandError {
+            inner: errors,
+            command: None,
+        })
+    }
 }
 
 fn resolve_loaded_schema<'src>(
@@ -352,7 +649,7 @@ fn resolve_loaded_schema<'src>(
 /// Extend loaded schema with builtins and plugins.
 fn extend_loaded_schema<'src>(
     schema: &mut LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
-    file_store: &mut FileStore,
+    file_store: &mut FileStore<'src>,
     plugins: &[Plugin<'src>],
 ) -> Result<(), CommandError> {
     let mut plugin_host = PluginHost::new(file_store);
@@ -27,6 +27,8 @@ pub enum OutputFileKind {
     OperationTypeDefinitionSourceMap,
     GraphqlSource,
     GraphqlSourceSourceMap,
+    Validators,
+    ValidatorsSourceMap,
 }
 
 impl OutputFileKind {
@@ -52,6 +54,8 @@ impl OutputFileKind {
             }
             OutputFileKind::GraphqlSource => OutputFileKind::GraphqlSourceSourceMap,
             OutputFileKind::GraphqlSourceSourceMap => OutputFileKind::GraphqlSourceSourceMap,
+            OutputFileKind::Validators => OutputFileKind::ValidatorsSourceMap,
+            OutputFileKind::ValidatorsSourceMap => OutputFileKind::ValidatorsSourceMap,
         }
     }
 }
@@ -73,6 +77,8 @@ impl Display for OutputFileKind {
             }
             OutputFileKind::GraphqlSource => write!(f, "graphqlSource"),
             OutputFileKind::GraphqlSourceSourceMap => write!(f, "graphqlSourceSourceMap"),
+            OutputFileKind::Validators => write!(f, "validators"),
+            OutputFileKind::ValidatorsSourceMap => write!(f, "validatorsSourceMap"),
         }
     }
 }
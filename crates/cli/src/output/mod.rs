@@ -1,20 +1,35 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use json_writer::JSONObjectWriter;
+use json_writer::{JSONArrayWriter, JSONObjectWriter};
 
 mod file_kind;
+mod sarif;
+mod tests;
 
+use colored::Colorize;
 pub use file_kind::{InputFileKind, OutputFileKind};
-use nitrogql_error::{PositionedError, print_positioned_error};
+use nitrogql_error::{
+    ErrorCategory, ErrorCode, ErrorCodeInfo, PositionedError, codes, print_positioned_error,
+    suggestion_message,
+};
+pub use sarif::sarif_output;
 
 use crate::file_store::FileStore;
 
 /// Struct that keeps track of all outputs.
 pub struct CliOutput {
     commands_run: Vec<String>,
-    command_error: Option<(Option<String>, String)>,
+    commands_finished: Vec<String>,
+    command_error: Option<(Option<String>, Option<ErrorCode>, String)>,
     check_errors: Vec<(file_kind::InputFileKind, PositionedError)>,
+    /// Problems that do not make commands fail.
+    warnings: Vec<PositionedError>,
     generated_files: Vec<(file_kind::OutputFileKind, PathBuf)>,
+    explained: Vec<&'static ErrorCodeInfo>,
+    /// Root directory of the project, used for relative paths in output.
+    root_dir: Option<PathBuf>,
+    /// Name of the project in the config file.
+    project: Option<String>,
 }
 
 impl CliOutput {
@@ -22,20 +37,55 @@ impl CliOutput {
     pub fn new() -> Self {
         Self {
             commands_run: Vec::new(),
+            commands_finished: Vec::new(),
             command_error: None,
             check_errors: Vec::new(),
+            warnings: Vec::new(),
             generated_files: Vec::new(),
+            explained: Vec::new(),
+            root_dir: None,
+            project: None,
         }
     }
 
+    /// Set the name of the project that this output belongs to.
+    pub fn project(&mut self, project: Option<String>) {
+        self.project = project;
+    }
+
+    /// Returns whether a command failed.
+    pub fn has_error(&self) -> bool {
+        self.command_error.is_some()
+    }
+
     /// Indicates that a command is run.
     pub fn command_run(&mut self, command_name: String) {
         self.commands_run.push(command_name);
     }
 
+    /// Indicates that a command finished successfully.
+    pub fn command_finished(&mut self, command_name: String) {
+        self.commands_finished.push(command_name);
+    }
+
+    /// Set the root directory of the project.
+    pub fn root_dir(&mut self, root_dir: &Path) {
+        self.root_dir = Some(root_dir.to_owned());
+    }
+
     /// Add a command error.
-    pub fn command_error(&mut self, command_name: Option<String>, error: String) {
-        self.command_error = Some((command_name, error));
+    pub fn command_error(
+        &mut self,
+        command_name: Option<String>,
+        code: Option<ErrorCode>,
+        error: String,
+    ) {
+        self.command_error = Some((command_name, code, error));
+    }
+
+    /// Add a warning.
+    pub fn warning(&mut self, warning: PositionedError) {
+        self.warnings.push(warning);
     }
 
     /// Add a generated file.
@@ -43,8 +93,33 @@ impl CliOutput {
         self.generated_files.push((kind, path));
     }
 
+    /// Add explanations of error codes.
+    pub fn explained(&mut self, infos: impl IntoIterator<Item = &'static ErrorCodeInfo>) {
+        self.explained.extend(infos);
+    }
+
     /// Output for human consumption.
-    pub fn human_output(self, file_store: &FileStore) {
+    fn human_output(self, file_store: &FileStore) {
+        for warning in self.warnings.iter() {
+            eprintln!(
+                "{} {}",
+                "Warning:".bright_yellow().bold(),
+                print_positioned_error(warning, file_store)
+            );
+        }
+        for command_name in self.commands_finished.iter() {
+            eprintln!("'{command_name}' finished");
+        }
+        for info in self.explained.iter() {
+            println!(
+                "{} ({}): {}\n{}\n\n{}\n",
+                info.code.to_string().bold(),
+                info.category,
+                info.name,
+                info.summary,
+                info.explanation
+            );
+        }
         if !self.check_errors.is_empty() {
             let (schema_errors, operation_errors): (Vec<_>, Vec<_>) = self
                 .check_errors
@@ -76,7 +151,7 @@ impl CliOutput {
                 eprintln!();
             }
         }
-        if let Some((command_name, error)) = self.command_error {
+        if let Some((command_name, _, error)) = self.command_error {
             match command_name {
                 Some(command_name) => eprintln!("Error in command '{command_name}':\n{error}"),
                 None => eprintln!("Error:\n{error}"),
@@ -84,13 +159,32 @@ impl CliOutput {
         }
     }
 
-    /// Output with JSON format.
-    pub fn json_output(self, file_store: &FileStore) {
-        let mut buffer = String::new();
-        let mut writer = JSONObjectWriter::new(&mut buffer);
-        if let Some((command, message)) = self.command_error {
+    /// Writes output with JSON format to given object.
+    fn json_output(self, writer: &mut JSONObjectWriter, file_store: &FileStore) {
+        if !self.warnings.is_empty() {
+            let mut warnings = writer.array("warnings");
+            for warning in self.warnings {
+                let mut obj = warnings.object();
+                obj.value("code", warning.code().map(|code| code.to_string()).as_ref());
+                obj.value(
+                    "category",
+                    warning
+                        .code()
+                        .and_then(|code| code.info())
+                        .map(|info| info.category.as_str()),
+                );
+                obj.value("message", &warning.into_inner().to_string());
+            }
+        }
+        if let Some((command, code, message)) = self.command_error {
             let mut obj = writer.object("error");
             obj.value("command", command.as_ref());
+            obj.value("code", code.map(|code| code.to_string()).as_ref());
+            obj.value(
+                "category",
+                code.and_then(|code| code.info())
+                    .map(|info| info.category.as_str()),
+            );
             obj.value("message", &message);
         }
         if self.commands_run.iter().any(|c| c == "check") {
@@ -112,9 +206,33 @@ impl CliOutput {
                     }
                     None => obj.value("file", None::<&bool>),
                 }
+                obj.value("code", error.code().map(|code| code.to_string()).as_ref());
+                obj.value(
+                    "category",
+                    error.category().map(|category| category.as_str()),
+                );
+                {
+                    let mut fields = obj.object("fields");
+                    for (key, value) in error.fields() {
+                        fields.value(key, value);
+                    }
+                }
+                obj.value("suggestions", error.suggestions());
                 obj.value("message", &error.into_inner().to_string());
             }
         }
+        if self.commands_run.iter().any(|c| c == "explain") {
+            let mut obj = writer.object("explain");
+            let mut codes = obj.array("codes");
+            for info in self.explained.iter() {
+                let mut obj = codes.object();
+                obj.value("code", &info.code.to_string());
+                obj.value("category", info.category.as_str());
+                obj.value("name", info.name);
+                obj.value("summary", info.summary);
+                obj.value("explanation", info.explanation);
+            }
+        }
         if self.commands_run.iter().any(|c| c == "generate") {
             let mut obj = writer.object("generate");
             let mut files = obj.array("files");
@@ -124,44 +242,139 @@ impl CliOutput {
                 obj.value("path", &path.to_string_lossy());
             }
         }
-        writer.end();
-        println!("{buffer}");
     }
 
-    /// Output in rdjson format.
-    pub fn rdjson_output(self, file_store: &FileStore) {
-        let mut buffer = String::new();
-        let mut writer = JSONObjectWriter::new(&mut buffer);
-        {
-            let mut source = writer.object("source");
-            source.value("name", "nitrogql");
-            source.value("url", "https://nitrogql.vercel.app/");
+    /// Writes diagnostics in rdjson format to given array.
+    /// The category of each diagnostic is written as a part of its source name.
+    fn rdjson_output(self, diagnostics: &mut JSONArrayWriter, file_store: &FileStore) {
+        for warning in self.warnings {
+            let mut obj = diagnostics.object();
+            write_rdjson_source(
+                &mut obj,
+                warning
+                    .code()
+                    .and_then(|code| code.info())
+                    .map(|info| info.category),
+            );
+            write_rdjson_code(&mut obj, warning.code());
+            obj.value("severity", "WARNING");
+            obj.value("message", &warning.into_inner().to_string());
         }
-        writer.value("severity", "ERROR");
-        {
-            let mut diagnostics = writer.array("diagnostics");
-            for (_, error) in self.check_errors {
-                let mut obj = diagnostics.object();
-                let position = error.position().unwrap_or_default();
-                obj.value("message", &error.into_inner().to_string());
-                {
-                    let mut location = obj.object("location");
-                    let file = (!position.builtin)
-                        .then(|| file_store.get_file(position.file))
-                        .flatten();
-                    if let Some((path, _, _)) = file {
-                        location.value("path", &path.to_string_lossy());
-                        let mut range = location.object("range");
-                        let mut start = range.object("start");
-                        start.value("line", position.line as u32 + 1);
-                        start.value("column", position.column as u32 + 1);
-                    }
+        for (_, error) in self.check_errors {
+            let mut obj = diagnostics.object();
+            let position = error.position().unwrap_or_default();
+            write_rdjson_source(&mut obj, error.category());
+            write_rdjson_code(&mut obj, error.code());
+            let suggestion = suggestion_message(error.suggestions());
+            let message = error.into_inner().to_string();
+            match suggestion {
+                Some(suggestion) => obj.value("message", &format!("{message}\n{suggestion}")),
+                None => obj.value("message", &message),
+            }
+            {
+                let mut location = obj.object("location");
+                let file = (!position.builtin)
+                    .then(|| file_store.get_file(position.file))
+                    .flatten();
+                if let Some((path, _, _)) = file {
+                    location.value("path", &path.to_string_lossy());
+                    let mut range = location.object("range");
+                    let mut start = range.object("start");
+                    start.value("line", position.line as u32 + 1);
+                    start.value("column", position.column as u32 + 1);
                 }
             }
         }
-        writer.end();
-        println!("{buffer}");
+        if let Some((_, code, message)) = self.command_error {
+            // Found errors are already reported above
+            if code == Some(codes::COMMAND_NOT_SUCCESSFUL) {
+                return;
+            }
+            let mut obj = diagnostics.object();
+            write_rdjson_source(
+                &mut obj,
+                code.and_then(|code| code.info()).map(|info| info.category),
+            );
+            write_rdjson_code(&mut obj, code);
+            obj.value("message", &message);
+        }
+    }
+}
+
+fn write_rdjson_source(obj: &mut JSONObjectWriter, category: Option<ErrorCategory>) {
+    let mut source = obj.object("source");
+    match category {
+        Some(category) => source.value("name", &format!("nitrogql/{category}")),
+        None => source.value("name", "nitrogql"),
+    }
+}
+
+fn write_rdjson_code(obj: &mut JSONObjectWriter, code: Option<ErrorCode>) {
+    if let Some(code) = code {
+        let mut code_obj = obj.object("code");
+        code_obj.value("value", &code.to_string());
+    }
+}
+
+/// Output for human consumption, grouped by project.
+pub fn human_output(outputs: Vec<(CliOutput, FileStore)>) {
+    for (output, file_store) in outputs {
+        if let Some(project) = output.project.as_ref() {
+            eprintln!("{}", format!("Project '{project}':").bold());
+        }
+        output.human_output(&file_store);
+    }
+}
+
+/// Output with JSON format.
+pub fn json_output(outputs: Vec<(CliOutput, FileStore)>) {
+    println!("{}", write_json_output(outputs));
+}
+
+/// Writes outputs with JSON format.
+/// Outputs of projects are put in the `projects` array when the config file has projects.
+pub(crate) fn write_json_output(outputs: Vec<(CliOutput, FileStore)>) -> String {
+    let mut buffer = String::new();
+    let mut writer = JSONObjectWriter::new(&mut buffer);
+    if outputs.iter().all(|(output, _)| output.project.is_none()) {
+        for (output, file_store) in outputs {
+            output.json_output(&mut writer, &file_store);
+        }
+    } else {
+        let mut projects = writer.array("projects");
+        for (output, file_store) in outputs {
+            let mut obj = projects.object();
+            obj.value("name", output.project.as_deref());
+            output.json_output(&mut obj, &file_store);
+        }
+    }
+    writer.end();
+    buffer
+}
+
+/// Output in rdjson format.
+pub fn rdjson_output(outputs: Vec<(CliOutput, FileStore)>) {
+    println!("{}", write_rdjson_output(outputs));
+}
+
+/// Writes outputs in rdjson format. Diagnostics of all projects are reported together.
+fn write_rdjson_output(outputs: Vec<(CliOutput, FileStore)>) -> String {
+    let mut buffer = String::new();
+    let mut writer = JSONObjectWriter::new(&mut buffer);
+    {
+        let mut source = writer.object("source");
+        source.value("name", "nitrogql");
+        source.value("url", "https://nitrogql.vercel.app/");
+    }
+    writer.value("severity", "ERROR");
+    {
+        let mut diagnostics = writer.array("diagnostics");
+        for (output, file_store) in outputs {
+            output.rdjson_output(&mut diagnostics, &file_store);
+        }
     }
+    writer.end();
+    buffer
 }
 
 impl Extend<(InputFileKind, PositionedError)> for CliOutput {
@@ -0,0 +1,216 @@
+use std::path::Path;
+
+use json_writer::{JSONArrayWriter, JSONObjectWriter};
+use nitrogql_ast::base::Pos;
+use nitrogql_error::{ERROR_CODES, PositionedError, suggestion_message};
+
+use crate::file_store::FileStore;
+
+use super::CliOutput;
+
+/// Id of the base URI that artifact locations are relative to.
+static ROOT_BASE_ID: &str = "PROJECTROOT";
+
+/// Id of the rule for errors without a known error code.
+/// This rule is listed after the rules of all error codes.
+static FALLBACK_RULE_ID: &str = "nitrogql";
+
+/// Output in SARIF 2.1.0 format.
+pub fn sarif_output(outputs: Vec<(CliOutput, FileStore)>) {
+    println!("{}", write_sarif_output(outputs));
+}
+
+/// Writes outputs in SARIF 2.1.0 format. Each project is reported as a separate run.
+pub(super) fn write_sarif_output(outputs: Vec<(CliOutput, FileStore)>) -> String {
+    let mut buffer = String::new();
+    let mut writer = JSONObjectWriter::new(&mut buffer);
+    writer.value("$schema", "https://json.schemastore.org/sarif-2.1.0.json");
+    writer.value("version", "2.1.0");
+    {
+        let mut runs = writer.array("runs");
+        for (output, file_store) in outputs {
+            output.sarif_run(&mut runs, &file_store);
+        }
+    }
+    writer.end();
+    buffer
+}
+
+impl CliOutput {
+    /// Writes a SARIF run for this output.
+    fn sarif_run(self, runs: &mut JSONArrayWriter, file_store: &FileStore) {
+        let mut run = runs.object();
+        if let Some(project) = self.project.as_ref() {
+            run.object("automationDetails")
+                .value("id", &format!("{project}/"));
+        }
+        {
+            let mut tool = run.object("tool");
+            let mut driver = tool.object("driver");
+            driver.value("name", "nitrogql");
+            driver.value("informationUri", "https://nitrogql.vercel.app/");
+            let mut rules = driver.array("rules");
+            for info in ERROR_CODES {
+                let mut rule = rules.object();
+                rule.value("id", &info.code.to_string());
+                rule.value("name", info.name);
+                rule.object("shortDescription").value("text", info.summary);
+                rule.object("fullDescription")
+                    .value("text", info.explanation);
+                rule.object("defaultConfiguration").value("level", "error");
+                rule.object("properties")
+                    .value("category", info.category.as_str());
+            }
+            let mut rule = rules.object();
+            rule.value("id", FALLBACK_RULE_ID);
+            rule.object("shortDescription")
+                .value("text", "An error without an error code.");
+            rule.object("defaultConfiguration").value("level", "error");
+        }
+        if let Some(root_dir) = self.root_dir.as_ref() {
+            let mut base_ids = run.object("originalUriBaseIds");
+            let mut root = base_ids.object(ROOT_BASE_ID);
+            let mut uri = path_to_uri(root_dir);
+            if !uri.ends_with('/') {
+                uri.push('/');
+            }
+            root.value("uri", &format!("file://{uri}"));
+        }
+        {
+            // Failing because of found errors is a successful execution of the tool
+            let tool_error = self
+                .command_error
+                .as_ref()
+                .filter(|_| self.check_errors.is_empty());
+            let mut invocations = run.array("invocations");
+            let mut invocation = invocations.object();
+            invocation.value("executionSuccessful", tool_error.is_none());
+            let mut notifications = invocation.array("toolExecutionNotifications");
+            if let Some((_, _, message)) = tool_error {
+                let mut notification = notifications.object();
+                notification.value("level", "error");
+                notification.object("message").value("text", message);
+            }
+        }
+        // Columns are counted in characters
+        run.value("columnKind", "unicodeCodePoints");
+        let mut results = run.array("results");
+        let root_dir = self.root_dir.as_deref();
+        for warning in self.warnings {
+            write_result(&mut results, warning, "warning", file_store, root_dir);
+        }
+        for (_, error) in self.check_errors {
+            write_result(&mut results, error, "error", file_store, root_dir);
+        }
+    }
+}
+
+/// Writes a SARIF result for given error.
+fn write_result(
+    results: &mut JSONArrayWriter,
+    error: PositionedError,
+    level: &str,
+    file_store: &FileStore,
+    root_dir: Option<&Path>,
+) {
+    let mut result = results.object();
+    let rule_index = error
+        .code()
+        .and_then(|code| ERROR_CODES.iter().position(|info| info.code == code));
+    match rule_index {
+        Some(index) => {
+            result.value("ruleId", &ERROR_CODES[index].code.to_string());
+            result.value("ruleIndex", index as u32);
+        }
+        None => {
+            result.value("ruleId", FALLBACK_RULE_ID);
+            result.value("ruleIndex", ERROR_CODES.len() as u32);
+        }
+    }
+    result.value("level", level);
+    let position = error.position();
+    let end = error.end();
+    let related_locations = error
+        .additional_info()
+        .iter()
+        .map(|(pos, message)| (*pos, message.clone()))
+        .collect::<Vec<_>>();
+    let suggestion = suggestion_message(error.suggestions());
+    {
+        let message = error.into_inner().to_string();
+        let mut message_obj = result.object("message");
+        match suggestion {
+            Some(suggestion) => message_obj.value("text", &format!("{message}\n{suggestion}")),
+            None => message_obj.value("text", &message),
+        }
+    }
+    {
+        let mut locations = result.array("locations");
+        if let Some(pos) = position {
+            write_physical_location(&mut locations.object(), pos, end, file_store, root_dir);
+        }
+    }
+    let mut related = result.array("relatedLocations");
+    for (id, (pos, message)) in related_locations.into_iter().enumerate() {
+        let mut location = related.object();
+        location.value("id", id as u32);
+        location.object("message").value("text", &message);
+        write_physical_location(&mut location, pos, None, file_store, root_dir);
+    }
+}
+
+/// Writes `physicalLocation` of given position. Nothing is written for built-in positions.
+fn write_physical_location(
+    location: &mut JSONObjectWriter,
+    pos: Pos,
+    end: Option<Pos>,
+    file_store: &FileStore,
+    root_dir: Option<&Path>,
+) {
+    if pos.builtin {
+        return;
+    }
+    let Some((path, _, _)) = file_store.get_file(pos.file) else {
+        return;
+    };
+    let mut physical = location.object("physicalLocation");
+    {
+        let mut artifact = physical.object("artifactLocation");
+        match root_dir.and_then(|root_dir| path.strip_prefix(root_dir).ok()) {
+            Some(relative) => {
+                artifact.value("uri", &path_to_uri(relative));
+                artifact.value("uriBaseId", ROOT_BASE_ID);
+            }
+            None => {
+                artifact.value("uri", &format!("file://{}", path_to_uri(path)));
+            }
+        }
+    }
+    // SARIF uses 1-based indices
+    let mut region = physical.object("region");
+    region.value("startLine", pos.line as u32 + 1);
+    region.value("startColumn", pos.column as u32 + 1);
+    if let Some(end) = end {
+        region.value("endLine", end.line as u32 + 1);
+        region.value("endColumn", end.column as u32 + 1);
+    }
+}
+
+/// Converts a path to a URI reference with percent-encoding.
+pub(super) fn path_to_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut result = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    // Windows absolute paths need a leading slash (file:///C:/...)
+    if !result.starts_with('/') && path.as_bytes().get(1) == Some(&b':') {
+        result = format!("/{}", result.replacen("%3A", ":", 1));
+    }
+    result
+}
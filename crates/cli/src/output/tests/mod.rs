@@ -0,0 +1,243 @@
+#![cfg(test)]
+
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use insta::assert_snapshot;
+use nitrogql_ast::base::Pos;
+use nitrogql_checker::{CheckError, CheckErrorMessage};
+use nitrogql_error::{ERROR_CODES, ErrorCategory, PositionedError};
+
+use crate::{
+    error::CliError,
+    file_store::{FileKind, FileStore, SourceArena},
+};
+
+use super::{
+    CliOutput, InputFileKind,
+    sarif::{path_to_uri, write_sarif_output},
+    write_json_output, write_rdjson_output,
+};
+
+fn pos(file: usize, line: usize, column: usize) -> Pos {
+    Pos {
+        line,
+        column,
+        file,
+        builtin: false,
+    }
+}
+
+fn file_store(sources: &SourceArena) -> FileStore<'_> {
+    let mut file_store = FileStore::new(sources);
+    file_store.add_file(
+        PathBuf::from("schema.graphql"),
+        "type Query {\n  me: Usr\n}\n".to_owned(),
+        FileKind::Schema,
+    );
+    file_store.add_file(
+        PathBuf::from("query.graphql"),
+        "query Me @lve {\n  me { id }\n}\n".to_owned(),
+        FileKind::Operation,
+    );
+    file_store
+}
+
+/// Output of a failed check command.
+fn check_output(project: Option<&str>) -> CliOutput {
+    let mut output = CliOutput::new();
+    output.project(project.map(ToOwned::to_owned));
+    output.command_run("check".to_owned());
+    output.extend([
+        (
+            InputFileKind::Schema,
+            CheckError {
+                position: pos(0, 1, 6),
+                message: CheckErrorMessage::UnknownType {
+                    name: "Usr".to_owned(),
+                    suggestions: vec!["User".to_owned()],
+                },
+                additional_info: vec![],
+            }
+            .into_positioned_error(ErrorCategory::Schema),
+        ),
+        (
+            InputFileKind::Operation,
+            CheckError {
+                position: pos(1, 0, 9),
+                message: CheckErrorMessage::UnknownDirective {
+                    name: "lve".to_owned(),
+                    suggestions: vec!["live".to_owned()],
+                },
+                additional_info: vec![],
+            }
+            .into_positioned_error(ErrorCategory::Operation),
+        ),
+        (
+            InputFileKind::Schema,
+            CheckError {
+                position: pos(0, 0, 0),
+                message: CheckErrorMessage::Plugin {
+                    message: "Query must have a description".to_owned(),
+                },
+                additional_info: vec![],
+            }
+            .into_positioned_error(ErrorCategory::Schema),
+        ),
+    ]);
+    output.command_error(
+        Some("check".to_owned()),
+        Some(CliError::CommandNotSuccessful("check".into()).code()),
+        "Command not successful: check".to_owned(),
+    );
+    output
+}
+
+/// Output of a command that failed without check errors.
+fn command_error_output() -> CliOutput {
+    let mut output = CliOutput::new();
+    let error = CliError::NoSchemaSpecified;
+    output.command_error(None, Some(error.code()), error.to_string());
+    output
+}
+
+#[test]
+fn category_from_checker() {
+    let error = CheckError {
+        position: pos(1, 0, 9),
+        message: CheckErrorMessage::UnknownType {
+            name: "Usr".to_owned(),
+            suggestions: vec![],
+        },
+        additional_info: vec![],
+    };
+    let error: PositionedError = error.into_positioned_error(ErrorCategory::Operation);
+    assert_eq!(error.category(), Some(ErrorCategory::Operation));
+}
+
+#[test]
+fn json_check_errors() {
+    let sources = SourceArena::new();
+    let output = write_json_output(vec![(check_output(None), file_store(&sources))]);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn json_command_error() {
+    let sources = SourceArena::new();
+    let output = write_json_output(vec![(command_error_output(), file_store(&sources))]);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn json_projects() {
+    let sources = SourceArena::new();
+    let mut lib = CliOutput::new();
+    lib.project(Some("lib".to_owned()));
+    lib.command_run("check".to_owned());
+    lib.command_finished("check".to_owned());
+    let output = write_json_output(vec![
+        (check_output(Some("app")), file_store(&sources)),
+        (lib, file_store(&sources)),
+    ]);
+    assert_snapshot!(output);
+}
+
+/// Output of a successful check command with a warning.
+fn warning_output() -> CliOutput {
+    let mut output = CliOutput::new();
+    output.command_run("check".to_owned());
+    let warning = CliError::PatternMatchesNoFiles("src/**/*.graphql".to_owned());
+    let code = warning.code();
+    output.warning(PositionedError::from(warning).with_code(code));
+    output.command_finished("check".to_owned());
+    output
+}
+
+#[test]
+fn json_warnings() {
+    let sources = SourceArena::new();
+    let output = write_json_output(vec![(warning_output(), file_store(&sources))]);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn rdjson_warnings() {
+    let sources = SourceArena::new();
+    let output = write_rdjson_output(vec![(warning_output(), file_store(&sources))]);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn rdjson_check_errors() {
+    let sources = SourceArena::new();
+    let output = write_rdjson_output(vec![(check_output(None), file_store(&sources))]);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn rdjson_command_error() {
+    let sources = SourceArena::new();
+    let output = write_rdjson_output(vec![(command_error_output(), file_store(&sources))]);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn sarif_path_to_uri() {
+    assert_eq!(
+        path_to_uri(Path::new("schema/a b.graphql")),
+        "schema/a%20b.graphql"
+    );
+    assert_eq!(
+        path_to_uri(Path::new("/home/user/project")),
+        "/home/user/project"
+    );
+    assert_eq!(
+        path_to_uri(Path::new("スキーマ.graphql")),
+        "%E3%82%B9%E3%82%AD%E3%83%BC%E3%83%9E.graphql"
+    );
+    assert_eq!(
+        path_to_uri(Path::new("C:\\project\\schema.graphql")),
+        "/C:/project/schema.graphql"
+    );
+}
+
+#[test]
+fn sarif_results() {
+    let sources = SourceArena::new();
+    let mut file_store = FileStore::new(&sources);
+    file_store.add_file(
+        PathBuf::from("/project/schema.graphql"),
+        "type Query {\n  me: Usr\n}\n".to_owned(),
+        FileKind::Schema,
+    );
+    file_store.add_file(
+        PathBuf::from("/other/query.graphql"),
+        "query Me {\n  me { id }\n}\n".to_owned(),
+        FileKind::Operation,
+    );
+    let mut output = check_output(Some("app"));
+    output.root_dir(Path::new("/project"));
+    let warning = CliError::PatternMatchesNoFiles("src/**/*.graphql".to_owned());
+    let code = warning.code();
+    output.warning(PositionedError::from(warning).with_code(code));
+    output.extend([(
+        InputFileKind::Operation,
+        PositionedError::new(
+            anyhow!("Error without code"),
+            Some(pos(1, 1, 2)),
+            vec![(pos(0, 1, 2), "Related location".to_owned())],
+        )
+        .with_end(pos(1, 1, 4)),
+    )]);
+    let output = write_sarif_output(vec![(output, file_store)]);
+    let mut sarif: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let run = &mut sarif["runs"][0];
+    let rules = run["tool"]["driver"]["rules"].take();
+    let rules = rules.as_array().unwrap();
+    assert_eq!(rules.len(), ERROR_CODES.len() + 1);
+    assert_eq!(rules[0]["id"], "NGQL0001");
+    assert_eq!(rules[ERROR_CODES.len()]["id"], "nitrogql");
+    assert_snapshot!(serde_json::to_string_pretty(&sarif).unwrap());
+}
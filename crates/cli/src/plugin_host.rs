@@ -1,17 +1,17 @@
 use crate::file_store::{FileKind, FileStore};
 
-pub struct PluginHost<'host> {
-    pub file_store: &'host mut FileStore,
+pub struct PluginHost<'host, 'src> {
+    pub file_store: &'host mut FileStore<'src>,
 }
 
-impl<'host> PluginHost<'host> {
-    pub fn new(file_store: &'host mut FileStore) -> Self {
+impl<'host, 'src> PluginHost<'host, 'src> {
+    pub fn new(file_store: &'host mut FileStore<'src>) -> Self {
         Self { file_store }
     }
 }
 
-impl nitrogql_plugin::PluginHost for PluginHost<'_> {
-    fn load_virtual_file(&mut self, content: String) -> &'static str {
+impl<'src> nitrogql_plugin::PluginHost<'src> for PluginHost<'_, 'src> {
+    fn load_virtual_file(&mut self, content: String) -> &'src str {
         let index = self
             .file_store
             .add_file("(plugin)".into(), content, FileKind::Schema);
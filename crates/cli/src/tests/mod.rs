@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use clap::Parser;
+use futures::executor::block_on;
+use insta::assert_snapshot;
+
+use crate::{Args, ProjectRun, file_store::SourceArena, output::write_json_output, run_cli_impl};
+
+/// Creates a directory with a config file that has two projects.
+/// The operation of `lib` refers to an unknown field.
+fn projects_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("nitrogql-cli-{name}-{}", process::id()));
+    for project in ["app", "lib"] {
+        fs::create_dir_all(dir.join(project).join("operations")).unwrap();
+        fs::write(
+            dir.join(project).join("schema.graphql"),
+            "type Query {\n  me: String!\n}\n",
+        )
+        .unwrap();
+    }
+    fs::write(
+        dir.join("app/operations/query.graphql"),
+        "query Me {\n  me\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("lib/operations/query.graphql"),
+        "query Me {\n  you\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("graphql.config.yaml"),
+        r#"
+projects:
+    app:
+        schema: app/schema.graphql
+        documents: app/operations/*.graphql
+    lib:
+        schema: lib/schema.graphql
+        documents: lib/operations/*.graphql
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+fn json_output(runs: Vec<ProjectRun>) -> String {
+    write_json_output(
+        runs.into_iter()
+            .map(|run| (run.output, run.file_store))
+            .collect(),
+    )
+}
+
+fn run<'src>(sources: &'src SourceArena, dir: &Path, args: &[&str]) -> Vec<ProjectRun<'src>> {
+    let config_file = dir.join("graphql.config.yaml");
+    let args = ["nitrogql", "--config-file", config_file.to_str().unwrap()]
+        .into_iter()
+        .chain(args.iter().copied());
+    block_on(run_cli_impl(Args::parse_from(args), sources))
+}
+
+#[test]
+fn all_projects_are_run() {
+    let dir = projects_dir("all-projects");
+    let sources = SourceArena::new();
+    let output: serde_json::Value =
+        serde_json::from_str(&json_output(run(&sources, &dir, &["check"]))).unwrap();
+    let projects = output["projects"].as_array().unwrap();
+    let results = projects
+        .iter()
+        .map(|project| {
+            (
+                project["name"].as_str().unwrap(),
+                project["check"]["errors"].as_array().unwrap().len(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(results, vec![("app", 0), ("lib", 1)]);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn project_option() {
+    let dir = projects_dir("project-option");
+    let sources = SourceArena::new();
+    let runs = run(&sources, &dir, &["--project", "app", "check"]);
+    // output is grouped by project even when only one is run
+    assert_snapshot!(json_output(runs));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn unknown_project() {
+    let dir = projects_dir("unknown-project");
+    let sources = SourceArena::new();
+    let runs = run(&sources, &dir, &["--project", "web", "check"]);
+    assert_snapshot!(json_output(runs));
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -1,24 +1,212 @@
-use std::{collections::HashMap, path::PathBuf, str::FromStr};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
+use glob::{MatchOptions, Pattern};
+use nitrogql_utils::clone_into;
 use serde::Deserialize;
 
-use crate::{parsing_utils::deserialize_fromstr, scalar_type::ScalarTypeConfig};
+use crate::{
+    parsing_utils::{deserialize_fromstr, deserialize_fromstr_option, deserialize_patterns},
+    scalar_type::ScalarTypeConfig,
+};
+
+/// A project defined in a config file.
+#[derive(Debug)]
+pub struct Project {
+    /// Name of the project.
+    /// None if the config file does not use `projects`.
+    pub name: Option<String>,
+    pub config: Config,
+}
 
 #[derive(Debug, Default)]
 pub struct Config {
     /// Path(s) to schema definition files.
+    /// Patterns starting with `!` exclude matched files.
     pub schema: Vec<String>,
     /// Path(s) to operation definition files.
+    /// Patterns starting with `!` exclude matched files.
     pub operations: Vec<String>,
+    /// Patterns of files excluded from both schema and operations.
+    pub exclude: Vec<String>,
     // extensions
     /// List of plugins.
     pub plugins: Vec<String>,
+    /// Aliases for paths in `#import`, in the form of tsconfig's `paths`.
+    /// Targets are relative to the config file.
+    pub paths: BTreeMap<String, Vec<String>>,
+    /// Entry point of modular schema. When set, schema files are loaded
+    /// by following `#import` from this file instead of `schema`.
+    pub schema_entry: Option<String>,
+    /// Whether files ignored by git are excluded from schema and operations.
+    pub respect_gitignore: bool,
+    pub check: CheckConfig,
     pub generate: GenerateConfig,
+    /// Overrides of `generate` for operation files matching patterns.
+    pub overrides: Vec<GenerateOverride>,
 }
 
-/// Config related to the 'generate' command.
+impl Config {
+    /// Returns the generate config for given operation file, with matching overrides applied.
+    /// Overrides are applied from the least specific one, so the most specific one wins.
+    pub fn generate_for_file(&self, root_dir: &Path, file: &Path) -> Cow<'_, GenerateConfig> {
+        let mut matched = self
+            .overrides
+            .iter()
+            .enumerate()
+            .filter_map(|(index, o)| o.specificity(root_dir, file).map(|s| (s, index, o)))
+            .collect::<Vec<_>>();
+        if matched.is_empty() {
+            return Cow::Borrowed(&self.generate);
+        }
+        // later overrides win among equally specific ones
+        matched.sort_by_key(|(specificity, index, _)| (*specificity, *index));
+        let mut generate = self.generate.clone();
+        for (_, _, o) in matched {
+            o.generate.apply_to(&mut generate);
+        }
+        Cow::Owned(generate)
+    }
+
+    /// Returns whether given file is matched by `operations`.
+    /// Files matched by patterns starting with `!` or by `exclude` are not included.
+    pub fn includes_operation(&self, root_dir: &Path, file: &Path) -> bool {
+        let (excludes, includes): (Vec<_>, Vec<_>) = self
+            .operations
+            .iter()
+            .map(String::as_str)
+            .partition(|pattern| pattern.starts_with('!'));
+        includes
+            .into_iter()
+            .any(|pattern| match_relative_pattern(root_dir, pattern, file).is_some())
+            && !excludes
+                .into_iter()
+                .map(|pattern| &pattern[1..])
+                .chain(self.exclude.iter().map(String::as_str))
+                .any(|pattern| match_relative_pattern(root_dir, pattern, file).is_some())
+    }
+}
+
+const OVERRIDE_MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Override of the generate config for operation files matching `files`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenerateOverride {
+    /// Patterns of files relative to the config file.
+    #[serde(deserialize_with = "deserialize_patterns")]
+    pub files: Vec<String>,
+    pub generate: GenerateOverrideConfig,
+}
+
+impl GenerateOverride {
+    /// If `file` matches this override, returns how specific the matched pattern is.
+    /// Specificity is the length of the pattern before the first wildcard.
+    fn specificity(&self, root_dir: &Path, file: &Path) -> Option<usize> {
+        self.files
+            .iter()
+            .filter_map(|pattern| match_relative_pattern(root_dir, pattern, file))
+            .max()
+    }
+}
+
+/// Matches `file` against a pattern relative to `root_dir`.
+/// If matched, returns the length of the pattern before the first wildcard.
+fn match_relative_pattern(root_dir: &Path, pattern: &str, file: &Path) -> Option<usize> {
+    let (base, rest) = split_relative_prefix(root_dir, pattern);
+    let base = Pattern::escape(&base.to_string_lossy());
+    Pattern::new(&format!("{base}/{rest}"))
+        .ok()?
+        .matches_path_with(file, OVERRIDE_MATCH_OPTIONS)
+        .then(|| base.len() + rest.find(['*', '?', '[']).unwrap_or(rest.len()))
+}
+
+/// Applies leading `./` and `../` of a pattern to `root_dir`.
+/// Returns the resulting directory and the rest of the pattern.
+fn split_relative_prefix<'a>(root_dir: &Path, pattern: &'a str) -> (PathBuf, &'a str) {
+    let mut base = root_dir.to_owned();
+    let mut rest = pattern;
+    loop {
+        if let Some(r) = rest.strip_prefix("./") {
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("../") {
+            base.pop();
+            rest = r;
+        } else {
+            return (base, rest);
+        }
+    }
+}
+
+/// Part of the generate config that can be overridden for specific files.
 #[derive(Debug, Default, Deserialize)]
-#[serde(default, rename_all = "camelCase")]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct GenerateOverrideConfig {
+    #[serde(deserialize_with = "deserialize_fromstr_option")]
+    pub mode: Option<GenerateMode>,
+    pub schema_module_specifier: Option<String>,
+    pub name: GenerateNameConfig,
+    pub export: GenerateExportOverrideConfig,
+}
+
+impl GenerateOverrideConfig {
+    /// Overwrites given config with values specified in this override.
+    fn apply_to(&self, generate: &mut GenerateConfig) {
+        if let Some(mode) = self.mode {
+            generate.mode = mode;
+        }
+        if self.schema_module_specifier.is_some() {
+            generate.schema_module_specifier = self.schema_module_specifier.clone();
+        }
+        self.name.apply_to(&mut generate.name);
+        self.export.apply_to(&mut generate.export);
+    }
+}
+
+/// Config related to exported names, in an override.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct GenerateExportOverrideConfig {
+    pub default_export_for_operation: Option<bool>,
+    pub operation_result_type: Option<bool>,
+    pub variables_type: Option<bool>,
+}
+
+impl GenerateExportOverrideConfig {
+    fn apply_to(&self, export: &mut GenerateExportConfig) {
+        clone_into(
+            &self.default_export_for_operation,
+            &mut export.default_export_for_operation,
+        );
+        clone_into(
+            &self.operation_result_type,
+            &mut export.operation_result_type,
+        );
+        clone_into(&self.variables_type, &mut export.variables_type);
+    }
+}
+
+/// Config related to the 'check' command.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct CheckConfig {
+    /// Whether import cycles between operation documents are reported as errors.
+    pub no_import_cycles: bool,
+    /// Whether imported fragments that are never used are reported as errors.
+    pub no_unused_imports: bool,
+}
+
+/// Config related to the 'generate' command.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 pub struct GenerateConfig {
     /// Mode of generation.
     #[serde(deserialize_with = "deserialize_fromstr")]
@@ -29,6 +217,11 @@ pub struct GenerateConfig {
     pub server_graphql_output: Option<PathBuf>,
     /// Output file path for resolvers.
     pub resolvers_output: Option<PathBuf>,
+    /// Output file path for the module that contains all operations.
+    /// Used in the client-preset mode.
+    pub client_preset_output: Option<PathBuf>,
+    /// Output file path for Zod validators of input types and variables.
+    pub validators_output: Option<PathBuf>,
     /// Module specifier for import schema types from operations.
     /// Defaults to relative paths.
     pub schema_module_specifier: Option<String>,
@@ -38,8 +231,33 @@ pub struct GenerateConfig {
     pub name: GenerateNameConfig,
     /// Config related to exporting generated names.
     pub export: GenerateExportConfig,
+    /// Config related to the generated resolvers file.
+    pub resolvers: GenerateResolversConfig,
     /// Whether to emit runtime for generated schema types.
     pub emit_schema_runtime: bool,
+    /// Whether to insert `__typename` into every selection set of operations
+    /// except root operation selections.
+    pub add_typename: bool,
+    /// Format of operation documents emitted as runtime values.
+    #[serde(deserialize_with = "deserialize_fromstr")]
+    pub document_format: DocumentFormat,
+}
+
+impl GenerateConfig {
+    /// Returns the style of enums, taking the default into account.
+    pub fn enum_style(&self) -> EnumStyle {
+        self.r#type
+            .enum_style
+            .unwrap_or(if self.emit_schema_runtime {
+                EnumStyle::ConstObject
+            } else {
+                EnumStyle::Union
+            })
+    }
+    /// Whether the schema output contains runtime code.
+    pub fn schema_has_runtime(&self) -> bool {
+        self.emit_schema_runtime || self.enum_style().has_runtime()
+    }
 }
 
 /// Mode of code generation.
@@ -52,6 +270,9 @@ pub enum GenerateMode {
     WithLoaderTS4_0,
     /// To be used standalone. Emits .graphql.ts that are supported by TS 4.0
     StandaloneTS4_0,
+    /// To be used standalone. Emits one module that contains all operations
+    /// and a `graphql` function to look them up by source text.
+    ClientPreset,
 }
 
 pub struct FromStrError;
@@ -63,34 +284,147 @@ impl FromStr for GenerateMode {
             "with-loader-ts-5.0" => Ok(GenerateMode::WithLoaderTS5_0),
             "with-loader-ts-4.0" => Ok(GenerateMode::WithLoaderTS4_0),
             "standalone-ts-4.0" => Ok(GenerateMode::StandaloneTS4_0),
+            "client-preset" => Ok(GenerateMode::ClientPreset),
+            _ => Err(FromStrError),
+        }
+    }
+}
+
+/// Format of operation documents emitted as runtime values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DocumentFormat {
+    /// JSON representation of `DocumentNode`, typed as `TypedDocumentNode`.
+    #[default]
+    DocumentNode,
+    /// Printed GraphQL source, typed as `TypedDocumentString`.
+    String,
+    /// Printed GraphQL source without insignificant whitespace, typed as `TypedDocumentString`.
+    MinifiedString,
+}
+
+impl FromStr for DocumentFormat {
+    type Err = FromStrError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "document-node" => Ok(DocumentFormat::DocumentNode),
+            "string" => Ok(DocumentFormat::String),
+            "minified-string" => Ok(DocumentFormat::MinifiedString),
             _ => Err(FromStrError),
         }
     }
 }
 
 /// Config related to generated types.
-#[derive(Debug, Deserialize)]
-#[serde(default, rename_all = "camelCase")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 pub struct GenerateTypeConfig {
     /// Type of scalars.
     pub scalar_types: HashMap<String, ScalarTypeConfig>,
+    /// Zod validators of scalars. Provided as raw TypeScript code.
+    pub scalar_validators: HashMap<String, String>,
     /// Whether to allow undefined as input value
     /// for nullable input fields.
     pub allow_undefined_as_optional_input: bool,
+    /// Whether fragment spreads are masked in result types.
+    pub fragment_masking: bool,
+    /// Whether the client throws on field errors instead of
+    /// returning null. If true, positions marked by `@semanticNonNull`
+    /// are typed as non-null in operation results.
+    pub throw_on_field_error: bool,
+    /// Whether to add a catch-all branch to result types of
+    /// unions and interfaces for object types unknown at build time.
+    pub future_proof_abstract_types: bool,
+    /// Scalars whose TypeScript types are branded with their names.
+    pub branded_scalars: Vec<String>,
+    /// How enums are emitted. Defaults to `ConstObject` if schema runtime
+    /// is emitted and `Union` otherwise.
+    #[serde(deserialize_with = "deserialize_fromstr_option")]
+    pub enum_style: Option<EnumStyle>,
+    /// Casing of member names of TypeScript enums and keys of const objects.
+    #[serde(deserialize_with = "deserialize_fromstr")]
+    pub enum_member_case: EnumMemberCase,
 }
 
 impl Default for GenerateTypeConfig {
     fn default() -> Self {
         Self {
             scalar_types: HashMap::new(),
+            scalar_validators: HashMap::new(),
             allow_undefined_as_optional_input: true,
+            fragment_masking: false,
+            throw_on_field_error: false,
+            future_proof_abstract_types: false,
+            branded_scalars: vec![],
+            enum_style: None,
+            enum_member_case: EnumMemberCase::default(),
+        }
+    }
+}
+
+/// How enums are emitted in generated schema types.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EnumStyle {
+    /// Union of string literal types.
+    Union,
+    /// Union of string literal types. In operation results,
+    /// unknown strings are also allowed for forward compatibility.
+    UnionWithFallback,
+    /// Union of string literal types, plus a const object that has all values.
+    ConstObject,
+    /// TypeScript `enum`.
+    Enum,
+}
+
+impl EnumStyle {
+    /// Whether this style needs runtime code in the schema output.
+    pub fn has_runtime(self) -> bool {
+        matches!(self, EnumStyle::ConstObject | EnumStyle::Enum)
+    }
+}
+
+impl FromStr for EnumStyle {
+    type Err = FromStrError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "union" => Ok(EnumStyle::Union),
+            "union-with-fallback" => Ok(EnumStyle::UnionWithFallback),
+            "const-object" => Ok(EnumStyle::ConstObject),
+            "enum" => Ok(EnumStyle::Enum),
+            _ => Err(FromStrError),
+        }
+    }
+}
+
+/// Casing of enum member names in generated code.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum EnumMemberCase {
+    /// Same as enum values in the schema.
+    #[default]
+    Keep,
+    /// e.g. `PremiumUser`
+    PascalCase,
+    /// e.g. `premiumUser`
+    CamelCase,
+    /// e.g. `PREMIUM_USER`
+    ScreamingSnakeCase,
+}
+
+impl FromStr for EnumMemberCase {
+    type Err = FromStrError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "keep" => Ok(EnumMemberCase::Keep),
+            "pascal-case" => Ok(EnumMemberCase::PascalCase),
+            "camel-case" => Ok(EnumMemberCase::CamelCase),
+            "screaming-snake-case" => Ok(EnumMemberCase::ScreamingSnakeCase),
+            _ => Err(FromStrError),
         }
     }
 }
 
 /// Config related to names of generated variables and types.
-#[derive(Debug, Default, Deserialize)]
-#[serde(default, rename_all = "camelCase")]
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 pub struct GenerateNameConfig {
     /// Suffix for type of operation result.
     pub operation_result_type_suffix: Option<String>,
@@ -110,9 +444,44 @@ pub struct GenerateNameConfig {
     pub fragment_variable_suffix: Option<String>,
 }
 
+impl GenerateNameConfig {
+    /// Overwrites given config with values specified in self.
+    fn apply_to(&self, name: &mut GenerateNameConfig) {
+        let fields = [
+            (
+                &self.operation_result_type_suffix,
+                &mut name.operation_result_type_suffix,
+            ),
+            (&self.variables_type_suffix, &mut name.variables_type_suffix),
+            (&self.fragment_type_suffix, &mut name.fragment_type_suffix),
+            (&self.query_variable_suffix, &mut name.query_variable_suffix),
+            (
+                &self.mutation_variable_suffix,
+                &mut name.mutation_variable_suffix,
+            ),
+            (
+                &self.subscription_variable_suffix,
+                &mut name.subscription_variable_suffix,
+            ),
+            (
+                &self.fragment_variable_suffix,
+                &mut name.fragment_variable_suffix,
+            ),
+        ];
+        for (value, target) in fields {
+            if value.is_some() {
+                target.clone_from(value);
+            }
+        }
+        if self.capitalize_operation_names.is_some() {
+            name.capitalize_operation_names = self.capitalize_operation_names;
+        }
+    }
+}
+
 /// Config related to exported names.
-#[derive(Debug, Deserialize)]
-#[serde(default, rename_all = "camelCase")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 pub struct GenerateExportConfig {
     /// Whether operation is exported as a default export.
     /// Effective only when a document contains only one operation.
@@ -132,3 +501,29 @@ impl Default for GenerateExportConfig {
         }
     }
 }
+
+/// Config related to the generated resolvers file.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct GenerateResolversConfig {
+    /// Name of the root resolvers type.
+    pub root_type_name: Option<String>,
+    /// Name of the resolver output type.
+    pub output_type_name: Option<String>,
+    /// Name of the namespace that schema types are imported as.
+    pub schema_namespace: Option<String>,
+    /// Default type of context passed to resolvers.
+    pub context_type: Option<TypeImportConfig>,
+    /// Type of the `info` argument of resolvers.
+    pub info_type: Option<TypeImportConfig>,
+}
+
+/// A type exported from a module.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeImportConfig {
+    /// Module specifier to import the type from. Emitted to the generated file as is.
+    pub module: String,
+    /// Exported name of the type.
+    pub name: String,
+}
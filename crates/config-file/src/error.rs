@@ -1,13 +1,90 @@
 use std::{io, path::PathBuf};
 
+use nitrogql_ast::base::Pos;
+use nitrogql_error::{PositionedError, codes};
 use thiserror::Error;
 
+use crate::DEFAULT_PROJECT_NAME;
+
 #[derive(Error, Debug)]
 pub enum ConfigFileError {
     #[error(transparent)]
     IO(#[from] io::Error),
-    #[error("Error loading config file: {0}")]
-    Yaml(#[from] serde_yaml::Error),
-    #[error("Cannot load config file '{0}': validation error")]
-    Validation(PathBuf),
+    #[error("Cannot load config file '{}': {}", path.display(), error.message)]
+    Validation {
+        /// Path to the config file.
+        path: PathBuf,
+        /// Content of the config file.
+        /// For config files written in JavaScript, this is their JSON output
+        /// and the error has no position.
+        content: String,
+        error: Box<ConfigValidationError>,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigValidationErrorMessage {
+    #[error("Unknown key '{key}'{}.", in_path(path))]
+    UnknownKey {
+        key: String,
+        /// Dot-separated path to the object that contains the key.
+        path: Option<String>,
+        suggestions: Vec<String>,
+    },
+    #[error("{0}.")]
+    Invalid(String),
+    #[error("Project '{DEFAULT_PROJECT_NAME}' is not defined in the config file.")]
+    NoDefaultProject,
+    #[error("Cannot resolve '{extends}' in 'extends': {reason}.")]
+    ExtendsNotResolved { extends: String, reason: String },
+    #[error("Config files extend each other: {}.", files.join(" -> "))]
+    ExtendsCycle {
+        /// Config files forming the cycle, starting and ending with the same file.
+        files: Vec<String>,
+    },
+}
+
+fn in_path(path: &Option<String>) -> String {
+    match path {
+        Some(path) => format!(" in '{path}'"),
+        None => String::new(),
+    }
+}
+
+/// Error found in the content of a config file.
+#[derive(Debug)]
+pub struct ConfigValidationError {
+    pub message: ConfigValidationErrorMessage,
+    /// 0-based line and column where the error is found.
+    pub position: Option<(usize, usize)>,
+}
+
+impl From<ConfigValidationError> for PositionedError {
+    /// Converts to a PositionedError.
+    /// Position is attached to the current file of Pos.
+    fn from(value: ConfigValidationError) -> Self {
+        let position = value.position.map(|(line, column)| Pos::new(line, column));
+        let (code, fields, suggestions) = match &value.message {
+            ConfigValidationErrorMessage::UnknownKey {
+                key,
+                path,
+                suggestions,
+            } => {
+                let mut fields = vec![("key", key.clone())];
+                fields.extend(path.iter().map(|path| ("path", path.clone())));
+                (codes::UNKNOWN_CONFIG_KEY, fields, suggestions.clone())
+            }
+            ConfigValidationErrorMessage::Invalid(_)
+            | ConfigValidationErrorMessage::NoDefaultProject
+            | ConfigValidationErrorMessage::ExtendsNotResolved { .. }
+            | ConfigValidationErrorMessage::ExtendsCycle { .. } => {
+                (codes::INVALID_CONFIG, vec![], vec![])
+            }
+        };
+
+        PositionedError::new(value.message.into(), position, vec![])
+            .with_code(code)
+            .with_fields(fields)
+            .with_suggestions(suggestions)
+    }
 }
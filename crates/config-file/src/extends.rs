@@ -0,0 +1,319 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    mem,
+    path::{Component, Path, PathBuf},
+};
+
+use nitrogql_semantics::{FileLookup, ImportPathError, ImportPathResolver, ResolutionHost};
+use nitrogql_utils::relative_path;
+use serde_yaml::Value;
+
+use crate::{
+    ConfigFileError, ConfigValidationError, ConfigValidationErrorMessage, Project,
+    parse_config::{parse_extends, parse_projects_from_value},
+};
+
+/// Config loaded from a config file and the config files it extends.
+#[derive(Debug)]
+pub struct LoadedConfig {
+    /// Loaded config files. The first one is the config file itself,
+    /// and each file extends the next one.
+    pub files: Vec<PathBuf>,
+    pub projects: Vec<Project>,
+}
+
+/// One config file in a chain of `extends`.
+#[derive(Debug)]
+struct ConfigSource {
+    path: PathBuf,
+    content: String,
+}
+
+impl ConfigSource {
+    fn validation_error(&self, mut error: ConfigValidationError) -> ConfigFileError {
+        // Positions in the JSON output of a JavaScript config do not point into the file itself
+        if is_js_config(&self.path) {
+            error.position = None;
+        }
+        ConfigFileError::Validation {
+            path: self.path.clone(),
+            content: self.content.clone(),
+            error: Box::new(error),
+        }
+    }
+}
+
+/// Returns whether given config file is written in JavaScript (or TypeScript).
+pub(crate) fn is_js_config(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        ["js", "mjs", "cjs", "ts", "mts", "cts"]
+            .iter()
+            .any(|js_ext| ext == *js_ext)
+    })
+}
+
+/// Loads a config file and the config files it extends.
+///
+/// Reading files is left to the caller so that hosts without synchronous file access can use this.
+/// Call `next_required_file` and supply the requested file until it returns None, then call `finish`.
+#[derive(Debug)]
+pub struct ConfigChainLoader {
+    /// Loaded config files; each file extends the next one.
+    chain: Vec<ConfigSource>,
+    /// Files supplied by the caller. None means that the file does not exist.
+    files: HashMap<PathBuf, Option<String>>,
+}
+
+impl ConfigChainLoader {
+    /// Starts loading from given config file.
+    pub fn new(path: PathBuf, content: String) -> Self {
+        Self {
+            chain: vec![ConfigSource { path, content }],
+            files: HashMap::new(),
+        }
+    }
+
+    /// Supplies a file requested by `next_required_file`.
+    /// `content` is None if the file does not exist.
+    /// Config files written in JavaScript should be supplied as their JSON output.
+    pub fn supply_file(&mut self, path: PathBuf, content: Option<String>) {
+        self.files.insert(path, content);
+    }
+
+    /// Returns the next file needed to continue loading, or None if all config files are loaded.
+    pub fn next_required_file(&mut self) -> Result<Option<PathBuf>, ConfigFileError> {
+        loop {
+            let last = self.chain.last().expect("chain should not be empty");
+            let Some(extends) =
+                parse_extends(&last.content).map_err(|error| last.validation_error(error))?
+            else {
+                return Ok(None);
+            };
+            let not_resolved = |reason: String| {
+                last.validation_error(ConfigValidationError {
+                    message: ConfigValidationErrorMessage::ExtendsNotResolved {
+                        extends: extends.clone(),
+                        reason,
+                    },
+                    position: None,
+                })
+            };
+            let target = match ImportPathResolver::default().resolve(&last.path, &extends, self) {
+                Ok(target) => target,
+                Err(ImportPathError::FileRequired(path)) => return Ok(Some(path)),
+                Err(err) => return Err(not_resolved(err.to_string())),
+            };
+            if let Some(index) = self.chain.iter().position(|file| file.path == target) {
+                let files = self.chain[index..]
+                    .iter()
+                    .map(|file| &file.path)
+                    .chain([&target])
+                    .map(|path| path.display().to_string())
+                    .collect();
+                return Err(last.validation_error(ConfigValidationError {
+                    message: ConfigValidationErrorMessage::ExtendsCycle { files },
+                    position: None,
+                }));
+            }
+            let content = match self.files.get(&target) {
+                None => return Ok(Some(target)),
+                Some(None) => {
+                    return Err(not_resolved(format!(
+                        "'{}' does not exist",
+                        target.display()
+                    )));
+                }
+                Some(Some(content)) => content.clone(),
+            };
+            self.chain.push(ConfigSource {
+                path: target,
+                content,
+            });
+        }
+    }
+
+    /// Merges loaded config files into one config.
+    /// Should be called after `next_required_file` returns None.
+    pub fn finish(self) -> Result<LoadedConfig, ConfigFileError> {
+        let root = &self.chain[0];
+        let mut merged = Value::Null;
+        for file in self.chain.iter().rev() {
+            let mut value: Value = serde_yaml::from_str(&file.content).map_err(|err| {
+                file.validation_error(ConfigValidationError {
+                    message: ConfigValidationErrorMessage::Invalid(err.to_string()),
+                    position: None,
+                })
+            })?;
+            if let Value::Mapping(mapping) = &mut value {
+                mapping.remove("extends");
+            }
+            // relative paths are relative to the file that defines them
+            let dir = file.path.parent().unwrap_or(Path::new(""));
+            let prefix = relative_path(&root.path, dir);
+            if !prefix.as_os_str().is_empty() {
+                rebase_config(&mut value, &prefix);
+            }
+            merged = merge(merged, value, &mut vec![]);
+        }
+        let projects =
+            parse_projects_from_value(merged).map_err(|error| root.validation_error(error))?;
+        Ok(LoadedConfig {
+            files: self.chain.into_iter().map(|file| file.path).collect(),
+            projects,
+        })
+    }
+}
+
+impl ResolutionHost for ConfigChainLoader {
+    fn lookup(&self, path: &Path) -> FileLookup<'_> {
+        match self.files.get(path) {
+            None => FileLookup::Pending,
+            Some(None) => FileLookup::NotFound,
+            Some(Some(content)) => FileLookup::Found(Cow::Borrowed(content)),
+        }
+    }
+}
+
+/// Path to the scalar type map, which is merged per scalar type.
+const SCALAR_TYPES: &[&str] = &["extensions", "nitrogql", "generate", "type", "scalarTypes"];
+/// Paths to arrays that are concatenated instead of replaced.
+const CONCATENATED_ARRAYS: &[&[&str]] = &[
+    &["extensions", "nitrogql", "plugins"],
+    &["extensions", "nitrogql", "overrides"],
+];
+
+/// Merges a config into its base config.
+/// Maps are merged deeply and other values in `child` replace those in `base`.
+fn merge(base: Value, child: Value, path: &mut Vec<String>) -> Value {
+    match (base, child) {
+        (base, Value::Null) => base,
+        (Value::Mapping(mut base), Value::Mapping(child)) => {
+            let shallow = path_ends_with(path, SCALAR_TYPES);
+            for (key, value) in child {
+                match base.get_mut(&key) {
+                    Some(slot) if !shallow => {
+                        path.push(key.as_str().unwrap_or_default().to_owned());
+                        *slot = merge(mem::take(slot), value, path);
+                        path.pop();
+                    }
+                    _ => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+            Value::Mapping(base)
+        }
+        (Value::Sequence(mut base), Value::Sequence(child))
+            if CONCATENATED_ARRAYS
+                .iter()
+                .any(|suffix| path_ends_with(path, suffix)) =>
+        {
+            for item in child {
+                if !base.contains(&item) {
+                    base.push(item);
+                }
+            }
+            Value::Sequence(base)
+        }
+        (_, child) => child,
+    }
+}
+
+fn path_ends_with(path: &[String], suffix: &[&str]) -> bool {
+    path.len() >= suffix.len()
+        && path[path.len() - suffix.len()..]
+            .iter()
+            .zip(suffix)
+            .all(|(a, b)| a == b)
+}
+
+/// Rewrites relative paths in a config so that they are relative to `prefix`.
+fn rebase_config(config: &mut Value, prefix: &Path) {
+    rebase_project(config, prefix);
+    if let Some(Value::Mapping(projects)) = config.get_mut("projects") {
+        for (_, project) in projects.iter_mut() {
+            rebase_project(project, prefix);
+        }
+    }
+}
+
+fn rebase_project(project: &mut Value, prefix: &Path) {
+    for key in ["schema", "documents", "exclude"] {
+        rebase_paths(project.get_mut(key), prefix);
+    }
+    let Some(nitrogql) = project
+        .get_mut("extensions")
+        .and_then(|extensions| extensions.get_mut("nitrogql"))
+    else {
+        return;
+    };
+    rebase_paths(nitrogql.get_mut("schemaEntry"), prefix);
+    if let Some(Value::Mapping(paths)) = nitrogql.get_mut("paths") {
+        for (_, targets) in paths.iter_mut() {
+            rebase_paths(Some(targets), prefix);
+        }
+    }
+    if let Some(generate) = nitrogql.get_mut("generate") {
+        for key in [
+            "schemaOutput",
+            "serverGraphqlOutput",
+            "resolversOutput",
+            "clientPresetOutput",
+            "validatorsOutput",
+        ] {
+            rebase_paths(generate.get_mut(key), prefix);
+        }
+    }
+    if let Some(Value::Sequence(overrides)) = nitrogql.get_mut("overrides") {
+        for item in overrides {
+            rebase_paths(item.get_mut("files"), prefix);
+        }
+    }
+}
+
+/// Rebases a path or a list of paths.
+fn rebase_paths(value: Option<&mut Value>, prefix: &Path) {
+    match value {
+        Some(Value::String(path)) => *path = rebase_path(path, prefix),
+        Some(Value::Sequence(items)) => {
+            for item in items {
+                if let Value::String(path) = item {
+                    *path = rebase_path(path, prefix);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Joins a relative path (or glob pattern) to `prefix`. A leading `!` is kept.
+fn rebase_path(path: &str, prefix: &Path) -> String {
+    let (negation, rest) = match path.strip_prefix('!') {
+        Some(rest) => ("!", rest),
+        None => ("", path),
+    };
+    if Path::new(rest).is_absolute() {
+        return path.to_owned();
+    }
+    let mut components = prefix
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    for segment in rest.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if components.last().is_some_and(|last| last != "..") => {
+                components.pop();
+            }
+            _ => components.push(segment.to_owned()),
+        }
+    }
+    let joined = components.join("/");
+    if joined.starts_with("..") {
+        format!("{negation}{joined}")
+    } else {
+        format!("{negation}./{joined}")
+    }
+}
@@ -0,0 +1,414 @@
+use serde_json::{Value, json};
+use serde_path_to_error::Segment;
+
+/// Returns JSON Schema of the config file.
+/// Published as `config-schema.json` in the CLI package so that editors can validate and complete config files.
+pub fn config_json_schema() -> Value {
+    let include = string_or_strings("Part of GraphQL Config. Not used by nitrogql.");
+    let exclude = string_or_strings("Pattern(s) of files excluded from schema and documents.");
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "nitrogql config",
+        "description": "GraphQL Config file with nitrogql extension.",
+        "type": "object",
+        "properties": {
+            "$schema": { "type": "string" },
+            "extends": {
+                "description": "Config file that this config file extends. Either a relative path or a path in a package.",
+                "type": "string"
+            },
+            "schema": string_or_strings("Path(s) to schema files. Glob patterns are allowed. Patterns starting with ! exclude matched files."),
+            "documents": string_or_strings("Path(s) to operation files. Glob patterns are allowed. Patterns starting with ! exclude matched files."),
+            "include": include.clone(),
+            "exclude": exclude.clone(),
+            "extensions": { "$ref": "#/definitions/extensions" },
+            "projects": {
+                "description": "Projects in this config file. Each project has the same options as the top level.",
+                "type": "object",
+                "additionalProperties": { "$ref": "#/definitions/project" }
+            }
+        },
+        "additionalProperties": false,
+        "definitions": {
+            "project": {
+                "type": "object",
+                "properties": {
+                    "schema": string_or_strings("Path(s) to schema files. Glob patterns are allowed. Patterns starting with ! exclude matched files."),
+                    "documents": string_or_strings("Path(s) to operation files. Glob patterns are allowed. Patterns starting with ! exclude matched files."),
+                    "include": include,
+                    "exclude": exclude,
+                    "extensions": { "$ref": "#/definitions/extensions" }
+                },
+                "additionalProperties": false
+            },
+            "extensions": {
+                "description": "Extensions for tools. Extensions other than 'nitrogql' are not checked.",
+                "type": "object",
+                "properties": {
+                    "nitrogql": { "$ref": "#/definitions/nitrogql" }
+                }
+            },
+            "nitrogql": {
+                "description": "Config of nitrogql.",
+                "type": "object",
+                "properties": {
+                    "plugins": {
+                        "description": "List of plugins to use.",
+                        "type": "array",
+                        "items": { "type": "string" }
+                    },
+                    "paths": {
+                        "description": "Aliases for paths in #import, in the form of tsconfig's 'paths'.",
+                        "type": "object",
+                        "additionalProperties": string_or_strings("Path(s) that the pattern is mapped to.")
+                    },
+                    "schemaEntry": {
+                        "description": "Entry point of modular schema. Schema files are loaded by following #import from this file.",
+                        "type": "string"
+                    },
+                    "respectGitignore": {
+                        "description": "Whether files ignored by git are excluded from schema and documents.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "check": { "$ref": "#/definitions/check" },
+                    "generate": { "$ref": "#/definitions/generate" },
+                    "overrides": {
+                        "description": "Overrides of the generate config for operation files matching patterns. The most specific matching override wins.",
+                        "type": "array",
+                        "items": { "$ref": "#/definitions/override" }
+                    }
+                },
+                "additionalProperties": false
+            },
+            "override": {
+                "type": "object",
+                "properties": {
+                    "files": string_or_strings("Pattern(s) of operation files this override applies to."),
+                    "generate": { "$ref": "#/definitions/generateOverride" }
+                },
+                "required": ["files", "generate"],
+                "additionalProperties": false
+            },
+            "generateOverride": {
+                "description": "Part of the generate config that can be overridden.",
+                "type": "object",
+                "properties": {
+                    "mode": {
+                        "description": "Mode of generation.",
+                        "enum": ["with-loader-ts-5.0", "with-loader-ts-4.0", "standalone-ts-4.0", "client-preset"]
+                    },
+                    "schemaModuleSpecifier": {
+                        "description": "Module specifier for importing schema types from operations.",
+                        "type": "string"
+                    },
+                    "name": { "$ref": "#/definitions/generateName" },
+                    "export": { "$ref": "#/definitions/generateExport" }
+                },
+                "additionalProperties": false
+            },
+            "check": {
+                "description": "Config related to the 'check' command.",
+                "type": "object",
+                "properties": {
+                    "noImportCycles": {
+                        "description": "Whether import cycles between operation documents are reported as errors.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "noUnusedImports": {
+                        "description": "Whether imported fragments that are never used are reported as errors.",
+                        "type": "boolean",
+                        "default": false
+                    }
+                },
+                "additionalProperties": false
+            },
+            "generate": {
+                "description": "Config related to the 'generate' command.",
+                "type": "object",
+                "properties": {
+                    "mode": {
+                        "description": "Mode of generation.",
+                        "enum": ["with-loader-ts-5.0", "with-loader-ts-4.0", "standalone-ts-4.0", "client-preset"],
+                        "default": "with-loader-ts-5.0"
+                    },
+                    "schemaOutput": {
+                        "description": "Path to the output schema type definition file.",
+                        "type": "string"
+                    },
+                    "clientPresetOutput": {
+                        "description": "Path to the output module that contains all operations. Required in the client-preset mode.",
+                        "type": "string"
+                    },
+                    "serverGraphqlOutput": {
+                        "description": "Path to the output GraphQL source file for use by a GraphQL server.",
+                        "type": "string"
+                    },
+                    "resolversOutput": {
+                        "description": "Path to the output resolvers type definition file.",
+                        "type": "string"
+                    },
+                    "validatorsOutput": {
+                        "description": "Path to the output file that contains Zod validators of input types and operation variables.",
+                        "type": "string"
+                    },
+                    "schemaModuleSpecifier": {
+                        "description": "Module specifier for importing schema types from operations. Defaults to relative paths.",
+                        "type": "string"
+                    },
+                    "type": { "$ref": "#/definitions/generateType" },
+                    "name": { "$ref": "#/definitions/generateName" },
+                    "export": { "$ref": "#/definitions/generateExport" },
+                    "resolvers": { "$ref": "#/definitions/generateResolvers" },
+                    "emitSchemaRuntime": {
+                        "description": "Whether to emit runtime for generated schema types.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "addTypename": {
+                        "description": "Whether to insert '__typename' into every selection set of operations except root operation selections.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "documentFormat": {
+                        "description": "Format of operation documents emitted as runtime values.",
+                        "enum": ["document-node", "string", "minified-string"],
+                        "default": "document-node"
+                    }
+                },
+                "additionalProperties": false
+            },
+            "generateType": {
+                "description": "Config related to generated types.",
+                "type": "object",
+                "properties": {
+                    "scalarTypes": {
+                        "description": "Mapping from GraphQL scalar types to TypeScript types.",
+                        "type": "object",
+                        "additionalProperties": { "$ref": "#/definitions/scalarType" }
+                    },
+                    "scalarValidators": {
+                        "description": "Mapping from GraphQL scalar types to Zod validators. Custom scalars used in inputs must have validators.",
+                        "type": "object",
+                        "additionalProperties": { "type": "string" }
+                    },
+                    "allowUndefinedAsOptionalInput": {
+                        "description": "Whether to allow undefined as input value for nullable input fields.",
+                        "type": "boolean",
+                        "default": true
+                    },
+                    "fragmentMasking": {
+                        "description": "Whether fragment spreads are masked in result types so that fields of fragments can only be read through the fragment.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "throwOnFieldError": {
+                        "description": "Whether the client throws on field errors instead of returning null. If true, positions marked by @semanticNonNull are typed as non-null in operation results.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "futureProofAbstractTypes": {
+                        "description": "Whether to add a catch-all branch to result types of unions and interfaces for object types unknown at build time.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "brandedScalars": {
+                        "description": "Scalars whose TypeScript types are branded with their names.",
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "default": []
+                    },
+                    "enumStyle": {
+                        "description": "How enums are emitted. Defaults to 'const-object' if emitSchemaRuntime is true and 'union' otherwise.",
+                        "enum": ["union", "union-with-fallback", "const-object", "enum"]
+                    },
+                    "enumMemberCase": {
+                        "description": "Casing of member names of TypeScript enums and keys of const objects.",
+                        "enum": ["keep", "pascal-case", "camel-case", "screaming-snake-case"],
+                        "default": "keep"
+                    }
+                },
+                "additionalProperties": false
+            },
+            "scalarType": {
+                "anyOf": [
+                    { "type": "string" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "send": { "type": "string" },
+                            "receive": { "type": "string" }
+                        },
+                        "required": ["send", "receive"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "resolverOutput": { "type": "string" },
+                            "resolverInput": { "type": "string" },
+                            "operationOutput": { "type": "string" },
+                            "operationInput": { "type": "string" }
+                        },
+                        "required": ["resolverOutput", "resolverInput", "operationOutput", "operationInput"],
+                        "additionalProperties": false
+                    }
+                ]
+            },
+            "generateName": {
+                "description": "Config related to names of generated variables and types.",
+                "type": "object",
+                "properties": {
+                    "operationResultTypeSuffix": {
+                        "description": "Suffix for type of operation result.",
+                        "type": "string"
+                    },
+                    "variablesTypeSuffix": {
+                        "description": "Suffix for type of variables for an operation.",
+                        "type": "string"
+                    },
+                    "fragmentTypeSuffix": {
+                        "description": "Suffix for type of fragment.",
+                        "type": "string"
+                    },
+                    "capitalizeOperationNames": {
+                        "description": "Whether operation name should be capitalized.",
+                        "type": "boolean"
+                    },
+                    "queryVariableSuffix": {
+                        "description": "Suffix for variable of query.",
+                        "type": "string"
+                    },
+                    "mutationVariableSuffix": {
+                        "description": "Suffix for variable of mutation.",
+                        "type": "string"
+                    },
+                    "subscriptionVariableSuffix": {
+                        "description": "Suffix for variable of subscription.",
+                        "type": "string"
+                    },
+                    "fragmentVariableSuffix": {
+                        "description": "Suffix for variable of fragment.",
+                        "type": "string"
+                    }
+                },
+                "additionalProperties": false
+            },
+            "generateExport": {
+                "description": "Config related to exported names.",
+                "type": "object",
+                "properties": {
+                    "defaultExportForOperation": {
+                        "description": "Whether operation is exported as a default export. Effective only when a document contains only one operation.",
+                        "type": "boolean",
+                        "default": true
+                    },
+                    "operationResultType": {
+                        "description": "Whether operation result type is exported.",
+                        "type": "boolean",
+                        "default": false
+                    },
+                    "variablesType": {
+                        "description": "Whether variables type is exported.",
+                        "type": "boolean",
+                        "default": false
+                    }
+                },
+                "additionalProperties": false
+            },
+            "generateResolvers": {
+                "description": "Config related to the generated resolvers file.",
+                "type": "object",
+                "properties": {
+                    "rootTypeName": {
+                        "description": "Name of the root resolvers type.",
+                        "type": "string",
+                        "default": "Resolvers"
+                    },
+                    "outputTypeName": {
+                        "description": "Name of the resolver output type.",
+                        "type": "string",
+                        "default": "ResolverOutput"
+                    },
+                    "schemaNamespace": {
+                        "description": "Name of the namespace that schema types are imported as.",
+                        "type": "string",
+                        "default": "Schema"
+                    },
+                    "contextType": {
+                        "$ref": "#/definitions/typeImport",
+                        "description": "Default type of context passed to resolvers."
+                    },
+                    "infoType": {
+                        "$ref": "#/definitions/typeImport",
+                        "description": "Type of the 'info' argument of resolvers. Defaults to GraphQLResolveInfo from 'graphql'."
+                    }
+                },
+                "additionalProperties": false
+            },
+            "typeImport": {
+                "description": "A type exported from a module.",
+                "type": "object",
+                "properties": {
+                    "module": {
+                        "description": "Module specifier to import the type from.",
+                        "type": "string"
+                    },
+                    "name": {
+                        "description": "Exported name of the type.",
+                        "type": "string"
+                    }
+                },
+                "required": ["module", "name"],
+                "additionalProperties": false
+            }
+        }
+    })
+}
+
+fn string_or_strings(description: &str) -> Value {
+    json!({
+        "description": description,
+        "anyOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" } }
+        ]
+    })
+}
+
+/// Returns keys allowed in the object at given path of a config file.
+/// Returns None if the object accepts any key or the path cannot be followed in the schema.
+pub(crate) fn allowed_keys<'a>(schema: &'a Value, path: &[Segment]) -> Option<Vec<&'a str>> {
+    let mut current = schema;
+    for segment in path {
+        let next = match segment {
+            Segment::Map { key } => current
+                .get("properties")
+                .and_then(|properties| properties.get(key))
+                .or_else(|| {
+                    current
+                        .get("additionalProperties")
+                        .filter(|value| value.is_object())
+                }),
+            Segment::Seq { .. } => current.get("items"),
+            Segment::Enum { .. } | Segment::Unknown => None,
+        }?;
+        current = resolve_ref(schema, next);
+    }
+    if current.get("additionalProperties") != Some(&Value::Bool(false)) {
+        return None;
+    }
+    let properties = current.get("properties")?.as_object()?;
+    Some(properties.keys().map(String::as_str).collect())
+}
+
+/// Follows `$ref` of given schema if any.
+fn resolve_ref<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+        .unwrap_or(schema)
+}
@@ -2,6 +2,8 @@ mod config;
 mod error;
 #[cfg(feature = "execute_js")]
 mod execute;
+mod extends;
+mod json_schema;
 #[cfg(feature = "fs")]
 mod load_config;
 #[cfg(feature = "execute_js")]
@@ -13,13 +15,21 @@ mod scalar_type;
 mod tests;
 mod type_target;
 
-pub use config::{Config, GenerateConfig, GenerateMode};
+pub use config::{
+    CheckConfig, Config, DocumentFormat, EnumMemberCase, EnumStyle, GenerateConfig,
+    GenerateExportConfig, GenerateExportOverrideConfig, GenerateMode, GenerateNameConfig,
+    GenerateOverride, GenerateOverrideConfig, GenerateResolversConfig, GenerateTypeConfig, Project,
+    TypeImportConfig,
+};
+pub use error::{ConfigFileError, ConfigValidationError, ConfigValidationErrorMessage};
 #[cfg(feature = "execute_js")]
 pub use execute::execute_js;
+pub use extends::{ConfigChainLoader, LoadedConfig};
+pub use json_schema::config_json_schema;
 #[cfg(feature = "fs")]
 pub use load_config::load_config;
 #[cfg(feature = "execute_js")]
 pub use node::{load_default_from_js_file, run_node};
-pub use parse_config::parse_config;
+pub use parse_config::{DEFAULT_PROJECT_NAME, parse_config, parse_projects};
 pub use scalar_type::{ScalarTypeConfig, SendReceiveScalarTypeConfig, SeparateScalarTypeConfig};
 pub use type_target::TypeTarget;
@@ -4,8 +4,10 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::parse_config::parse_config;
-use crate::{config::Config, node::load_default_from_js_file};
+use crate::{
+    extends::{ConfigChainLoader, LoadedConfig, is_js_config},
+    node::load_default_from_js_file,
+};
 
 use super::error::ConfigFileError;
 
@@ -42,48 +44,58 @@ async fn search_graphql_config(cwd: &Path) -> io::Result<Option<(PathBuf, String
     trace!("search_graphql_config from {}", cwd.display());
     for (name, kind) in CONFIG_NAMES.iter() {
         let config_file_path = cwd.join(name);
-        match kind {
-            LoaderKind::Yaml => {
-                match fs::read_to_string(&config_file_path) {
-                    Ok(buf) => {
-                        trace!("Found config file {}", config_file_path.display());
-                        return Ok(Some((config_file_path, buf)));
-                    }
-                    Err(err) if err.kind() == io::ErrorKind::NotFound => {
-                        trace!("Not found: {}", config_file_path.display());
-                    }
-                    // Maybe a WASI way of expressing file not found error
-                    Err(err)
-                        if err.to_string().starts_with(
-                            "failed to find a pre-opened file descriptor through which",
-                        ) =>
-                    {
-                        trace!("Not found: {}", config_file_path.display());
-                    }
-                    Err(err) => return Err(err),
-                }
+        if let Some(buf) = read_config_file(&config_file_path, *kind).await? {
+            trace!("Found config file {}", config_file_path.display());
+            return Ok(Some((config_file_path, buf)));
+        }
+        trace!("Not found: {}", config_file_path.display());
+    }
+    Ok(None)
+}
+
+/// Reads a config file. Config files written in JavaScript are executed.
+/// Returns None if the file does not exist.
+async fn read_config_file(path: &Path, kind: LoaderKind) -> io::Result<Option<String>> {
+    match kind {
+        LoaderKind::Yaml => match fs::read_to_string(path) {
+            Ok(buf) => Ok(Some(buf)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            // Maybe a WASI way of expressing file not found error
+            Err(err)
+                if err
+                    .to_string()
+                    .starts_with("failed to find a pre-opened file descriptor through which") =>
+            {
+                Ok(None)
             }
-            LoaderKind::Js => {
-                if config_file_path.try_exists()? {
-                    trace!("Found config file {}", config_file_path.display());
-                    return load_default_from_js_file(&config_file_path)
-                        .await
-                        .map(|buf| Some((config_file_path, buf)));
-                } else {
-                    trace!("Not found: {}", config_file_path.display());
-                }
+            Err(err) => Err(err),
+        },
+        LoaderKind::Js => {
+            if path.try_exists()? {
+                load_default_from_js_file(path).await.map(Some)
+            } else {
+                Ok(None)
             }
         }
     }
-    Ok(None)
 }
 
-/// Loads config file. Returns a pair of loaded file name and loaded config.
+/// Decides how to read given file from its extension.
+fn loader_kind(path: &Path) -> LoaderKind {
+    if is_js_config(path) {
+        LoaderKind::Js
+    } else {
+        LoaderKind::Yaml
+    }
+}
+
+/// Loads config file and the config files it extends.
+/// Returns None if no config file is found.
 /// Config file should follow the GraphQL Config format: https://the-guild.dev/graphql/config/docs
 pub async fn load_config(
     cwd: &Path,
     config_file: Option<&Path>,
-) -> Result<Option<(PathBuf, Config)>, ConfigFileError> {
+) -> Result<Option<LoadedConfig>, ConfigFileError> {
     let config_source = match config_file {
         Some(path) => {
             let mut path_to_read = cwd.to_owned();
@@ -92,11 +104,15 @@ pub async fn load_config(
         }
         None => search_graphql_config(cwd).await,
     }?;
+    let Some((path, content)) = config_source else {
+        return Ok(None);
+    };
 
-    match config_source {
-        None => Ok(None),
-        Some((path, source)) => parse_config(&source)
-            .map(|config| Some((path.clone(), config)))
-            .ok_or(ConfigFileError::Validation(path)),
+    let mut loader = ConfigChainLoader::new(path, content);
+    while let Some(required) = loader.next_required_file()? {
+        trace!("Loading {} for extends", required.display());
+        let content = read_config_file(&required, loader_kind(&required)).await?;
+        loader.supply_file(required, content);
     }
+    loader.finish().map(Some)
 }
@@ -1,43 +1,267 @@
-use serde::Deserialize;
+use std::{collections::HashMap, fmt};
 
-use crate::{Config, GenerateConfig, parsing_utils::StringOrVecString};
+use nitrogql_utils::did_you_mean;
+use serde::{
+    Deserialize, Deserializer,
+    de::{IgnoredAny, MapAccess, Visitor},
+};
+use serde_path_to_error::Segment;
+
+use crate::{
+    CheckConfig, Config, GenerateConfig, GenerateOverride, Project, config_json_schema,
+    error::{ConfigValidationError, ConfigValidationErrorMessage},
+    json_schema::allowed_keys,
+    parsing_utils::StringOrVecString,
+};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConfigParser {
+    /// JSON Schema for editors.
+    #[serde(rename = "$schema")]
+    _schema_url: Option<IgnoredAny>,
+    /// Config file that this config file extends.
+    extends: Option<String>,
+    schema: Option<StringOrVecString>,
+    documents: Option<StringOrVecString>,
+    /// Not supported by nitrogql; accepted as part of GraphQL Config.
+    #[serde(rename = "include")]
+    _include: Option<IgnoredAny>,
+    exclude: Option<StringOrVecString>,
+    extensions: Option<Extensions>,
+    projects: Option<ProjectsParser>,
+}
 
 #[derive(Deserialize)]
-struct ConfigParser {
+#[serde(deny_unknown_fields)]
+struct ProjectParser {
     schema: Option<StringOrVecString>,
     documents: Option<StringOrVecString>,
+    /// Not supported by nitrogql; accepted as part of GraphQL Config.
+    #[serde(rename = "include")]
+    _include: Option<IgnoredAny>,
+    exclude: Option<StringOrVecString>,
     extensions: Option<Extensions>,
 }
 
+/// Extensions may contain configs of other tools, so unknown keys are allowed here.
 #[derive(Deserialize)]
 struct Extensions {
     nitrogql: Option<NitrogqlConfigParser>,
 }
 
 #[derive(Default, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
 struct NitrogqlConfigParser {
     plugins: Vec<String>,
+    paths: HashMap<String, StringOrVecString>,
+    schema_entry: Option<String>,
+    respect_gitignore: bool,
+    check: Option<CheckConfig>,
     generate: Option<GenerateConfig>,
+    overrides: Vec<GenerateOverride>,
+}
+
+/// Projects in the order of appearance.
+struct ProjectsParser(Vec<(String, ProjectParser)>);
+
+impl<'de> Deserialize<'de> for ProjectsParser {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ProjectsVisitor)
+    }
 }
 
+struct ProjectsVisitor;
+
+impl<'de> Visitor<'de> for ProjectsVisitor {
+    type Value = ProjectsParser;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map from project names to projects")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut projects = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            projects.push(entry);
+        }
+        Ok(ProjectsParser(projects))
+    }
+}
+
+/// Name of the project used when a specific project is not requested.
+pub const DEFAULT_PROJECT_NAME: &str = "default";
+
 /// Parse config file from given string.
-/// Returns None if there is a validation error.
-pub fn parse_config(source: &str) -> Option<Config> {
-    let parsed: ConfigParser = serde_yaml::from_str(source).unwrap();
-    let ConfigParser {
+/// If the config file has `projects`, the `default` project is returned.
+pub fn parse_config(source: &str) -> Result<Config, ConfigValidationError> {
+    let projects = parse_projects(source)?;
+    projects
+        .into_iter()
+        .find(|project| {
+            project
+                .name
+                .as_deref()
+                .is_none_or(|name| name == DEFAULT_PROJECT_NAME)
+        })
+        .map(|project| project.config)
+        .ok_or(ConfigValidationError {
+            message: ConfigValidationErrorMessage::NoDefaultProject,
+            position: None,
+        })
+}
+
+/// Parse config file with all projects in it.
+/// If the config file does not have `projects`, the whole config file is one unnamed project.
+/// `extends` is not resolved by this function; use `ConfigChainLoader` for that.
+pub fn parse_projects(source: &str) -> Result<Vec<Project>, ConfigValidationError> {
+    let parsed = deserialize_config(serde_yaml::Deserializer::from_str(source))?;
+    Ok(parser_to_projects(parsed))
+}
+
+/// Validates a config file and returns the value of its `extends`.
+pub(crate) fn parse_extends(source: &str) -> Result<Option<String>, ConfigValidationError> {
+    let parsed = deserialize_config(serde_yaml::Deserializer::from_str(source))?;
+    Ok(parsed.extends)
+}
+
+/// Parse projects from a config already loaded as a YAML value.
+pub(crate) fn parse_projects_from_value(
+    value: serde_yaml::Value,
+) -> Result<Vec<Project>, ConfigValidationError> {
+    let parsed = deserialize_config(value)?;
+    Ok(parser_to_projects(parsed))
+}
+
+/// Deserializes a config file, keeping track of the path to the value being deserialized.
+fn deserialize_config<'de>(
+    deserializer: impl Deserializer<'de, Error = serde_yaml::Error>,
+) -> Result<ConfigParser, ConfigValidationError> {
+    serde_path_to_error::deserialize(deserializer).map_err(yaml_error)
+}
+
+fn parser_to_projects(parsed: ConfigParser) -> Vec<Project> {
+    match parsed.projects {
+        None => vec![Project {
+            name: None,
+            config: project_to_config(ProjectParser {
+                schema: parsed.schema,
+                documents: parsed.documents,
+                _include: parsed._include,
+                exclude: parsed.exclude,
+                extensions: parsed.extensions,
+            }),
+        }],
+        Some(ProjectsParser(projects)) => projects
+            .into_iter()
+            .map(|(name, project)| Project {
+                name: Some(name),
+                config: project_to_config(project),
+            })
+            .collect(),
+    }
+}
+
+fn project_to_config(project: ProjectParser) -> Config {
+    let ProjectParser {
         schema,
         documents,
+        exclude,
         extensions,
-    } = parsed;
+        ..
+    } = project;
     let nitrogql = extensions.and_then(|e| e.nitrogql);
-    let (plugins, generate) = nitrogql
-        .map(|n| (n.plugins, n.generate.unwrap_or_default()))
-        .unwrap_or_default();
-    Some(Config {
+    let NitrogqlConfigParser {
+        plugins,
+        paths,
+        schema_entry,
+        respect_gitignore,
+        check,
+        generate,
+        overrides,
+    } = nitrogql.unwrap_or_default();
+    let paths = paths
+        .into_iter()
+        .map(|(pattern, targets)| (pattern, targets.into_vec()))
+        .collect();
+    Config {
         schema: schema.map(|s| s.into_vec()).unwrap_or_default(),
         operations: documents.map(|s| s.into_vec()).unwrap_or_default(),
+        exclude: exclude.map(|s| s.into_vec()).unwrap_or_default(),
         plugins,
-        generate,
+        paths,
+        schema_entry,
+        respect_gitignore,
+        check: check.unwrap_or_default(),
+        generate: generate.unwrap_or_default(),
+        overrides,
+    }
+}
+
+/// Converts an error from serde_yaml into a validation error.
+/// An error at a key that the JSON Schema does not allow is reported as an unknown key.
+fn yaml_error(error: serde_path_to_error::Error<serde_yaml::Error>) -> ConfigValidationError {
+    let path = error.path().iter().cloned().collect::<Vec<_>>();
+    let error = error.into_inner();
+    // convert 1-based line and column to 0-based
+    let position = error
+        .location()
+        .map(|location| (location.line() - 1, location.column() - 1));
+    let message = match unknown_key(&path) {
+        Some(message) => message,
+        None => {
+            let mut message = error.to_string();
+            if let Some(index) = message.rfind(" at line ") {
+                message.truncate(index);
+            }
+            ConfigValidationErrorMessage::Invalid(message)
+        }
+    };
+    ConfigValidationError { message, position }
+}
+
+/// Returns an unknown key error if the last key of `path` is not allowed in its object.
+fn unknown_key(path: &[Segment]) -> Option<ConfigValidationErrorMessage> {
+    let (Segment::Map { key }, parent) = path.split_last()? else {
+        return None;
+    };
+    let schema = config_json_schema();
+    let allowed = allowed_keys(&schema, parent)?;
+    if allowed.contains(&key.as_str()) {
+        return None;
+    }
+    let suggestions = did_you_mean(
+        key,
+        allowed.into_iter().filter(|name| !name.starts_with('$')),
+    );
+    Some(ConfigValidationErrorMessage::UnknownKey {
+        key: key.clone(),
+        path: (!parent.is_empty()).then(|| format_path(parent)),
+        suggestions,
     })
 }
+
+/// Formats a path in the form of `projects.admin.extensions`.
+fn format_path(path: &[Segment]) -> String {
+    let mut result = String::new();
+    for segment in path {
+        match segment {
+            Segment::Map { key } => {
+                if !result.is_empty() {
+                    result.push('.');
+                }
+                result.push_str(key);
+            }
+            Segment::Seq { index } => result.push_str(&format!("[{index}]")),
+            Segment::Enum { variant } => result.push_str(&format!("::{variant}")),
+            Segment::Unknown => result.push_str(".?"),
+        }
+    }
+    result
+}
@@ -1,6 +1,9 @@
 use std::{marker::PhantomData, str::FromStr};
 
-use serde::{Deserialize, Deserializer, de::Visitor};
+use serde::{
+    Deserialize, Deserializer,
+    de::{Error, Visitor},
+};
 
 /// A string or a list of strings.
 pub enum StringOrVecString {
@@ -88,3 +91,28 @@ where
         T::from_str(v).map_err(|_| E::custom(format!("invalid enum value: {v}")))
     }
 }
+
+pub fn deserialize_fromstr_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    deserialize_fromstr(deserializer).map(Some)
+}
+
+/// Deserializes a string or a list of strings as glob patterns.
+pub fn deserialize_patterns<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let patterns = StringOrVecString::deserialize(deserializer)?.into_vec();
+    for pattern in patterns.iter() {
+        if let Err(err) = glob::Pattern::new(pattern) {
+            return Err(D::Error::custom(format!(
+                "invalid pattern '{pattern}': {}",
+                err.msg
+            )));
+        }
+    }
+    Ok(patterns)
+}
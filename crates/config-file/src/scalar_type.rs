@@ -16,14 +16,14 @@ pub enum ScalarTypeConfig {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct SendReceiveScalarTypeConfig {
     pub send: String,
     pub receive: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct SeparateScalarTypeConfig {
     pub resolver_output: String,
     pub resolver_input: String,
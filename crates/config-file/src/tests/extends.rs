@@ -0,0 +1,296 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    ConfigChainLoader, ConfigFileError, ConfigValidationErrorMessage, GenerateMode, LoadedConfig,
+    ScalarTypeConfig, SendReceiveScalarTypeConfig,
+};
+
+/// Loads a config from `path` with files in `files`.
+fn load(path: &str, files: &[(&str, &str)]) -> Result<LoadedConfig, ConfigFileError> {
+    let content = files
+        .iter()
+        .find(|(file, _)| *file == path)
+        .map(|(_, content)| content.to_string())
+        .unwrap();
+    let mut loader = ConfigChainLoader::new(path.into(), content);
+    while let Some(required) = loader.next_required_file()? {
+        let content = files
+            .iter()
+            .find(|(file, _)| Path::new(file) == required)
+            .map(|(_, content)| content.to_string());
+        loader.supply_file(required, content);
+    }
+    loader.finish()
+}
+
+const BASE: &str = r#"
+schema: ./schema/*.graphql
+extensions:
+    nitrogql:
+        plugins:
+            - "nitrogql:model-plugin"
+        generate:
+            mode: standalone-ts-4.0
+            schemaOutput: ./generated/schema.d.ts
+            type:
+                scalarTypes:
+                    Date: string
+                    BigInt: bigint
+            name:
+                operationResultTypeSuffix: Data
+"#;
+
+#[test]
+fn extends_relative_path() {
+    let config = r#"
+extends: ../shared/graphql.config.yaml
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        plugins:
+            - "nitrogql:graphql-scalars-plugin"
+            - "nitrogql:model-plugin"
+        generate:
+            type:
+                scalarTypes:
+                    Date:
+                        send: Date
+                        receive: string
+            name:
+                variablesTypeSuffix: Vars
+"#;
+    let LoadedConfig { files, projects } = load(
+        "/app/web/graphql.config.yaml",
+        &[
+            ("/app/web/graphql.config.yaml", config),
+            ("/app/shared/graphql.config.yaml", BASE),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("/app/web/graphql.config.yaml"),
+            PathBuf::from("/app/shared/graphql.config.yaml"),
+        ]
+    );
+    let config = &projects[0].config;
+    // paths in the base config are relative to the base config
+    assert_eq!(config.schema, vec!["../shared/schema/*.graphql"]);
+    assert_eq!(config.operations, vec!["src/**/*.graphql"]);
+    assert_eq!(
+        config.generate.schema_output,
+        Some(PathBuf::from("../shared/generated/schema.d.ts"))
+    );
+    assert_eq!(
+        config.plugins,
+        vec!["nitrogql:model-plugin", "nitrogql:graphql-scalars-plugin"]
+    );
+    assert_eq!(config.generate.mode, GenerateMode::StandaloneTS4_0);
+    assert_eq!(
+        config.generate.r#type.scalar_types.get("Date"),
+        Some(&ScalarTypeConfig::SendReceive(
+            SendReceiveScalarTypeConfig {
+                send: "Date".to_owned(),
+                receive: "string".to_owned(),
+            }
+        ))
+    );
+    assert_eq!(
+        config.generate.r#type.scalar_types.get("BigInt"),
+        Some(&ScalarTypeConfig::Single("bigint".to_owned()))
+    );
+    assert_eq!(
+        config.generate.name.operation_result_type_suffix.as_deref(),
+        Some("Data")
+    );
+    assert_eq!(
+        config.generate.name.variables_type_suffix.as_deref(),
+        Some("Vars")
+    );
+}
+
+#[test]
+fn extends_chain() {
+    let middle = r#"
+extends: ./base.yaml
+extensions:
+    nitrogql:
+        generate:
+            mode: with-loader-ts-4.0
+"#;
+    let config = r#"
+extends: ./config/middle.yaml
+documents: src/**/*.graphql
+"#;
+    let LoadedConfig { files, projects } = load(
+        "/app/graphql.config.yaml",
+        &[
+            ("/app/graphql.config.yaml", config),
+            ("/app/config/middle.yaml", middle),
+            ("/app/config/base.yaml", BASE),
+        ],
+    )
+    .unwrap();
+    assert_eq!(files.len(), 3);
+    let config = &projects[0].config;
+    assert_eq!(config.schema, vec!["./config/schema/*.graphql"]);
+    assert_eq!(config.generate.mode, GenerateMode::WithLoaderTS4_0);
+}
+
+#[test]
+fn extends_package() {
+    let config = r#"
+extends: "@company/graphql-config/base"
+documents: src/**/*.graphql
+"#;
+    let package_json = r#"
+{
+    "name": "@company/graphql-config",
+    "exports": {
+        "./base": "./configs/base.yaml"
+    }
+}
+"#;
+    let LoadedConfig { files, projects } = load(
+        "/app/web/graphql.config.yaml",
+        &[
+            ("/app/web/graphql.config.yaml", config),
+            (
+                "/app/node_modules/@company/graphql-config/package.json",
+                package_json,
+            ),
+            (
+                "/app/node_modules/@company/graphql-config/configs/base.yaml",
+                BASE,
+            ),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        files[1],
+        PathBuf::from("/app/node_modules/@company/graphql-config/configs/base.yaml")
+    );
+    assert_eq!(
+        projects[0].config.schema,
+        vec!["../node_modules/@company/graphql-config/configs/schema/*.graphql"]
+    );
+}
+
+#[test]
+fn extends_projects() {
+    let base = r#"
+projects:
+    app:
+        schema: schema.graphql
+        extensions:
+            nitrogql:
+                generate:
+                    mode: standalone-ts-4.0
+    admin:
+        schema: admin.graphql
+"#;
+    let config = r#"
+extends: ./base.yaml
+projects:
+    app:
+        documents: app/**/*.graphql
+"#;
+    let LoadedConfig { projects, .. } = load(
+        "/app/graphql.config.yaml",
+        &[
+            ("/app/graphql.config.yaml", config),
+            ("/app/base.yaml", base),
+        ],
+    )
+    .unwrap();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0].name.as_deref(), Some("app"));
+    assert_eq!(projects[0].config.schema, vec!["schema.graphql"]);
+    assert_eq!(projects[0].config.operations, vec!["app/**/*.graphql"]);
+    assert_eq!(
+        projects[0].config.generate.mode,
+        GenerateMode::StandaloneTS4_0
+    );
+    assert_eq!(projects[1].name.as_deref(), Some("admin"));
+}
+
+#[test]
+fn extends_missing_file() {
+    let config = "extends: ./missing.yaml\n";
+    let err = load(
+        "/app/graphql.config.yaml",
+        &[("/app/graphql.config.yaml", config)],
+    )
+    .unwrap_err();
+    let ConfigFileError::Validation { path, error, .. } = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(path, PathBuf::from("/app/graphql.config.yaml"));
+    assert!(matches!(
+        error.message,
+        ConfigValidationErrorMessage::ExtendsNotResolved { .. }
+    ));
+}
+
+#[test]
+fn extends_cycle() {
+    let err = load(
+        "/app/a.yaml",
+        &[
+            ("/app/a.yaml", "extends: ./b.yaml\n"),
+            ("/app/b.yaml", "extends: ./a.yaml\n"),
+        ],
+    )
+    .unwrap_err();
+    let ConfigFileError::Validation { error, .. } = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(
+        error.message.to_string(),
+        "Config files extend each other: /app/a.yaml -> /app/b.yaml -> /app/a.yaml."
+    );
+}
+
+#[test]
+fn error_in_base_config() {
+    let base = r#"
+extensions:
+    nitrogql:
+        generate:
+            shemaOutput: schema.d.ts
+"#;
+    let err = load(
+        "/app/graphql.config.yaml",
+        &[
+            ("/app/graphql.config.yaml", "extends: ./base.yaml\n"),
+            ("/app/base.yaml", base),
+        ],
+    )
+    .unwrap_err();
+    let ConfigFileError::Validation { path, error, .. } = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(path, PathBuf::from("/app/base.yaml"));
+    assert_eq!(error.position, Some((4, 12)));
+}
+
+#[test]
+fn error_in_js_config() {
+    // JavaScript configs are supplied as their JSON output
+    let config = r#"{"extensions":{"nitrogql":{"generate":{"shemaOutput":"schema.d.ts"}}}}"#;
+    let err = load(
+        "/app/graphql.config.ts",
+        &[("/app/graphql.config.ts", config)],
+    )
+    .unwrap_err();
+    let ConfigFileError::Validation { path, error, .. } = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(path, PathBuf::from("/app/graphql.config.ts"));
+    assert_eq!(error.position, None);
+    assert_eq!(
+        error.message.to_string(),
+        "Unknown key 'shemaOutput' in 'extensions.nitrogql.generate'."
+    );
+}
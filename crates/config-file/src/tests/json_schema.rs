@@ -0,0 +1,90 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use crate::{config_json_schema, parse_config::ConfigParser};
+
+/// Path to the published JSON Schema, relative to this crate.
+const SCHEMA_FILE: &str = "../../packages/cli/config-schema.json";
+
+/// Published JSON Schema must be up to date.
+/// Run with `UPDATE_CONFIG_SCHEMA=1` to regenerate it.
+#[test]
+fn published_schema_is_up_to_date() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SCHEMA_FILE);
+    let generated = serde_json::to_string_pretty(&config_json_schema()).unwrap() + "\n";
+    if std::env::var_os("UPDATE_CONFIG_SCHEMA").is_some() {
+        fs::write(&path, generated).unwrap();
+        return;
+    }
+    let published = fs::read_to_string(&path).unwrap();
+    assert!(
+        published == generated,
+        "config-schema.json is outdated; run tests with UPDATE_CONFIG_SCHEMA=1"
+    );
+}
+
+/// Keys in the JSON Schema must match keys accepted by the parser.
+#[test]
+fn schema_keys_match_parser() {
+    let schema = config_json_schema();
+    let objects = [
+        (vec![], "/properties"),
+        (vec!["projects", "foo"], "/definitions/project/properties"),
+        (
+            vec!["extensions", "nitrogql"],
+            "/definitions/nitrogql/properties",
+        ),
+        (
+            vec!["extensions", "nitrogql", "check"],
+            "/definitions/check/properties",
+        ),
+        (
+            vec!["extensions", "nitrogql", "generate"],
+            "/definitions/generate/properties",
+        ),
+        (
+            vec!["extensions", "nitrogql", "generate", "type"],
+            "/definitions/generateType/properties",
+        ),
+        (
+            vec!["extensions", "nitrogql", "generate", "name"],
+            "/definitions/generateName/properties",
+        ),
+        (
+            vec!["extensions", "nitrogql", "generate", "export"],
+            "/definitions/generateExport/properties",
+        ),
+        (
+            vec!["extensions", "nitrogql", "generate", "resolvers"],
+            "/definitions/generateResolvers/properties",
+        ),
+    ];
+    for (path, pointer) in objects {
+        let schema_keys = schema
+            .pointer(pointer)
+            .and_then(|properties| properties.as_object())
+            .unwrap()
+            .keys()
+            .map(|key| key.as_str())
+            .collect::<BTreeSet<_>>();
+        // Parser reports keys it accepts when an unknown key is found.
+        let mut source = String::new();
+        for (depth, key) in path.iter().enumerate() {
+            source.push_str(&format!("{}{key}:\n", "  ".repeat(depth)));
+        }
+        source.push_str(&format!(
+            "{}unknownKeyForTest: 1\n",
+            "  ".repeat(path.len())
+        ));
+        let message = serde_yaml::from_str::<ConfigParser>(&source)
+            .err()
+            .unwrap()
+            .to_string();
+        let (_, expected) = message.split_once("expected").unwrap();
+        let parser_keys = expected
+            .split('`')
+            .skip(1)
+            .step_by(2)
+            .collect::<BTreeSet<_>>();
+        assert_eq!(schema_keys, parser_keys, "keys of {}", path.join("."));
+    }
+}
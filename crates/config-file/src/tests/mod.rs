@@ -1,10 +1,16 @@
 use std::path::PathBuf;
 
-use crate::{GenerateMode, ScalarTypeConfig, parse_config};
+use crate::{DocumentFormat, GenerateMode, ScalarTypeConfig, parse_config};
 
 mod export;
+mod extends;
+mod json_schema;
 mod name;
+mod overrides;
+mod projects;
+mod resolvers;
 mod r#type;
+mod validation;
 
 #[test]
 fn parse_schema_and_documents() {
@@ -34,6 +40,84 @@ documents:
     );
 }
 
+#[test]
+fn parse_paths() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        paths:
+            "~/*": ./src/*
+            "@fragments/*":
+                - ./fragments/*
+                - ./shared/fragments/*
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.paths.len(), 2);
+    assert_eq!(config.paths["~/*"], vec!["./src/*"]);
+    assert_eq!(
+        config.paths["@fragments/*"],
+        vec!["./fragments/*", "./shared/fragments/*"]
+    );
+}
+
+#[test]
+fn parse_schema_entry() {
+    let config = r#"
+extensions:
+    nitrogql:
+        schemaEntry: ./schema/index.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.schema.is_empty());
+    assert_eq!(
+        config.schema_entry.as_deref(),
+        Some("./schema/index.graphql")
+    );
+}
+
+#[test]
+fn parse_exclude() {
+    let config = r#"
+schema: schema.graphql
+documents:
+    - src/**/*.graphql
+    - "!**/__generated__/**"
+exclude: src/fixtures/**
+extensions:
+    nitrogql:
+        respectGitignore: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.operations,
+        vec!["src/**/*.graphql", "!**/__generated__/**"]
+    );
+    assert_eq!(config.exclude, vec!["src/fixtures/**"]);
+    assert!(config.respect_gitignore);
+    let config = parse_config("schema: schema.graphql").unwrap();
+    assert!(config.exclude.is_empty());
+    assert!(!config.respect_gitignore);
+}
+
+#[test]
+fn parse_check_config() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        check:
+            noImportCycles: true
+            noUnusedImports: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.check.no_import_cycles);
+    assert!(config.check.no_unused_imports);
+    let config = parse_config("schema: schema.graphql").unwrap();
+    assert!(!config.check.no_import_cycles);
+    assert!(!config.check.no_unused_imports);
+}
+
 #[test]
 fn parse_generate_config() {
     let config = r#"
@@ -57,6 +141,82 @@ extensions:
         config.generate.schema_module_specifier,
         Some("@generated/schema".to_owned())
     );
+    assert!(!config.generate.add_typename);
+}
+
+#[test]
+fn parse_client_preset_config() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            mode: client-preset
+            schemaOutput: src/generated/schema.d.ts
+            clientPresetOutput: src/generated/graphql.ts
+"#;
+
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.mode, GenerateMode::ClientPreset);
+    assert_eq!(
+        config.generate.client_preset_output,
+        Some(PathBuf::from("src/generated/graphql.ts"))
+    );
+}
+
+#[test]
+fn parse_validators_output() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            schemaOutput: src/generated/schema.d.ts
+            validatorsOutput: src/generated/validators.ts
+"#;
+
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.generate.validators_output,
+        Some(PathBuf::from("src/generated/validators.ts"))
+    );
+}
+
+#[test]
+fn parse_add_typename() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            addTypename: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.generate.add_typename);
+}
+
+#[test]
+fn parse_document_format() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            documentFormat: minified-string
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.generate.document_format,
+        DocumentFormat::MinifiedString
+    );
+
+    let config = parse_config("schema: schema.graphql").unwrap();
+    assert_eq!(
+        config.generate.document_format,
+        DocumentFormat::DocumentNode
+    );
 }
 
 #[test]
@@ -143,27 +303,17 @@ fn json_is_yaml() {
 }
 
 #[test]
-fn extra_fields_are_ignored() {
+fn extra_fields_in_other_extensions_are_ignored() {
     let config = r#"
 schema: schema.graphql
-documents: src/**/*.graphql
 extensions:
+    codegen:
+        extra: "field"
     nitrogql:
         generate:
             mode: standalone-ts-4.0
-            schemaOutput: src/generated/schema.d.ts
-            schemaModuleSpecifier: "@generated/schema"
-            extra: "field"
 "#;
 
     let config = parse_config(config).unwrap();
     assert_eq!(config.generate.mode, GenerateMode::StandaloneTS4_0);
-    assert_eq!(
-        config.generate.schema_output,
-        Some(PathBuf::from("src/generated/schema.d.ts"))
-    );
-    assert_eq!(
-        config.generate.schema_module_specifier,
-        Some("@generated/schema".to_owned())
-    );
 }
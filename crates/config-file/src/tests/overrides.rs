@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use crate::{GenerateMode, parse_config};
+
+const CONFIG: &str = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            mode: with-loader-ts-5.0
+            schemaOutput: src/generated/schema.d.ts
+            name:
+                queryVariableSuffix: Query
+        overrides:
+            - files: src/legacy/**
+              generate:
+                  mode: standalone-ts-4.0
+                  export:
+                      defaultExportForOperation: false
+            - files:
+                  - ./src/legacy/admin/**
+              generate:
+                  schemaModuleSpecifier: "@admin/schema"
+                  name:
+                      queryVariableSuffix: Doc
+            - files: src/**
+              generate:
+                  mode: with-loader-ts-4.0
+"#;
+
+#[test]
+fn parse_overrides() {
+    let config = parse_config(CONFIG).unwrap();
+    assert_eq!(config.overrides.len(), 3);
+    assert_eq!(config.overrides[0].files, vec!["src/legacy/**"]);
+    assert_eq!(
+        config.overrides[0].generate.mode,
+        Some(GenerateMode::StandaloneTS4_0)
+    );
+    assert_eq!(
+        config.overrides[0]
+            .generate
+            .export
+            .default_export_for_operation,
+        Some(false)
+    );
+    assert_eq!(config.overrides[1].files, vec!["./src/legacy/admin/**"]);
+    assert_eq!(config.overrides[1].generate.mode, None);
+    let config = parse_config("schema: schema.graphql").unwrap();
+    assert!(config.overrides.is_empty());
+}
+
+#[test]
+fn file_without_matching_override() {
+    let config = parse_config(CONFIG).unwrap();
+    let generate = config.generate_for_file(Path::new("/app"), Path::new("/app/lib/a.graphql"));
+    assert_eq!(generate.mode, GenerateMode::WithLoaderTS5_0);
+    assert!(generate.export.default_export_for_operation);
+    assert_eq!(
+        generate.name.query_variable_suffix.as_deref(),
+        Some("Query")
+    );
+}
+
+#[test]
+fn most_specific_override_wins() {
+    let config = parse_config(CONFIG).unwrap();
+    let root = Path::new("/app");
+
+    let generate = config.generate_for_file(root, Path::new("/app/src/a.graphql"));
+    assert_eq!(generate.mode, GenerateMode::WithLoaderTS4_0);
+
+    let generate = config.generate_for_file(root, Path::new("/app/src/legacy/a.graphql"));
+    assert_eq!(generate.mode, GenerateMode::StandaloneTS4_0);
+    assert!(!generate.export.default_export_for_operation);
+    assert_eq!(generate.schema_module_specifier, None);
+
+    // less specific overrides still apply to options not set by more specific ones
+    let generate = config.generate_for_file(root, Path::new("/app/src/legacy/admin/a.graphql"));
+    assert_eq!(generate.mode, GenerateMode::StandaloneTS4_0);
+    assert!(!generate.export.default_export_for_operation);
+    assert_eq!(
+        generate.schema_module_specifier.as_deref(),
+        Some("@admin/schema")
+    );
+    assert_eq!(generate.name.query_variable_suffix.as_deref(), Some("Doc"));
+    assert_eq!(
+        generate.schema_output, config.generate.schema_output,
+        "options that cannot be overridden are kept"
+    );
+}
+
+#[test]
+fn invalid_override_pattern() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        overrides:
+            - files: "src/[legacy/**"
+              generate:
+                  mode: standalone-ts-4.0
+"#;
+    let err = parse_config(config).unwrap_err();
+    assert!(
+        err.message.to_string().contains("invalid pattern"),
+        "unexpected error: {}",
+        err.message
+    );
+}
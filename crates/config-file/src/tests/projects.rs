@@ -0,0 +1,94 @@
+use std::path::Path;
+
+use crate::{parse_config, parse_projects};
+
+#[test]
+fn single_project() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let projects = parse_projects(config).unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, None);
+    assert_eq!(projects[0].config.schema, vec!["schema.graphql"]);
+}
+
+#[test]
+fn multiple_projects() {
+    let config = r#"
+projects:
+    public:
+        schema: public/schema.graphql
+        documents: public/**/*.graphql
+        extensions:
+            nitrogql:
+                generate:
+                    schemaOutput: public/generated/schema.d.ts
+    admin:
+        schema: admin/schema.graphql
+        documents: admin/**/*.graphql
+"#;
+    let projects = parse_projects(config).unwrap();
+    let names = projects
+        .iter()
+        .map(|project| project.name.as_deref())
+        .collect::<Vec<_>>();
+    // order of appearance is kept
+    assert_eq!(names, vec![Some("public"), Some("admin")]);
+    assert_eq!(projects[0].config.schema, vec!["public/schema.graphql"]);
+    assert_eq!(
+        projects[0].config.generate.schema_output.as_deref(),
+        Some("public/generated/schema.d.ts".as_ref())
+    );
+    assert_eq!(projects[1].config.operations, vec!["admin/**/*.graphql"]);
+    assert_eq!(projects[1].config.generate.schema_output, None);
+}
+
+#[test]
+fn default_project() {
+    let config = r#"
+projects:
+    admin:
+        schema: admin/schema.graphql
+    default:
+        schema: schema.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.schema, vec!["schema.graphql"]);
+
+    let config = r#"
+projects:
+    admin:
+        schema: admin/schema.graphql
+"#;
+    assert!(parse_config(config).is_err());
+}
+
+#[test]
+fn project_includes_operation() {
+    let config = r#"
+projects:
+    public:
+        schema: public/schema.graphql
+        documents:
+            - ./public/**/*.graphql
+            - "!public/legacy/**"
+    admin:
+        schema: admin/schema.graphql
+        documents: admin/**/*.graphql
+        exclude: admin/generated/**
+"#;
+    let projects = parse_projects(config).unwrap();
+    let root = Path::new("/app");
+    let includes = |index: usize, file: &str| {
+        projects[index]
+            .config
+            .includes_operation(root, Path::new(file))
+    };
+    assert!(includes(0, "/app/public/a.graphql"));
+    assert!(!includes(0, "/app/public/legacy/a.graphql"));
+    assert!(!includes(0, "/app/admin/a.graphql"));
+    assert!(includes(1, "/app/admin/pages/a.graphql"));
+    assert!(!includes(1, "/app/admin/generated/a.graphql"));
+}
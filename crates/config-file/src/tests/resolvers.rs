@@ -0,0 +1,71 @@
+use crate::{TypeImportConfig, parse_config};
+
+#[test]
+fn default_config() {
+    let config = parse_config("schema: schema.graphql").unwrap();
+    let resolvers = config.generate.resolvers;
+    assert_eq!(resolvers.root_type_name, None);
+    assert_eq!(resolvers.output_type_name, None);
+    assert_eq!(resolvers.schema_namespace, None);
+    assert_eq!(resolvers.context_type, None);
+    assert_eq!(resolvers.info_type, None);
+}
+
+#[test]
+fn resolvers_config() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            resolvers:
+                rootTypeName: GraphQLResolvers
+                outputTypeName: GraphQLResolverOutput
+                schemaNamespace: GraphQLSchema
+                contextType:
+                    module: "@/server/context"
+                    name: Context
+                infoType:
+                    module: graphql
+                    name: GraphQLResolveInfo
+"#;
+    let config = parse_config(config).unwrap();
+    let resolvers = config.generate.resolvers;
+    assert_eq!(
+        resolvers.root_type_name.as_deref(),
+        Some("GraphQLResolvers")
+    );
+    assert_eq!(
+        resolvers.output_type_name.as_deref(),
+        Some("GraphQLResolverOutput")
+    );
+    assert_eq!(resolvers.schema_namespace.as_deref(), Some("GraphQLSchema"));
+    assert_eq!(
+        resolvers.context_type,
+        Some(TypeImportConfig {
+            module: "@/server/context".to_owned(),
+            name: "Context".to_owned(),
+        })
+    );
+    assert_eq!(
+        resolvers.info_type,
+        Some(TypeImportConfig {
+            module: "graphql".to_owned(),
+            name: "GraphQLResolveInfo".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn type_import_requires_name() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            resolvers:
+                contextType:
+                    module: "@/server/context"
+"#;
+    assert!(parse_config(config).is_err());
+}
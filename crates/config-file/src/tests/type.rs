@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{ScalarTypeConfig, parse_config};
+use crate::{EnumMemberCase, EnumStyle, ScalarTypeConfig, parse_config};
 
 #[test]
 fn default_config() {
@@ -12,6 +12,11 @@ documents: []
     let ty = config.generate.r#type;
     assert_eq!(ty.scalar_types, HashMap::new());
     assert!(ty.allow_undefined_as_optional_input);
+    assert!(!ty.fragment_masking);
+    assert!(!ty.throw_on_field_error);
+    assert!(!ty.future_proof_abstract_types);
+    assert!(ty.branded_scalars.is_empty());
+    assert_eq!(ty.scalar_validators, HashMap::new());
 }
 
 #[test]
@@ -58,3 +63,136 @@ extensions:
     assert_eq!(ty.scalar_types, expected);
     assert!(!ty.allow_undefined_as_optional_input);
 }
+
+#[test]
+fn fragment_masking() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                fragmentMasking: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.generate.r#type.fragment_masking);
+}
+
+#[test]
+fn throw_on_field_error() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                throwOnFieldError: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.generate.r#type.throw_on_field_error);
+}
+
+#[test]
+fn future_proof_abstract_types() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                futureProofAbstractTypes: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.generate.r#type.future_proof_abstract_types);
+}
+
+#[test]
+fn branded_scalars() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                brandedScalars:
+                    - UserId
+                    - PostId
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.generate.r#type.branded_scalars,
+        vec!["UserId".to_owned(), "PostId".to_owned()]
+    );
+}
+
+#[test]
+fn scalar_validators() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                scalarValidators:
+                    Date: z.coerce.date()
+                    URL: z.string().url()
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.generate.r#type.scalar_validators,
+        vec![
+            ("Date".to_owned(), "z.coerce.date()".to_owned()),
+            ("URL".to_owned(), "z.string().url()".to_owned()),
+        ]
+        .into_iter()
+        .collect::<HashMap<_, _>>()
+    );
+}
+
+#[test]
+fn enum_style() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                enumStyle: enum
+                enumMemberCase: pascal-case
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.r#type.enum_style, Some(EnumStyle::Enum));
+    assert_eq!(
+        config.generate.r#type.enum_member_case,
+        EnumMemberCase::PascalCase
+    );
+    assert!(config.generate.schema_has_runtime());
+}
+
+#[test]
+fn enum_style_default() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.enum_style(), EnumStyle::Union);
+    assert!(!config.generate.schema_has_runtime());
+
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            emitSchemaRuntime: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.enum_style(), EnumStyle::ConstObject);
+}
@@ -0,0 +1,135 @@
+use crate::{ConfigValidationErrorMessage, parse_config, parse_projects};
+
+#[test]
+fn unknown_key_in_generate() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            shemaOutput: src/generated/schema.d.ts
+"#;
+    let err = parse_config(config).unwrap_err();
+    assert_eq!(err.position, Some((5, 12)));
+    let ConfigValidationErrorMessage::UnknownKey {
+        key,
+        path,
+        suggestions,
+    } = err.message
+    else {
+        panic!("unexpected error: {:?}", err.message);
+    };
+    assert_eq!(key, "shemaOutput");
+    assert_eq!(path.as_deref(), Some("extensions.nitrogql.generate"));
+    assert_eq!(suggestions, vec!["schemaOutput"]);
+}
+
+#[test]
+fn unknown_key_at_top_level() {
+    let config = r#"
+schema: schema.graphql
+document: src/**/*.graphql
+"#;
+    let err = parse_config(config).unwrap_err();
+    assert_eq!(err.message.to_string(), "Unknown key 'document'.");
+    let ConfigValidationErrorMessage::UnknownKey { suggestions, .. } = err.message else {
+        panic!("unexpected error: {:?}", err.message);
+    };
+    assert_eq!(suggestions, vec!["documents"]);
+}
+
+#[test]
+fn unknown_key_in_project() {
+    let config = r#"
+projects:
+    admin:
+        schema: admin/schema.graphql
+        extensions:
+            nitrogql:
+                check:
+                    noImportCycle: true
+"#;
+    let err = parse_projects(config).unwrap_err();
+    assert_eq!(err.position, Some((7, 20)));
+    let ConfigValidationErrorMessage::UnknownKey {
+        path, suggestions, ..
+    } = err.message
+    else {
+        panic!("unexpected error: {:?}", err.message);
+    };
+    assert_eq!(
+        path.as_deref(),
+        Some("projects.admin.extensions.nitrogql.check")
+    );
+    assert_eq!(suggestions, vec!["noImportCycles"]);
+}
+
+#[test]
+fn unknown_key_in_override() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        overrides:
+            - files: src/**
+              generate:
+                  mod: standalone-ts4.0
+"#;
+    let err = parse_config(config).unwrap_err();
+    let ConfigValidationErrorMessage::UnknownKey {
+        key,
+        path,
+        suggestions,
+    } = err.message
+    else {
+        panic!("unexpected error: {:?}", err.message);
+    };
+    assert_eq!(key, "mod");
+    assert_eq!(
+        path.as_deref(),
+        Some("extensions.nitrogql.overrides[0].generate")
+    );
+    assert_eq!(suggestions, vec!["mode"]);
+}
+
+#[test]
+fn invalid_value() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        plugins: nitrogql:model-plugin
+"#;
+    let err = parse_config(config).unwrap_err();
+    assert_eq!(err.position, Some((4, 17)));
+    assert_eq!(
+        err.message.to_string(),
+        "extensions.nitrogql.plugins: invalid type: string \"nitrogql:model-plugin\", expected a sequence."
+    );
+}
+
+#[test]
+fn invalid_yaml() {
+    let config = r#"
+schema: schema.graphql
+documents: [src/**/*.graphql
+"#;
+    let err = parse_config(config).unwrap_err();
+    assert!(matches!(
+        err.message,
+        ConfigValidationErrorMessage::Invalid(_)
+    ));
+    assert!(err.position.is_some());
+}
+
+#[test]
+fn json_schema_key_is_allowed() {
+    let config = r#"
+{
+    "$schema": "./node_modules/@nitrogql/cli/config-schema.json",
+    "schema": "schema.graphql"
+}
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.schema, vec!["schema.graphql"]);
+}
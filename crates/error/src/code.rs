@@ -0,0 +1,554 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Category of a diagnostic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// Errors found while parsing GraphQL documents.
+    Syntax,
+    /// Errors in schema definitions.
+    Schema,
+    /// Errors in operation documents.
+    Operation,
+    /// Errors reported by plugins.
+    Plugin,
+    /// Errors in config files.
+    Config,
+    /// Errors in running the command line tool.
+    Cli,
+}
+
+impl ErrorCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Syntax => "syntax",
+            ErrorCategory::Schema => "schema",
+            ErrorCategory::Operation => "operation",
+            ErrorCategory::Plugin => "plugin",
+            ErrorCategory::Config => "config",
+            ErrorCategory::Cli => "cli",
+        }
+    }
+}
+
+impl Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Stable code that identifies a kind of diagnostic.
+/// Printed in the form of `NGQL0012`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorCode(pub u16);
+
+impl ErrorCode {
+    /// Returns information about this code.
+    pub fn info(self) -> Option<&'static ErrorCodeInfo> {
+        ERROR_CODES.iter().find(|info| info.code == self)
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NGQL{:04}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FromStrError;
+
+impl Display for FromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error code must be in the form of NGQL0000")
+    }
+}
+
+impl std::error::Error for FromStrError {}
+
+impl FromStr for ErrorCode {
+    type Err = FromStrError;
+
+    /// Parses an error code. The `NGQL` prefix is case-insensitive and may be omitted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = match s.get(..4) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("NGQL") => &s[4..],
+            _ => s,
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(FromStrError);
+        }
+        digits.parse().map(ErrorCode).map_err(|_| FromStrError)
+    }
+}
+
+/// Description of one error code.
+#[derive(Debug)]
+pub struct ErrorCodeInfo {
+    pub code: ErrorCode,
+    pub category: ErrorCategory,
+    /// Short kebab-case name of the error.
+    pub name: &'static str,
+    /// One-line summary of the error.
+    pub summary: &'static str,
+    /// Longer explanation shown by `nitrogql explain`.
+    pub explanation: &'static str,
+}
+
+/// Error codes issued by nitrogql.
+/// Codes are stable; never renumber or reuse them.
+pub mod codes {
+    use super::ErrorCode;
+
+    // syntax
+    pub const SYNTAX_ERROR: ErrorCode = ErrorCode(1);
+    pub const WILDCARD_IMPORT_ONLY_ONCE: ErrorCode = ErrorCode(2);
+    pub const WILDCARD_IMPORT_WITH_SPECIFIC: ErrorCode = ErrorCode(3);
+    // schema
+    pub const RESERVED_NAME: ErrorCode = ErrorCode(101);
+    pub const DUPLICATED_NAME: ErrorCode = ErrorCode(102);
+    pub const UNKNOWN_TYPE: ErrorCode = ErrorCode(103);
+    pub const RECURSING_DIRECTIVE: ErrorCode = ErrorCode(104);
+    pub const NO_OUTPUT_TYPE: ErrorCode = ErrorCode(105);
+    pub const NO_INPUT_TYPE: ErrorCode = ErrorCode(106);
+    pub const NOT_INTERFACE: ErrorCode = ErrorCode(107);
+    pub const INTERFACE_NOT_IMPLEMENTED: ErrorCode = ErrorCode(108);
+    pub const NO_IMPLEMENT_SELF: ErrorCode = ErrorCode(109);
+    pub const INTERFACE_FIELD_NOT_IMPLEMENTED: ErrorCode = ErrorCode(110);
+    pub const FIELD_TYPE_MISMATCH_WITH_INTERFACE: ErrorCode = ErrorCode(111);
+    pub const INTERFACE_ARGUMENT_NOT_IMPLEMENTED: ErrorCode = ErrorCode(112);
+    pub const ARGUMENT_TYPE_MISMATCH_WITH_INTERFACE: ErrorCode = ErrorCode(113);
+    pub const ARGUMENT_TYPE_NON_NULL_AGAINST_INTERFACE: ErrorCode = ErrorCode(114);
+    pub const NON_OBJECT_TYPE_UNION_MEMBER: ErrorCode = ErrorCode(115);
+    pub const ONE_OF_FIELD_NOT_NULLABLE: ErrorCode = ErrorCode(116);
+    pub const ONE_OF_FIELD_WITH_DEFAULT_VALUE: ErrorCode = ErrorCode(117);
+    pub const DUPLICATED_DECLARATION: ErrorCode = ErrorCode(118);
+    pub const EXTENSION_WITHOUT_ORIGINAL: ErrorCode = ErrorCode(119);
+    pub const SCHEMA_IMPORT_FILE_NOT_FOUND: ErrorCode = ErrorCode(120);
+    pub const SCHEMA_IMPORT_TARGET_NOT_FOUND: ErrorCode = ErrorCode(121);
+    pub const NOT_IMPORTED: ErrorCode = ErrorCode(122);
+    pub const SEMANTIC_NON_NULL_LEVEL_OUT_OF_RANGE: ErrorCode = ErrorCode(123);
+    pub const BRAND_ON_NON_SCALAR_TYPE: ErrorCode = ErrorCode(124);
+    pub const SCHEMA_IMPORT_CYCLE: ErrorCode = ErrorCode(125);
+    // operation
+    pub const UNKNOWN_DIRECTIVE: ErrorCode = ErrorCode(201);
+    pub const DIRECTIVE_LOCATION_NOT_ALLOWED: ErrorCode = ErrorCode(202);
+    pub const REPEATED_DIRECTIVE: ErrorCode = ErrorCode(203);
+    pub const ARGUMENTS_NOT_NEEDED: ErrorCode = ErrorCode(204);
+    pub const REQUIRED_ARGUMENT_NOT_SPECIFIED: ErrorCode = ErrorCode(205);
+    pub const TYPE_MISMATCH: ErrorCode = ErrorCode(206);
+    pub const UNKNOWN_VARIABLE: ErrorCode = ErrorCode(207);
+    pub const UNKNOWN_ENUM_MEMBER: ErrorCode = ErrorCode(208);
+    pub const UNKNOWN_ARGUMENT: ErrorCode = ErrorCode(209);
+    pub const REQUIRED_FIELD_NOT_SPECIFIED: ErrorCode = ErrorCode(210);
+    pub const UNKNOWN_FIELD: ErrorCode = ErrorCode(211);
+    pub const UNNAMED_OPERATION_MUST_BE_SINGLE: ErrorCode = ErrorCode(212);
+    pub const DUPLICATE_OPERATION_NAME: ErrorCode = ErrorCode(213);
+    pub const DUPLICATE_FRAGMENT_NAME: ErrorCode = ErrorCode(214);
+    pub const NO_ROOT_TYPE: ErrorCode = ErrorCode(215);
+    pub const SELECTION_ON_INVALID_TYPE: ErrorCode = ErrorCode(216);
+    pub const MUST_SPECIFY_SELECTION_SET: ErrorCode = ErrorCode(217);
+    pub const FIELD_NOT_FOUND: ErrorCode = ErrorCode(218);
+    pub const DUPLICATED_VARIABLE_NAME: ErrorCode = ErrorCode(219);
+    pub const INVALID_FRAGMENT_TARGET: ErrorCode = ErrorCode(220);
+    pub const UNKNOWN_FRAGMENT: ErrorCode = ErrorCode(221);
+    pub const FRAGMENT_CONDITION_NEVER_MATCHES: ErrorCode = ErrorCode(222);
+    pub const RECURSING_FRAGMENT_SPREAD: ErrorCode = ErrorCode(223);
+    pub const SUBSCRIPTION_MUST_HAVE_EXACTLY_ONE_ROOT_FIELD: ErrorCode = ErrorCode(224);
+    pub const ONE_OF_INPUT_NOT_EXACTLY_ONE_FIELD: ErrorCode = ErrorCode(225);
+    pub const ONE_OF_INPUT_NULL_VALUE: ErrorCode = ErrorCode(226);
+    pub const ONE_OF_INPUT_NULLABLE_VARIABLE: ErrorCode = ErrorCode(227);
+    pub const IMPORT_FILE_NOT_FOUND: ErrorCode = ErrorCode(228);
+    pub const IMPORT_TARGET_NOT_FOUND: ErrorCode = ErrorCode(229);
+    pub const TYPE_SYSTEM_ERROR: ErrorCode = ErrorCode(230);
+    pub const IMPORTED_OPERATION_NAME_CONFLICT: ErrorCode = ErrorCode(231);
+    pub const UNUSED_IMPORT: ErrorCode = ErrorCode(232);
+    pub const IMPORT_CYCLE: ErrorCode = ErrorCode(233);
+    pub const CLIENT_PRESET_NAME_CONFLICT: ErrorCode = ErrorCode(234);
+    pub const CLIENT_PRESET_NO_LOOKUP_TARGET: ErrorCode = ErrorCode(235);
+    pub const IMPORTED_OPERATION_NOT_EXPORTED: ErrorCode = ErrorCode(236);
+    pub const VALIDATOR_NAME_CONFLICT: ErrorCode = ErrorCode(237);
+    // plugin
+    pub const PLUGIN_ERROR: ErrorCode = ErrorCode(301);
+    // config
+    pub const INVALID_CONFIG: ErrorCode = ErrorCode(401);
+    pub const UNKNOWN_CONFIG_KEY: ErrorCode = ErrorCode(402);
+    pub const NO_SCHEMA_SPECIFIED: ErrorCode = ErrorCode(403);
+    pub const INTROSPECTION_ONCE: ErrorCode = ErrorCode(404);
+    pub const MIX_GRAPHQL_AND_INTROSPECTION: ErrorCode = ErrorCode(405);
+    pub const OPTION_REQUIRED: ErrorCode = ErrorCode(406);
+    pub const CANNOT_EMIT_RUNTIME_TO_DTS: ErrorCode = ErrorCode(407);
+    pub const NO_PROJECT_DEFINED: ErrorCode = ErrorCode(408);
+    pub const PROJECT_NOT_FOUND: ErrorCode = ErrorCode(409);
+    pub const CANNOT_LOAD_PLUGIN: ErrorCode = ErrorCode(410);
+    pub const INVALID_GLOB: ErrorCode = ErrorCode(411);
+    pub const PATTERN_MATCHES_NO_FILES: ErrorCode = ErrorCode(412);
+    pub const MISSING_SCALAR_VALIDATOR: ErrorCode = ErrorCode(413);
+    // cli
+    pub const NO_COMMAND_SPECIFIED: ErrorCode = ErrorCode(501);
+    pub const UNKNOWN_COMMAND: ErrorCode = ErrorCode(502);
+    pub const INVALID_COMMAND: ErrorCode = ErrorCode(503);
+    pub const UNKNOWN_ERROR_CODE: ErrorCode = ErrorCode(504);
+    pub const SOURCE_MAP_FILE_NAME: ErrorCode = ErrorCode(505);
+    pub const COMMAND_NOT_SUCCESSFUL: ErrorCode = ErrorCode(506);
+}
+
+macro_rules! error_codes {
+    ($($code:ident, $category:ident, $name:literal, $summary:literal, $explanation:literal;)*) => {
+        /// All error codes issued by nitrogql.
+        pub static ERROR_CODES: &[ErrorCodeInfo] = &[
+            $(ErrorCodeInfo {
+                code: codes::$code,
+                category: ErrorCategory::$category,
+                name: $name,
+                summary: $summary,
+                explanation: $explanation,
+            },)*
+        ];
+    };
+}
+
+error_codes! {
+    SYNTAX_ERROR, Syntax, "syntax-error",
+    "The document is not valid GraphQL syntax.",
+    "The parser could not read the document. Check the reported position for a missing brace, \
+     an unterminated string or a misplaced keyword.";
+    WILDCARD_IMPORT_ONLY_ONCE, Syntax, "wildcard-import-only-once",
+    "A wildcard import is specified more than once.",
+    "An `#import * from \"...\"` statement imports everything from a file, so it must appear \
+     at most once per imported file.";
+    WILDCARD_IMPORT_WITH_SPECIFIC, Syntax, "wildcard-import-with-specific",
+    "A wildcard import is combined with specific imports.",
+    "When everything is imported from a file with `*`, names from the same file cannot also \
+     be listed individually. Remove either the wildcard or the specific names.";
+    RESERVED_NAME, Schema, "reserved-name",
+    "A name starting with '__' is used.",
+    "Names that start with two underscores are reserved for GraphQL introspection. \
+     Rename the definition.";
+    DUPLICATED_NAME, Schema, "duplicated-name",
+    "A name is defined more than once.",
+    "Each field, argument, enum member or input field must have a unique name within its \
+     parent definition.";
+    UNKNOWN_TYPE, Schema, "unknown-type",
+    "A type that is not defined in the schema is referenced.",
+    "Every referenced type must be defined in one of the schema files. Check the spelling \
+     or add the missing type definition.";
+    RECURSING_DIRECTIVE, Schema, "recursing-directive",
+    "A directive references itself.",
+    "A directive definition must not use itself, directly or indirectly, in the definitions \
+     of its arguments.";
+    NO_OUTPUT_TYPE, Schema, "no-output-type",
+    "An output type is used where only input types are allowed.",
+    "Arguments, variables and input object fields can only have scalar, enum or input object \
+     types.";
+    NO_INPUT_TYPE, Schema, "no-input-type",
+    "An input type is used where only output types are allowed.",
+    "Fields of object types and interfaces cannot have input object types.";
+    NOT_INTERFACE, Schema, "not-interface",
+    "A type that is not an interface is implemented.",
+    "Only interfaces can be listed after `implements`.";
+    INTERFACE_NOT_IMPLEMENTED, Schema, "interface-not-implemented",
+    "An interface implemented by a parent interface is not implemented.",
+    "If a type implements an interface, it must also implement every interface that the \
+     interface implements.";
+    NO_IMPLEMENT_SELF, Schema, "no-implement-self",
+    "An interface implements itself.",
+    "An interface must not list itself after `implements`.";
+    INTERFACE_FIELD_NOT_IMPLEMENTED, Schema, "interface-field-not-implemented",
+    "A field required by an interface is missing.",
+    "A type that implements an interface must define every field of the interface.";
+    FIELD_TYPE_MISMATCH_WITH_INTERFACE, Schema, "field-type-mismatch-with-interface",
+    "A field type is not compatible with the same field of an interface.",
+    "The type of a field must be the same as, or a subtype of, the type of the corresponding \
+     interface field.";
+    INTERFACE_ARGUMENT_NOT_IMPLEMENTED, Schema, "interface-argument-not-implemented",
+    "An argument required by an interface field is missing.",
+    "A field that implements an interface field must accept every argument of the interface \
+     field.";
+    ARGUMENT_TYPE_MISMATCH_WITH_INTERFACE, Schema, "argument-type-mismatch-with-interface",
+    "An argument type differs from the same argument of an interface.",
+    "Arguments of a field that implements an interface field must have exactly the same \
+     types as the interface field.";
+    ARGUMENT_TYPE_NON_NULL_AGAINST_INTERFACE, Schema, "argument-type-non-null-against-interface",
+    "An additional argument is non-null.",
+    "Arguments that are not present in the interface field must be nullable, so that the \
+     field can be queried through the interface.";
+    NON_OBJECT_TYPE_UNION_MEMBER, Schema, "non-object-type-union-member",
+    "A union member is not an object type.",
+    "Members of a union must all be object types.";
+    ONE_OF_FIELD_NOT_NULLABLE, Schema, "one-of-field-not-nullable",
+    "A field of a @oneOf input object is non-null.",
+    "Every field of a @oneOf input object must be nullable because only one of them is \
+     given at a time.";
+    ONE_OF_FIELD_WITH_DEFAULT_VALUE, Schema, "one-of-field-with-default-value",
+    "A field of a @oneOf input object has a default value.",
+    "Fields of a @oneOf input object must not have default values.";
+    DUPLICATED_DECLARATION, Schema, "duplicated-declaration",
+    "A type or directive is declared more than once.",
+    "Each type and directive must be declared once. Use `extend` to add to an existing \
+     declaration.";
+    EXTENSION_WITHOUT_ORIGINAL, Schema, "extension-without-original",
+    "An extension has no original declaration.",
+    "A type or schema extension requires the extended definition to be declared somewhere \
+     in the schema.";
+    SCHEMA_IMPORT_FILE_NOT_FOUND, Schema, "schema-import-file-not-found",
+    "A file imported from a schema file is not found or its path cannot be resolved.",
+    "Paths in `#import` are resolved relative to the importing file, through the 'paths' \
+     option of the config file, or as packages in node_modules.";
+    SCHEMA_IMPORT_TARGET_NOT_FOUND, Schema, "schema-import-target-not-found",
+    "An imported name is not found in the imported schema file.",
+    "Names listed in `#import` in schema files must be names of types or directives defined \
+     in the imported file.";
+    NOT_IMPORTED, Schema, "not-imported",
+    "A type or directive is used in a schema file that neither defines nor imports it.",
+    "When the schema is loaded from 'schemaEntry', each schema file must define or `#import` \
+     every type and directive it uses. Built-in types and directives are always available.";
+    SEMANTIC_NON_NULL_LEVEL_OUT_OF_RANGE, Schema, "semantic-non-null-level-out-of-range",
+    "A level given to @semanticNonNull does not exist in the field type.",
+    "Level 0 refers to the field itself and each list wrapper adds one level. Levels must \
+     not be negative nor exceed the list depth of the field type.";
+    BRAND_ON_NON_SCALAR_TYPE, Schema, "brand-on-non-scalar-type",
+    "@brand is applied to a field whose type is not a scalar type.",
+    "Brands are added to TypeScript types of scalar values. Fields whose type (ignoring \
+     list and non-null wrappers) is not a scalar type cannot have @brand.";
+    SCHEMA_IMPORT_CYCLE, Schema, "schema-import-cycle",
+    "Schema files import each other.",
+    "Imports between schema files must not form a cycle. When types in different files refer \
+     to each other, import one of them and add the field that closes the cycle with `extend` \
+     in a file that imports both.";
+    UNKNOWN_DIRECTIVE, Operation, "unknown-directive",
+    "A directive that is not defined is used.",
+    "Every applied directive must be defined in the schema or be one of the built-in \
+     directives.";
+    DIRECTIVE_LOCATION_NOT_ALLOWED, Operation, "directive-location-not-allowed",
+    "A directive is used at a location it does not support.",
+    "A directive can only be applied to the locations listed in its definition.";
+    REPEATED_DIRECTIVE, Operation, "repeated-directive",
+    "A non-repeatable directive is applied more than once.",
+    "Mark the directive definition as `repeatable` to apply it multiple times to the same \
+     location.";
+    ARGUMENTS_NOT_NEEDED, Operation, "arguments-not-needed",
+    "Arguments are given to something that takes none.",
+    "Remove the argument list, or add arguments to the definition.";
+    REQUIRED_ARGUMENT_NOT_SPECIFIED, Operation, "required-argument-not-specified",
+    "A required argument is missing.",
+    "Non-null arguments without a default value must always be specified.";
+    TYPE_MISMATCH, Operation, "type-mismatch",
+    "A value does not match the expected type.",
+    "Literal values and variables must be compatible with the type of the argument or \
+     input field they are given to.";
+    UNKNOWN_VARIABLE, Operation, "unknown-variable",
+    "A variable that is not defined is used.",
+    "Variables must be declared in the variable definitions of the operation.";
+    UNKNOWN_ENUM_MEMBER, Operation, "unknown-enum-member",
+    "A value is not a member of the enum.",
+    "Enum values must be one of the members of the expected enum type.";
+    UNKNOWN_ARGUMENT, Operation, "unknown-argument",
+    "An argument that is not defined is given.",
+    "Only arguments declared in the field or directive definition can be given.";
+    REQUIRED_FIELD_NOT_SPECIFIED, Operation, "required-field-not-specified",
+    "A required input field is missing.",
+    "Non-null input fields without a default value must be specified in object values.";
+    UNKNOWN_FIELD, Operation, "unknown-field",
+    "An input field that is not defined is given.",
+    "Object values can only contain fields declared in the input object type.";
+    UNNAMED_OPERATION_MUST_BE_SINGLE, Operation, "unnamed-operation-must-be-single",
+    "An unnamed operation is mixed with other operations.",
+    "An operation without a name is only allowed when it is the only operation in the \
+     document.";
+    DUPLICATE_OPERATION_NAME, Operation, "duplicate-operation-name",
+    "Operation names are duplicated.",
+    "Each operation in a document must have a unique name.";
+    DUPLICATE_FRAGMENT_NAME, Operation, "duplicate-fragment-name",
+    "Fragment names are duplicated.",
+    "Each fragment must have a unique name.";
+    NO_ROOT_TYPE, Operation, "no-root-type",
+    "The schema has no root type for the operation.",
+    "The schema definition does not declare a root type for this kind of operation, \
+     e.g. `mutation`.";
+    SELECTION_ON_INVALID_TYPE, Operation, "selection-on-invalid-type",
+    "Fields are selected on a scalar or enum.",
+    "Selection sets are only allowed on object, interface and union types.";
+    MUST_SPECIFY_SELECTION_SET, Operation, "must-specify-selection-set",
+    "A selection set is missing.",
+    "Fields of object, interface or union types must have a selection set.";
+    FIELD_NOT_FOUND, Operation, "field-not-found",
+    "A selected field does not exist on the type.",
+    "Only fields declared on the parent type can be selected. Use a fragment with a type \
+     condition to select fields of a more specific type.";
+    DUPLICATED_VARIABLE_NAME, Operation, "duplicated-variable-name",
+    "A variable is defined more than once.",
+    "Each variable of an operation must have a unique name.";
+    INVALID_FRAGMENT_TARGET, Operation, "invalid-fragment-target",
+    "A fragment targets a type that cannot have selections.",
+    "Type conditions must be object, interface or union types.";
+    UNKNOWN_FRAGMENT, Operation, "unknown-fragment",
+    "A fragment that is not defined is spread.",
+    "Spread fragments must be defined in the same document or imported with `#import`.";
+    FRAGMENT_CONDITION_NEVER_MATCHES, Operation, "fragment-condition-never-matches",
+    "A fragment can never apply to its parent type.",
+    "The type condition of the fragment and the parent type have no possible types in \
+     common.";
+    RECURSING_FRAGMENT_SPREAD, Operation, "recursing-fragment-spread",
+    "A fragment spreads itself.",
+    "Fragments must not spread themselves, directly or indirectly.";
+    SUBSCRIPTION_MUST_HAVE_EXACTLY_ONE_ROOT_FIELD, Operation, "subscription-single-root-field",
+    "A subscription selects more than one root field.",
+    "Subscription operations must select exactly one root field.";
+    ONE_OF_INPUT_NOT_EXACTLY_ONE_FIELD, Operation, "one-of-input-not-exactly-one-field",
+    "A @oneOf input value does not have exactly one field.",
+    "Values of @oneOf input objects must specify exactly one field.";
+    ONE_OF_INPUT_NULL_VALUE, Operation, "one-of-input-null-value",
+    "A field of a @oneOf input value is null.",
+    "The single field given to a @oneOf input object must not be null.";
+    ONE_OF_INPUT_NULLABLE_VARIABLE, Operation, "one-of-input-nullable-variable",
+    "A nullable variable is used for a @oneOf input field.",
+    "Variables used for fields of @oneOf input objects must be of non-null types.";
+    IMPORT_FILE_NOT_FOUND, Operation, "import-file-not-found",
+    "An imported file is not found or its path cannot be resolved.",
+    "Paths in `#import` are resolved relative to the importing file, through the 'paths' \
+     option of the config file, or as packages in node_modules.";
+    IMPORT_TARGET_NOT_FOUND, Operation, "import-target-not-found",
+    "An imported name is not found in the imported file.",
+    "Names listed in `#import` must be names of fragments or operations defined in the \
+     imported file.";
+    TYPE_SYSTEM_ERROR, Operation, "type-system-error",
+    "An operation could not be checked because of a schema error.",
+    "This error should have been reported while checking the schema. Please report it as a \
+     bug of nitrogql.";
+    IMPORTED_OPERATION_NAME_CONFLICT, Operation, "imported-operation-name-conflict",
+    "An imported operation has the same name as another operation.",
+    "Imported operations are re-exported from the importing document, so their names must not \
+     conflict with operations defined in or imported into the same document.";
+    UNUSED_IMPORT, Operation, "unused-import",
+    "An imported fragment is never used.",
+    "This error is reported only when 'check.noUnusedImports' is enabled in the config file. \
+     Every fragment listed in `#import` must then be spread in the importing document, and a \
+     wildcard import must provide at least one fragment that is spread. Fragments used by \
+     imported fragments are imported together, so they need not be imported explicitly.";
+    IMPORT_CYCLE, Operation, "import-cycle",
+    "Operation documents import each other.",
+    "This error is reported only when 'check.noImportCycles' is enabled in the config file. \
+     Move the shared fragments to a separate file so that imports form no cycle.";
+    CLIENT_PRESET_NAME_CONFLICT, Operation, "client-preset-name-conflict",
+    "Two documents of the client preset module define operations or fragments with the same name.",
+    "All documents in the 'client-preset' mode are generated into one module, in which every \
+     operation and fragment is exported by its name. Rename one of the definitions.";
+    CLIENT_PRESET_NO_LOOKUP_TARGET, Operation, "client-preset-no-lookup-target",
+    "A document of the client preset module cannot be looked up by its source text.",
+    "The 'graphql' function of the client preset module returns the only operation of the \
+     given document, or the only fragment if it has no operation. Documents with more \
+     definitions are reported as a warning. Their definitions can still be imported by name \
+     from the module.";
+    IMPORTED_OPERATION_NOT_EXPORTED, Operation, "imported-operation-not-exported",
+    "An imported operation cannot be re-exported because its file does not export it.",
+    "Imported operations are re-exported from the generated code of the importing document, \
+     assuming that the imported file is generated with the same options. By default, an \
+     operation is exported only as the default export of a file that has exactly one \
+     operation. Move the operation to its own file, or set \
+     'generate.export.defaultExportForOperation' to false so that operations are exported by \
+     name.";
+    VALIDATOR_NAME_CONFLICT, Operation, "validator-name-conflict",
+    "Two validators of the validators module have the same name.",
+    "A variables validator is named after its operation with the 'variablesTypeSuffix' \
+     option, and exported from the validators module together with validators of input \
+     types, enums and scalars of the schema. Rename the operation or change the suffix so \
+     that no two validators have the same name.";
+    PLUGIN_ERROR, Plugin, "plugin-error",
+    "A plugin reported an error.",
+    "The message is given by the plugin. Refer to the documentation of the plugin.";
+    INVALID_CONFIG, Config, "invalid-config",
+    "The config file cannot be read as a valid config.",
+    "The config file must be valid YAML or JSON in the GraphQL Config format, and each option \
+     must have a value of the documented type.";
+    UNKNOWN_CONFIG_KEY, Config, "unknown-config-key",
+    "An unknown key is found in the config file.",
+    "Keys in the config file and in 'extensions.nitrogql' must be ones documented as options. \
+     Check the spelling of the key. Keys of other tools' extensions are not checked.";
+    NO_SCHEMA_SPECIFIED, Config, "no-schema-specified",
+    "No schema file is specified.",
+    "Specify schema files with the 'schema' option or the entry file with the 'schemaEntry' \
+     option of the config file.";
+    INTROSPECTION_ONCE, Config, "introspection-once",
+    "More than one introspection JSON file is specified.",
+    "A schema can be loaded from at most one introspection JSON file.";
+    MIX_GRAPHQL_AND_INTROSPECTION, Config, "mix-graphql-and-introspection",
+    "Both GraphQL files and an introspection JSON file are specified for the schema.",
+    "A schema must be given either as GraphQL files or as one introspection JSON file.";
+    OPTION_REQUIRED, Config, "option-required",
+    "An option required by the command is missing.",
+    "Some commands need options of the config file or arguments, e.g. 'generate' needs \
+     'extensions.nitrogql.generate.schemaOutput'. The message names the missing option.";
+    CANNOT_EMIT_RUNTIME_TO_DTS, Config, "cannot-emit-runtime-to-dts",
+    "Runtime code is emitted to a .d.ts file.",
+    "When the generated schema contains runtime code, e.g. with 'emitSchemaRuntime', the \
+     schema output file must be a .ts file.";
+    NO_PROJECT_DEFINED, Config, "no-project-defined",
+    "A project is selected but the config file has no projects.",
+    "The '--project' option can only be used when the config file defines 'projects'.";
+    PROJECT_NOT_FOUND, Config, "project-not-found",
+    "The selected project is not defined in the config file.",
+    "The name given to '--project' must be one of the keys of 'projects' in the config file.";
+    CANNOT_LOAD_PLUGIN, Config, "cannot-load-plugin",
+    "A plugin listed in the config file cannot be loaded.",
+    "Check the name of the plugin in the 'plugins' option. Only built-in plugins are \
+     supported.";
+    INVALID_GLOB, Config, "invalid-glob",
+    "A glob pattern in the config file is invalid.",
+    "Patterns given to 'schema' and 'documents' must be valid glob patterns.";
+    PATTERN_MATCHES_NO_FILES, Config, "pattern-matches-no-files",
+    "A glob pattern in the config file matches no files.",
+    "This is reported as a warning and does not make commands fail. Check that the pattern \
+     is relative to the config file and that matched files are not ignored by .gitignore.";
+    MISSING_SCALAR_VALIDATOR, Config, "missing-scalar-validator",
+    "A custom scalar used in inputs has no validator.",
+    "When 'validatorsOutput' is set, every custom scalar used in input types or operation \
+     variables must have a validator in the 'scalarValidators' option. Otherwise any value \
+     would pass validation.";
+    NO_COMMAND_SPECIFIED, Cli, "no-command-specified",
+    "No command is given.",
+    "Give one or more commands such as `nitrogql check generate`.";
+    UNKNOWN_COMMAND, Cli, "unknown-command",
+    "An unknown command is given.",
+    "Available commands are 'check', 'generate' and 'explain'.";
+    INVALID_COMMAND, Cli, "invalid-command",
+    "Commands are given in an invalid order.",
+    "'check' must be run before any other command.";
+    UNKNOWN_ERROR_CODE, Cli, "unknown-error-code",
+    "An unknown error code is given to the explain command.",
+    "Error codes are in the form of NGQL0000. The code is printed with each reported \
+     error.";
+    SOURCE_MAP_FILE_NAME, Cli, "source-map-file-name",
+    "The name of a source map file cannot be calculated.",
+    "Source map files are named after generated files. Make sure that output paths point \
+     to files, not directories.";
+    COMMAND_NOT_SUCCESSFUL, Cli, "command-not-successful",
+    "A command failed because of errors found in the project.",
+    "The command found errors in the schema or operations. They are reported separately.";
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn codes_are_unique() {
+        let mut seen_codes = HashSet::new();
+        let mut seen_names = HashSet::new();
+        for info in ERROR_CODES {
+            assert!(seen_codes.insert(info.code), "duplicated {}", info.code);
+            assert!(seen_names.insert(info.name), "duplicated {}", info.name);
+        }
+    }
+
+    #[test]
+    fn parse_code() {
+        assert_eq!("NGQL0012".parse(), Ok(ErrorCode(12)));
+        assert_eq!("ngql0218".parse(), Ok(ErrorCode(218)));
+        assert_eq!("218".parse(), Ok(ErrorCode(218)));
+        assert_eq!("NGQL".parse::<ErrorCode>(), Err(FromStrError));
+        assert_eq!("NGQL12a".parse::<ErrorCode>(), Err(FromStrError));
+        assert_eq!(ErrorCode(12).to_string(), "NGQL0012");
+    }
+}
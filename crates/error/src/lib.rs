@@ -1,18 +1,31 @@
-use colored::Colorize;
-use std::fmt::{Display, Write};
-use std::ops::Index;
-use std::path::{Path, PathBuf};
-use std::result::Result as StdResult;
+use std::{num::NonZeroU16, result::Result as StdResult};
 
 use nitrogql_ast::base::Pos;
-use nitrogql_utils::{first_non_space_byte_index, skip_chars};
+
+pub use code::{ERROR_CODES, ErrorCategory, ErrorCode, ErrorCodeInfo, codes};
+pub use print::{print_positioned_error, suggestion_message};
+
+mod code;
+mod print;
 
 /// Error that may be positioned.
 #[derive(Debug)]
 pub struct PositionedError {
     inner: anyhow::Error,
     position: Option<Pos>,
+    /// Column where the range this error points to ends (exclusive), if known.
+    /// Ranges are limited to one line, so only the column is kept.
+    /// Kept small so that results with this error stay small.
+    end_column: Option<NonZeroU16>,
     additional_info: Vec<(Pos, String)>,
+    /// Names suggested as a fix for this error.
+    suggestions: Vec<String>,
+    /// Stable code of this error.
+    code: Option<ErrorCode>,
+    /// Category of this error given by the place where it was found.
+    category: Option<ErrorCategory>,
+    /// Structured data that describes this error.
+    fields: Vec<(&'static str, String)>,
 }
 
 pub type Result<T> = StdResult<T, PositionedError>;
@@ -26,8 +39,49 @@ impl PositionedError {
         PositionedError {
             inner,
             position,
+            end_column: None,
             additional_info,
+            suggestions: vec![],
+            code: None,
+            category: None,
+            fields: vec![],
+        }
+    }
+
+    /// Sets the end of the range this error points to.
+    /// The end is ignored unless it is on the same line as the position.
+    pub fn with_end(mut self, end: Pos) -> Self {
+        if self
+            .position
+            .is_some_and(|position| position.line == end.line)
+        {
+            self.end_column = u16::try_from(end.column).ok().and_then(NonZeroU16::new);
         }
+        self
+    }
+
+    /// Attaches an error code to this error.
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sets the category of this error, overriding the category of its code.
+    pub fn with_category(mut self, category: ErrorCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Attaches structured fields to this error.
+    pub fn with_fields(mut self, fields: Vec<(&'static str, String)>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Attaches suggestions to this error.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
     }
 
     pub fn has_position(&self) -> bool {
@@ -38,6 +92,49 @@ impl PositionedError {
         self.position
     }
 
+    /// Returns the end of the range this error points to, if known.
+    pub fn end(&self) -> Option<Pos> {
+        let column = self.end_column?;
+        self.position.map(|position| Pos {
+            column: column.get().into(),
+            ..position
+        })
+    }
+
+    pub fn additional_info(&self) -> &[(Pos, String)] {
+        &self.additional_info
+    }
+
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    pub fn code(&self) -> Option<ErrorCode> {
+        self.code
+    }
+
+    /// Returns the category of this error.
+    /// Falls back to the category of its code if no category is set explicitly.
+    pub fn category(&self) -> Option<ErrorCategory> {
+        self.category.or_else(|| {
+            self.code
+                .and_then(|code| code.info())
+                .map(|info| info.category)
+        })
+    }
+
+    pub fn fields(&self) -> &[(&'static str, String)] {
+        &self.fields
+    }
+
+    /// Returns the underlying error if it is of given type.
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+    {
+        self.inner.downcast_ref()
+    }
+
     pub fn into_inner(self) -> anyhow::Error {
         self.inner
     }
@@ -51,112 +148,12 @@ where
         PositionedError {
             inner: value.into(),
             position: None,
+            end_column: None,
             additional_info: vec![],
+            suggestions: vec![],
+            code: None,
+            category: None,
+            fields: vec![],
         }
     }
 }
-
-static INDENT: &str = "    ";
-
-pub fn print_positioned_error<S: AsRef<str>, T>(
-    error: &PositionedError,
-    files: &impl Index<usize, Output = (PathBuf, S, T)>,
-) -> String {
-    let inner = &error.inner;
-    let Some(position) = error.position else {
-        return format!("{inner}");
-    };
-    if position.builtin {
-        return format!("{inner}");
-    }
-
-    let (ref file_path, ref source, _) = files[position.file];
-
-    let mut message = message_for_line(file_path, source.as_ref(), position, inner, false);
-
-    for (pos, mes) in error.additional_info.iter() {
-        if pos.builtin {
-            continue;
-        }
-        let (ref file_path, ref source, _) = files[pos.file];
-
-        write!(
-            message,
-            "\n\n{}",
-            message_for_line(file_path, source.as_ref(), *pos, mes, true)
-        )
-        .unwrap();
-    }
-
-    message
-}
-
-fn message_for_line(
-    file_path: &Path,
-    source: &str,
-    pos: Pos,
-    error: &impl Display,
-    is_additional: bool,
-) -> String {
-    // print -2 ~ +2 lines
-    let relevant_lines = source
-        .lines()
-        .enumerate()
-        .skip(pos.line.saturating_sub(2))
-        .take(5)
-        .collect::<Vec<_>>();
-    if relevant_lines
-        .iter()
-        .all(|(line_no, _)| *line_no != pos.line)
-    {
-        // No targeted line (?)
-        return format!("{error}");
-    }
-
-    let minimum_indent = relevant_lines
-        .iter()
-        .filter_map(|(_, line)| first_non_space_byte_index(line).map(|(char_idx, _)| char_idx))
-        .min();
-    let Some(minimum_indent) = minimum_indent else {
-        return format!("{error}");
-    };
-
-    // VSCode uses 1-based indices, so we follow here
-    let src_string = format!(
-        "{}:{}:{}\n",
-        file_path.display(),
-        pos.line + 1,
-        pos.column + 1
-    )
-    .bold();
-
-    let trimmed_column = pos.column.saturating_sub(minimum_indent);
-
-    let mut result = if is_additional {
-        format!("{INDENT}{src_string}")
-    } else {
-        src_string.to_string()
-    };
-
-    for (line_no, source_line) in relevant_lines {
-        let trimmed_line = skip_chars(source_line, minimum_indent);
-        let spaces = " ".repeat(trimmed_column);
-        if line_no != pos.line {
-            let printed_line = trimmed_line.bright_black();
-            if is_additional {
-                result.push_str(&format!("{INDENT}{printed_line}\n"));
-            } else {
-                result.push_str(&format!("{printed_line}\n"));
-            }
-        } else if is_additional {
-            let error_str = format!("{error}").bright_green().underline();
-            result.push_str(&format!(
-                "{INDENT}{trimmed_line}\n{INDENT}{spaces}^\n{INDENT}{spaces}{error_str}\n"
-            ));
-        } else {
-            let error_str = format!("{error}").bright_yellow().underline();
-            result.push_str(&format!("{trimmed_line}\n{spaces}^\n{spaces}{error_str}\n"));
-        }
-    }
-    result
-}
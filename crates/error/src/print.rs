@@ -0,0 +1,359 @@
+use std::fmt::Write;
+use std::ops::Index;
+use std::path::{Path, PathBuf};
+
+use colored::{ColoredString, Colorize};
+use nitrogql_ast::base::Pos;
+
+use crate::PositionedError;
+
+/// Number of lines printed before and after the labelled line.
+const CONTEXT_LINES: usize = 2;
+
+/// Kind of a label in a code frame.
+#[derive(Copy, Clone)]
+enum LabelKind {
+    /// The position of the error itself.
+    Primary,
+    /// A location related to the error.
+    Related,
+}
+
+impl LabelKind {
+    fn marker(self, width: usize) -> ColoredString {
+        match self {
+            LabelKind::Primary => "^".repeat(width).bright_yellow().bold(),
+            LabelKind::Related => "-".repeat(width).bright_green().bold(),
+        }
+    }
+
+    fn label(self, label: &str) -> ColoredString {
+        match self {
+            LabelKind::Primary => label.bright_yellow(),
+            LabelKind::Related => label.bright_green(),
+        }
+    }
+}
+
+/// One code frame to print.
+struct Frame<'a> {
+    pos: Pos,
+    /// End of the underlined range, if known.
+    end: Option<Pos>,
+    label: Option<&'a str>,
+    kind: LabelKind,
+}
+
+/// Renders given error in a human-readable form.
+/// Each location related to the error is printed as a code frame with its own file path.
+/// Colors are controlled by the `colored` crate, so output is plain when colors are disabled.
+pub fn print_positioned_error<S: AsRef<str>, T>(
+    error: &PositionedError,
+    files: &impl Index<usize, Output = (PathBuf, S, T)>,
+) -> String {
+    let inner = match error.code {
+        Some(code) => format!("[{code}] {}", error.inner),
+        None => format!("{}", error.inner),
+    };
+    let mut frames = vec![];
+    if let Some(position) = error.position.filter(|position| !position.builtin) {
+        frames.push(Frame {
+            pos: position,
+            end: error.end(),
+            label: None,
+            kind: LabelKind::Primary,
+        });
+    }
+    let mut notes = vec![];
+    for (pos, mes) in error.additional_info.iter() {
+        if pos.builtin {
+            notes.push(mes.clone());
+        } else {
+            frames.push(Frame {
+                pos: *pos,
+                end: None,
+                label: Some(mes.as_str()),
+                kind: LabelKind::Related,
+            });
+        }
+    }
+    if let Some(suggestion) = suggestion_message(&error.suggestions) {
+        notes.push(suggestion);
+    }
+
+    let gutter_width = frames
+        .iter()
+        .map(|Frame { pos, .. }| {
+            let line_count = files[pos.file].1.as_ref().lines().count();
+            let last_line = (pos.line + CONTEXT_LINES + 1).min(line_count.max(1));
+            last_line.to_string().len()
+        })
+        .max()
+        .unwrap_or(0);
+
+    let mut message = inner.bold().to_string();
+    for frame in frames {
+        let (ref file_path, ref source, _) = files[frame.pos.file];
+        message.push('\n');
+        write_frame(
+            &mut message,
+            file_path,
+            source.as_ref(),
+            frame,
+            gutter_width,
+        );
+    }
+    for note in notes {
+        write!(
+            message,
+            "\n{} {} {}",
+            " ".repeat(gutter_width),
+            "=".bright_blue().bold(),
+            note.bright_cyan()
+        )
+        .unwrap();
+    }
+    message
+}
+
+/// Generates a "did you mean" message from given suggestions.
+pub fn suggestion_message(suggestions: &[String]) -> Option<String> {
+    let (last, rest) = suggestions.split_last()?;
+    let last = format!("'{last}'");
+    if rest.is_empty() {
+        return Some(format!("Did you mean {last}?"));
+    }
+    let rest = rest
+        .iter()
+        .map(|s| format!("'{s}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!("Did you mean {rest} or {last}?"))
+}
+
+/// Writes a code frame around the position of given frame.
+/// The range up to the end is underlined if it is on the same line.
+fn write_frame(
+    result: &mut String,
+    file_path: &Path,
+    source: &str,
+    frame: Frame,
+    gutter_width: usize,
+) {
+    let Frame {
+        pos,
+        end,
+        label,
+        kind,
+    } = frame;
+    let padding = " ".repeat(gutter_width);
+    let bar = "|".bright_blue().bold();
+    // VSCode uses 1-based indices, so we follow here
+    write!(
+        result,
+        "{padding}{} {}:{}:{}",
+        "-->".bright_blue().bold(),
+        file_path.display(),
+        pos.line + 1,
+        pos.column + 1
+    )
+    .unwrap();
+
+    let relevant_lines = source
+        .lines()
+        .enumerate()
+        .skip(pos.line.saturating_sub(CONTEXT_LINES))
+        .take(CONTEXT_LINES * 2 + 1)
+        .collect::<Vec<_>>();
+    if relevant_lines
+        .iter()
+        .all(|(line_no, _)| *line_no != pos.line)
+    {
+        // No targeted line (?)
+        if let Some(label) = label {
+            write!(result, "\n{padding} {bar} {}", kind.label(label)).unwrap();
+        }
+        return;
+    }
+
+    write!(result, "\n{padding} {bar}").unwrap();
+    for (line_no, source_line) in relevant_lines {
+        let line_no_str = format!("{:>gutter_width$}", line_no + 1)
+            .bright_blue()
+            .bold();
+        if source_line.is_empty() {
+            write!(result, "\n{line_no_str} {bar}").unwrap();
+        } else {
+            write!(result, "\n{line_no_str} {bar} {source_line}").unwrap();
+        }
+        if line_no != pos.line {
+            continue;
+        }
+        let spaces: String = source_line
+            .chars()
+            .take(pos.column)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = match end {
+            Some(end) if end.line == pos.line && end.column > pos.column => end.column - pos.column,
+            _ => token_width(source_line, pos.column),
+        };
+        let marker = kind.marker(width);
+        match label {
+            Some(label) => write!(
+                result,
+                "\n{padding} {bar} {spaces}{marker} {}",
+                kind.label(label)
+            )
+            .unwrap(),
+            None => write!(result, "\n{padding} {bar} {spaces}{marker}").unwrap(),
+        }
+    }
+    write!(result, "\n{padding} {bar}").unwrap();
+}
+
+/// Calculates the width (in chars) of the token which starts at given column.
+/// Positions in the AST point to the start of tokens, so this is used to underline the whole token
+/// when the end of the range is not known.
+fn token_width(line: &str, column: usize) -> usize {
+    let rest: Vec<char> = line.chars().skip(column).collect();
+    let is_name_char = |ch: &char| ch.is_ascii_alphanumeric() || *ch == '_';
+    let name_width = |chars: &[char]| chars.iter().take_while(|ch| is_name_char(ch)).count();
+    let width = match rest.first() {
+        None => 0,
+        Some('$' | '@') => 1 + name_width(&rest[1..]),
+        Some('.') if rest.starts_with(&['.', '.', '.']) => 3,
+        Some('"') if rest.starts_with(&['"', '"', '"']) => rest[3..]
+            .windows(3)
+            .position(|w| w == ['"', '"', '"'])
+            .map_or(rest.len(), |end| end + 6),
+        Some('"') => {
+            let mut escaped = false;
+            rest[1..]
+                .iter()
+                .position(|&ch| {
+                    let end = !escaped && ch == '"';
+                    escaped = !escaped && ch == '\\';
+                    end
+                })
+                .map_or(rest.len(), |end| end + 2)
+        }
+        Some('-') => 1 + name_width(&rest[1..]),
+        Some(ch) if is_name_char(ch) => name_width(&rest),
+        Some(_) => 1,
+    };
+    width.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use anyhow::anyhow;
+    use nitrogql_ast::base::Pos;
+
+    use super::*;
+
+    fn pos(file: usize, line: usize, column: usize) -> Pos {
+        Pos {
+            line,
+            column,
+            file,
+            builtin: false,
+        }
+    }
+
+    fn files() -> Vec<(PathBuf, &'static str, ())> {
+        vec![
+            (
+                PathBuf::from("schema/a.graphql"),
+                "# comment\n\ntype User {\n  id: ID!\n  name: String!\n}\n",
+                (),
+            ),
+            (
+                PathBuf::from("schema/b.graphql"),
+                "type User {\n  id: ID!\n}\n",
+                (),
+            ),
+        ]
+    }
+
+    #[test]
+    fn token_widths() {
+        assert_eq!(token_width("type User {", 5), 4);
+        assert_eq!(token_width("query($foo: Int)", 6), 4);
+        assert_eq!(token_width("  id @skip(if: true)", 5), 5);
+        assert_eq!(token_width("  ...Frag", 2), 3);
+        assert_eq!(token_width(r#"  f(a: "a\"b") "#, 7), 6);
+        assert_eq!(token_width("  a: -123", 5), 4);
+        assert_eq!(token_width("type User {", 0), 4);
+        assert_eq!(token_width("  type: String", 2), 4);
+        assert_eq!(token_width("query {", 0), 5);
+        assert_eq!(token_width("{", 0), 1);
+        assert_eq!(token_width("", 0), 1);
+    }
+
+    #[test]
+    fn print_related_locations() {
+        colored::control::set_override(false);
+        let error = PositionedError::new(
+            anyhow!("Duplicated name 'User'"),
+            Some(pos(0, 2, 5)),
+            vec![
+                (pos(1, 0, 5), "Another definition of 'User'".to_owned()),
+                (Pos::builtin(), "Hint: rename one of them.".to_owned()),
+            ],
+        )
+        .with_code(crate::codes::DUPLICATED_NAME);
+        assert_eq!(
+            print_positioned_error(&error, &files()),
+            "[NGQL0102] Duplicated name 'User'
+ --> schema/a.graphql:3:6
+  |
+1 | # comment
+2 |
+3 | type User {
+  |      ^^^^
+4 |   id: ID!
+5 |   name: String!
+  |
+ --> schema/b.graphql:1:6
+  |
+1 | type User {
+  |      ---- Another definition of 'User'
+2 |   id: ID!
+3 | }
+  |
+  = Hint: rename one of them."
+        );
+    }
+
+    #[test]
+    fn print_with_end() {
+        colored::control::set_override(false);
+        let error = PositionedError::new(anyhow!("Invalid field"), Some(pos(1, 1, 2)), vec![])
+            .with_end(pos(1, 1, 9));
+        assert_eq!(
+            print_positioned_error(&error, &files()),
+            "Invalid field
+ --> schema/b.graphql:2:3
+  |
+1 | type User {
+2 |   id: ID!
+  |   ^^^^^^^
+3 | }
+  |"
+        );
+    }
+
+    #[test]
+    fn print_suggestion_without_position() {
+        colored::control::set_override(false);
+        let error = PositionedError::new(anyhow!("Unknown type 'Usr'"), None, vec![])
+            .with_suggestions(vec!["User".to_owned()]);
+        assert_eq!(
+            print_positioned_error(&error, &files()),
+            "Unknown type 'Usr'\n = Did you mean 'User'?"
+        );
+    }
+}
@@ -1,12 +1,30 @@
+use std::path::Path;
+
 use nitrogql_ast::OperationDocument;
 use nitrogql_config_file::Config;
 use nitrogql_printer::{OperationJSPrinterOptions, print_js_for_operation_document};
+use nitrogql_semantics::{OperationExtension, add_typename};
 use sourcemap_writer::SourceWriter;
 
-pub fn print_js(document: &OperationDocument, config: &Config) -> String {
+/// Prints JS for an operation document in `file`.
+/// Overrides in config that match `file` are applied.
+pub fn print_js(
+    document: &OperationDocument,
+    extension: &OperationExtension,
+    config: &Config,
+    root_dir: &Path,
+    file: &Path,
+) -> String {
     let mut writer = SourceWriter::new();
-    let options = OperationJSPrinterOptions::from_config(config);
-    print_js_for_operation_document(options, document, &mut writer);
+    let generate = config.generate_for_file(root_dir, file);
+    let options = OperationJSPrinterOptions::from_config(&generate);
+    if generate.add_typename {
+        // The loader does not load the schema, so default root type names are assumed
+        let document = add_typename(document.clone(), &["Query", "Mutation", "Subscription"]);
+        print_js_for_operation_document(options, &document, extension, &mut writer);
+    } else {
+        print_js_for_operation_document(options, document, extension, &mut writer);
+    }
     let buffers = writer.into_buffers();
     buffers.buffer
 }
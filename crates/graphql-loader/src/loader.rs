@@ -1,10 +1,13 @@
 use std::path::{Path, PathBuf};
 
 use nitrogql_ast::OperationDocument;
-use nitrogql_config_file::Config;
+use nitrogql_config_file::{Config, DEFAULT_PROJECT_NAME, Project};
 use nitrogql_error::{PositionedError, Result};
-use nitrogql_semantics::{OperationExtension, OperationResolver, resolve_operation_imports};
-use nitrogql_utils::resolve_relative_path;
+use nitrogql_printer::OperationJSPrinterOptions;
+use nitrogql_semantics::{
+    ImportPathError, ImportPathResolver, OperationExtension, OperationResolver, ReexportOptions,
+    check_reexported_operations, resolve_operation_imports,
+};
 use thiserror::Error;
 
 use crate::{
@@ -16,19 +19,54 @@ use crate::{
 pub enum LoaderError {
     #[error("Task not found")]
     TaskNotFound,
+    #[error("No project in the config file includes '{}'", .0.display())]
+    NoProjectForFile(PathBuf),
+}
+
+/// Selects the project that given file belongs to and returns its index.
+/// The first project whose `documents` include the file is selected.
+/// Files not included in any project belong to the default project if there is one.
+pub fn select_project(projects: &[Project], root_dir: &Path, file_name: &Path) -> Result<usize> {
+    projects
+        .iter()
+        .position(|project| project.config.includes_operation(root_dir, file_name))
+        .or_else(|| {
+            projects.iter().position(|project| {
+                project
+                    .name
+                    .as_deref()
+                    .is_none_or(|name| name == DEFAULT_PROJECT_NAME)
+            })
+        })
+        .ok_or_else(|| {
+            PositionedError::new(
+                LoaderError::NoProjectForFile(file_name.to_owned()).into(),
+                None,
+                vec![],
+            )
+        })
 }
 
-/// Initiates a task.
+/// Initiates a task for a file in the project of given index.
 /// Returns the task id.
-pub fn initiate_task(tasks: &mut Tasks, file_name: PathBuf, input_source: String) -> Result<usize> {
-    let mut task = Task::new(file_name.clone());
+pub fn initiate_task(
+    tasks: &mut Tasks,
+    project: usize,
+    file_name: PathBuf,
+    input_source: String,
+) -> Result<usize> {
+    let mut task = Task::new(file_name.clone(), project);
     task.register_file(file_name, input_source)?;
     let task_id = tasks.add_task(task);
     Ok(task_id)
 }
 
 /// Get the list of additionally required files for the given task.
-pub fn get_required_files(tasks: &mut Tasks, task_id: usize) -> Result<Vec<PathBuf>> {
+pub fn get_required_files(
+    tasks: &mut Tasks,
+    task_id: usize,
+    import_path_resolver: &ImportPathResolver,
+) -> Result<Vec<PathBuf>> {
     let task = tasks
         .get_task_mut(task_id)
         .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
@@ -36,8 +74,13 @@ pub fn get_required_files(tasks: &mut Tasks, task_id: usize) -> Result<Vec<PathB
     let mut required_files = vec![];
     for (from_file, (_, extensions)) in task.iter_loaded_files() {
         for import in extensions.imports.iter() {
-            let path = Path::new(import.path.value.as_str());
-            let path = resolve_relative_path(from_file, path);
+            let path = match import_path_resolver.resolve(from_file, &import.path.value, task) {
+                Ok(path) => path,
+                // Files needed to resolve the path (e.g. package.json) are required first
+                Err(ImportPathError::FileRequired(path)) => path,
+                // Other errors are reported when emitting
+                Err(_) => continue,
+            };
             if task.contains_file(&path) || required_files.contains(&path) {
                 continue;
             }
@@ -61,28 +104,81 @@ pub fn load_file(
     Ok(())
 }
 
+/// Record that an additional file does not exist.
+pub fn load_missing_file(tasks: &mut Tasks, task_id: usize, file_name: PathBuf) -> Result<()> {
+    let task = tasks
+        .get_task_mut(task_id)
+        .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
+    task.register_missing_file(file_name);
+    Ok(())
+}
+
 /// Emit JavaScript for the given task.
-pub fn emit_js(tasks: &Tasks, task_id: usize, config: &Config) -> Result<String> {
+pub fn emit_js(
+    tasks: &Tasks,
+    task_id: usize,
+    config: &Config,
+    root_dir: &Path,
+    import_path_resolver: &ImportPathResolver,
+) -> Result<String> {
     let task = tasks
         .get_task(task_id)
         .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
     let (document, extensions) = task.get_root_document();
+    let operation_resolver = TaskOperationResolver {
+        task,
+        import_path_resolver,
+    };
     let document = resolve_operation_imports(
         (&task.root_file_name, document, extensions),
-        &TaskOperationResolver(task),
+        &operation_resolver,
     )?;
-    let js = print_js(&document, config);
+    let options = OperationJSPrinterOptions::from_config(
+        &config.generate_for_file(root_dir, &task.root_file_name),
+    )
+    .base_options;
+    if let Some(error) = check_reexported_operations(
+        (&task.root_file_name, extensions),
+        &operation_resolver,
+        ReexportOptions {
+            default_export_for_operation: options.default_export_for_operation,
+            named_export_for_operation: options.named_export_for_operation,
+        },
+    )
+    .into_iter()
+    .next()
+    {
+        return Err(error);
+    }
+    let js = print_js(
+        &document,
+        extensions,
+        config,
+        root_dir,
+        &task.root_file_name,
+    );
     Ok(js)
 }
 
-struct TaskOperationResolver<'a>(&'a Task);
+struct TaskOperationResolver<'a> {
+    task: &'a Task,
+    import_path_resolver: &'a ImportPathResolver,
+}
 
 impl<'a> OperationResolver<'a> for TaskOperationResolver<'a> {
     fn resolve(&self, path: &Path) -> Option<(&OperationDocument<'a>, &OperationExtension<'a>)> {
-        let task = self.0;
-        let (document, extension) = task.get_file(path)?;
+        let (document, extension) = self.task.get_file(path)?;
         Some((document, extension))
     }
+
+    fn resolve_import_path(
+        &self,
+        from_file: &Path,
+        import_path: &str,
+    ) -> std::result::Result<PathBuf, ImportPathError> {
+        self.import_path_resolver
+            .resolve(from_file, import_path, self.task)
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +192,7 @@ mod tests {
         let mut tasks = Tasks::new();
         let task_id = initiate_task(
             &mut tasks,
+            0,
             PathBuf::from("/path/to/op.graphql"),
             r#"
             query Test {
@@ -106,9 +203,161 @@ mod tests {
         )
         .unwrap();
         // no imports
-        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &Default::default()).unwrap();
         assert_eq!(required_files.len(), 0);
-        let js = emit_js(&tasks, task_id, &Default::default()).unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &Default::default(),
+            Path::new("/"),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_snapshot!(js);
+    }
+
+    #[test]
+    fn select_project_by_documents() {
+        let projects = nitrogql_config_file::parse_projects(
+            r#"
+projects:
+    public:
+        schema: public/schema.graphql
+        documents: public/**/*.graphql
+    admin:
+        schema: admin/schema.graphql
+        documents: admin/**/*.graphql
+"#,
+        )
+        .unwrap();
+        let root_dir = Path::new("/path");
+        let select = |file: &str| select_project(&projects, root_dir, Path::new(file));
+        assert_eq!(select("/path/public/op.graphql").unwrap(), 0);
+        assert_eq!(select("/path/admin/op.graphql").unwrap(), 1);
+        // no default project to fall back to
+        let error = select("/path/other/op.graphql").unwrap_err();
+        assert_eq!(
+            error.into_inner().to_string(),
+            "No project in the config file includes '/path/other/op.graphql'"
+        );
+    }
+
+    #[test]
+    fn test_override() {
+        let config = nitrogql_config_file::parse_config(
+            r#"
+extensions:
+    nitrogql:
+        overrides:
+            - files: to/**
+              generate:
+                  name:
+                      queryVariableSuffix: Document
+"#,
+        )
+        .unwrap();
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            0,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            query Test {
+                test
+            }
+            query Test2 {
+                test
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &config,
+            Path::new("/path"),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_snapshot!(js);
+    }
+
+    #[test]
+    fn test_minified_string_format() {
+        let config = nitrogql_config_file::parse_config(
+            r#"
+extensions:
+    nitrogql:
+        generate:
+            documentFormat: minified-string
+"#,
+        )
+        .unwrap();
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            0,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            query Test($id: ID!) {
+                test(id: $id) {
+                    id
+                }
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &config,
+            Path::new("/path"),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_snapshot!(js);
+    }
+
+    #[test]
+    fn test_add_typename() {
+        let config = nitrogql_config_file::parse_config(
+            r#"
+extensions:
+    nitrogql:
+        generate:
+            addTypename: true
+"#,
+        )
+        .unwrap();
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            0,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            query Test {
+                me {
+                    id
+                    ...F
+                }
+            }
+            fragment F on User {
+                name
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &config,
+            Path::new("/path"),
+            &Default::default(),
+        )
+        .unwrap();
         assert_snapshot!(js);
     }
 
@@ -117,6 +366,7 @@ mod tests {
         let mut tasks = Tasks::new();
         let task_id = initiate_task(
             &mut tasks,
+            0,
             PathBuf::from("/path/to/op.graphql"),
             r#"
             #import Frag1 from "./frag1.graphql"
@@ -128,7 +378,7 @@ mod tests {
             .to_string(),
         )
         .unwrap();
-        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &Default::default()).unwrap();
         assert_eq!(
             required_files,
             vec![PathBuf::from("/path/to/frag1.graphql"),]
@@ -145,9 +395,16 @@ mod tests {
             .to_string(),
         )
         .unwrap();
-        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &Default::default()).unwrap();
         assert_eq!(required_files.len(), 0);
-        let js = emit_js(&tasks, task_id, &Default::default()).unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &Default::default(),
+            Path::new("/"),
+            &Default::default(),
+        )
+        .unwrap();
         assert_snapshot!(js);
     }
 
@@ -156,6 +413,7 @@ mod tests {
         let mut tasks = Tasks::new();
         let task_id = initiate_task(
             &mut tasks,
+            0,
             PathBuf::from("/path/to/op.graphql"),
             r#"
             #import Frag1 from "./frag1.graphql"
@@ -167,7 +425,7 @@ mod tests {
             .to_string(),
         )
         .unwrap();
-        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &Default::default()).unwrap();
         assert_eq!(
             required_files,
             vec![PathBuf::from("/path/to/frag1.graphql"),]
@@ -186,7 +444,7 @@ mod tests {
             .to_string(),
         )
         .unwrap();
-        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &Default::default()).unwrap();
         assert_eq!(
             required_files,
             vec![PathBuf::from("/path/to/frag2.graphql"),]
@@ -203,9 +461,144 @@ mod tests {
             .to_string(),
         )
         .unwrap();
-        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &Default::default()).unwrap();
         assert_eq!(required_files.len(), 0);
-        let js = emit_js(&tasks, task_id, &Default::default()).unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &Default::default(),
+            Path::new("/"),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_snapshot!(js);
+    }
+
+    #[test]
+    fn reload_file() {
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            0,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            #import Frag1 from "./frag1.graphql"
+            query Test {
+                ...Frag1
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/frag1.graphql"),
+            r#"
+            fragment Frag1 on Query {
+                test
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        // loading the same file again replaces the previous source
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/frag1.graphql"),
+            r#"
+            fragment Frag1 on Query {
+                reloaded
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &Default::default(),
+            Path::new("/"),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_snapshot!(js);
+    }
+
+    #[test]
+    fn package_import() {
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            0,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            #import Frag1 from "@acme/fragments/frag1.graphql"
+            query Test {
+                ...Frag1
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let resolver = ImportPathResolver::default();
+        let required_files = get_required_files(&mut tasks, task_id, &resolver).unwrap();
+        assert_eq!(
+            required_files,
+            vec![PathBuf::from(
+                "/path/to/node_modules/@acme/fragments/package.json"
+            )]
+        );
+        load_missing_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/node_modules/@acme/fragments/package.json"),
+        )
+        .unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &resolver).unwrap();
+        assert_eq!(
+            required_files,
+            vec![PathBuf::from(
+                "/path/node_modules/@acme/fragments/package.json"
+            )]
+        );
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/node_modules/@acme/fragments/package.json"),
+            r#"{ "exports": { "./*": "./src/*" } }"#.to_string(),
+        )
+        .unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &resolver).unwrap();
+        assert_eq!(
+            required_files,
+            vec![PathBuf::from(
+                "/path/node_modules/@acme/fragments/src/frag1.graphql"
+            )]
+        );
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/node_modules/@acme/fragments/src/frag1.graphql"),
+            r#"
+            fragment Frag1 on Query {
+                test
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let required_files = get_required_files(&mut tasks, task_id, &resolver).unwrap();
+        assert_eq!(required_files.len(), 0);
+        let js = emit_js(
+            &tasks,
+            task_id,
+            &Default::default(),
+            Path::new("/"),
+            &resolver,
+        )
+        .unwrap();
         assert_snapshot!(js);
     }
 }
@@ -4,16 +4,28 @@ mod loader;
 mod logger;
 mod tasks;
 
-use std::{cell::RefCell, slice};
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    slice,
+};
 
 use log::debug;
-use nitrogql_config_file::Config;
+use nitrogql_config_file::{Config, ConfigChainLoader, ConfigFileError, Project};
+use nitrogql_semantics::ImportPathResolver;
 
 use crate::logger::StringLogger;
 
 thread_local! {
-    /// Loaded config.
-    static CONFIG: RefCell<Config> = RefCell::new(Config::default());
+    /// Projects in the loaded config.
+    static PROJECTS: RefCell<Vec<Project>> = RefCell::new(vec![Project {
+        name: None,
+        config: Config::default(),
+    }]);
+    /// Config being loaded.
+    static CONFIG_LOADER: RefCell<Option<ConfigChainLoader>> = const { RefCell::new(None) };
+    /// Directory of the loaded config file.
+    static ROOT_DIR: RefCell<PathBuf> = const { RefCell::new(PathBuf::new()) };
     /// Result of last operation.
     static RESULT: RefCell<Option<String>> = const { RefCell::new(None) };
     /// Global set of tasks.
@@ -55,11 +67,80 @@ pub unsafe extern "C" fn free_string(ptr: *mut u8, len_bytes: usize) {
     let _ = unsafe { String::from_raw_parts(ptr, 0, len_bytes) };
 }
 
-/// Loads config from given source. Returns true if successful
+/// Starts loading config from given config file.
+/// Paths in the config are relative to the directory of the config file.
+/// Config files it extends are requested by `get_required_config_files`.
 #[unsafe(no_mangle)]
-pub extern "C" fn load_config(config_file_ptr: *const u8, config_file_len: usize) -> bool {
+pub extern "C" fn load_config(
+    config_path_ptr: *const u8,
+    config_path_len: usize,
+    config_file_ptr: *const u8,
+    config_file_len: usize,
+) {
+    let config_path = PathBuf::from(read_str_ptr(config_path_ptr, config_path_len));
     let config_file = read_str_ptr(config_file_ptr, config_file_len);
-    load_config_impl(&config_file)
+    debug!("load_config {}", config_path.display());
+    let root_dir = config_path.parent().map(Path::to_owned).unwrap_or_default();
+    ROOT_DIR.with(|cell| cell.replace(root_dir));
+    CONFIG_LOADER.with(|cell| cell.replace(Some(ConfigChainLoader::new(config_path, config_file))));
+}
+
+/// Get the list of files required to continue loading config.
+/// If the list is empty, loading config is finished.
+/// Returns true if successful.
+/// Otherwise, the error message is stored in `RESULT`.
+#[unsafe(no_mangle)]
+pub extern "C" fn get_required_config_files() -> bool {
+    CONFIG_LOADER.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        let Some(loader) = cell.as_mut() else {
+            RESULT.with(|cell| cell.replace(Some(String::new())));
+            return true;
+        };
+        let result = match loader.next_required_file() {
+            Ok(Some(required)) => Ok(required.to_string_lossy().into_owned()),
+            Ok(None) => match cell.take().expect("loader should exist").finish() {
+                Ok(loaded) => {
+                    PROJECTS.with(|cell| cell.replace(loaded.projects));
+                    Ok(String::new())
+                }
+                Err(err) => Err(config_error_message(err)),
+            },
+            Err(err) => Err(config_error_message(err)),
+        };
+        match result {
+            Ok(required) => {
+                RESULT.with(|cell| cell.replace(Some(required)));
+                true
+            }
+            Err(message) => {
+                cell.take();
+                RESULT.with(|cell| cell.replace(Some(message)));
+                false
+            }
+        }
+    })
+}
+
+/// Supplies a file requested by `get_required_config_files`.
+/// Config files written in JavaScript should be supplied as their JSON output.
+#[unsafe(no_mangle)]
+pub extern "C" fn load_config_file(
+    file_name_ptr: *const u8,
+    file_name_len: usize,
+    input_source_ptr: *const u8,
+    input_source_len: usize,
+) {
+    let file_name = read_str_ptr(file_name_ptr, file_name_len);
+    let input_source = read_str_ptr(input_source_ptr, input_source_len);
+    supply_config_file(file_name.into(), Some(input_source));
+}
+
+/// Marks given file requested by `get_required_config_files` as nonexistent.
+#[unsafe(no_mangle)]
+pub extern "C" fn load_missing_config_file(file_name_ptr: *const u8, file_name_len: usize) {
+    let file_name = read_str_ptr(file_name_ptr, file_name_len);
+    supply_config_file(file_name.into(), None);
 }
 
 /// Initiates a task with given filename and source.
@@ -76,9 +157,17 @@ pub extern "C" fn initiate_task(
     );
     let file_name = read_str_ptr(file_name_ptr, file_name_len);
     let input_source = read_str_ptr(input_source_ptr, input_source_len);
+    let file_name = PathBuf::from(file_name);
+    let project = PROJECTS.with(|projects| {
+        ROOT_DIR.with(|root_dir| {
+            loader::select_project(&projects.borrow(), &root_dir.borrow(), &file_name)
+        })
+    });
     TASKS.with(|tasks| {
         let mut tasks = tasks.borrow_mut();
-        match loader::initiate_task(&mut tasks, file_name.into(), input_source) {
+        match project
+            .and_then(|project| loader::initiate_task(&mut tasks, project, file_name, input_source))
+        {
             Ok(task_id) => task_id,
             Err(err) => {
                 RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
@@ -96,7 +185,8 @@ pub extern "C" fn get_required_files(task_id: usize) -> bool {
     debug!("get_required_files {task_id}");
     TASKS.with(|tasks| {
         let mut tasks = tasks.borrow_mut();
-        match loader::get_required_files(&mut tasks, task_id) {
+        let resolver = import_path_resolver(task_project(&tasks, task_id));
+        match loader::get_required_files(&mut tasks, task_id, &resolver) {
             Ok(required_files) => {
                 let required_files = required_files
                     .into_iter()
@@ -141,6 +231,28 @@ pub extern "C" fn load_file(
     })
 }
 
+/// Marks given file as nonexistent.
+/// Returns true if successful.
+#[unsafe(no_mangle)]
+pub extern "C" fn load_missing_file(
+    task_id: usize,
+    file_name_ptr: *const u8,
+    file_name_len: usize,
+) -> bool {
+    debug!("load_missing_file {task_id} {file_name_ptr:?} {file_name_len}");
+    let file_name = read_str_ptr(file_name_ptr, file_name_len);
+    TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        match loader::load_missing_file(&mut tasks, task_id, file_name.into()) {
+            Ok(_) => true,
+            Err(err) => {
+                RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
+                false
+            }
+        }
+    })
+}
+
 /// Converts given GraphQL string to JS.
 /// Returns true if successful.
 #[unsafe(no_mangle)]
@@ -148,18 +260,27 @@ pub extern "C" fn emit_js(task_id: usize) -> bool {
     debug!("convert_to_js {task_id}");
     TASKS.with(|tasks| {
         let tasks = tasks.borrow();
-        CONFIG.with(
-            |config| match loader::emit_js(&tasks, task_id, &config.borrow()) {
-                Ok(js) => {
-                    RESULT.with(|cell| cell.replace(Some(js)));
-                    true
-                }
-                Err(err) => {
-                    RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
-                    false
+        let project = task_project(&tasks, task_id);
+        PROJECTS.with(|projects| {
+            ROOT_DIR.with(|root_dir| {
+                match loader::emit_js(
+                    &tasks,
+                    task_id,
+                    &projects.borrow()[project].config,
+                    &root_dir.borrow(),
+                    &import_path_resolver(project),
+                ) {
+                    Ok(js) => {
+                        RESULT.with(|cell| cell.replace(Some(js)));
+                        true
+                    }
+                    Err(err) => {
+                        RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
+                        false
+                    }
                 }
-            },
-        )
+            })
+        })
     })
 }
 
@@ -200,17 +321,45 @@ pub extern "C" fn get_log() {
     RESULT.with(|cell| cell.replace(Some(log)));
 }
 
-fn load_config_impl(config_file: &str) -> bool {
-    let config = nitrogql_config_file::parse_config(config_file);
-    match config {
-        None => false,
-        Some(config) => {
-            CONFIG.with(|cell| cell.replace(config));
-            true
+fn supply_config_file(file_name: PathBuf, source: Option<String>) {
+    debug!("load_config_file {}", file_name.display());
+    CONFIG_LOADER.with(|cell| {
+        if let Some(loader) = cell.borrow_mut().as_mut() {
+            loader.supply_file(file_name, source);
         }
+    });
+}
+
+fn config_error_message(err: ConfigFileError) -> String {
+    match &err {
+        ConfigFileError::Validation { error, .. } => match error.position {
+            Some((line, column)) => {
+                format!("{err} (line {}, column {})", line + 1, column + 1)
+            }
+            None => err.to_string(),
+        },
+        err => err.to_string(),
     }
 }
 
+/// Returns the index of the project of given task.
+/// Unknown tasks fall back to the first project; they are reported by the loader.
+fn task_project(tasks: &tasks::Tasks, task_id: usize) -> usize {
+    tasks.get_task(task_id).map_or(0, |task| task.project)
+}
+
+/// Creates a resolver of import paths from the config of given project.
+fn import_path_resolver(project: usize) -> ImportPathResolver {
+    ROOT_DIR.with(|root_dir| {
+        PROJECTS.with(|projects| {
+            ImportPathResolver::new(
+                root_dir.borrow().clone(),
+                &projects.borrow()[project].config.paths,
+            )
+        })
+    })
+}
+
 fn read_str_ptr(ptr: *const u8, len: usize) -> String {
     let slice = unsafe { slice::from_raw_parts(ptr, len) };
     String::from_utf8(slice.to_vec()).unwrap()
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::HashMap,
     path::{Path, PathBuf},
 };
@@ -6,7 +7,10 @@ use std::{
 use nitrogql_ast::OperationDocument;
 use nitrogql_error::Result;
 use nitrogql_parser::parse_operation_document;
-use nitrogql_semantics::{OperationExtension, resolve_operation_extensions};
+use nitrogql_semantics::{
+    FileLookup, OperationExtension, ResolutionHost, resolve_operation_extensions,
+};
+use self_cell::self_cell;
 
 /// Set of tasks.
 #[derive(Debug)]
@@ -48,78 +52,111 @@ impl Tasks {
     }
 }
 
+/// Parsed contents of one operation file.
+pub type ParsedOperation<'src> = (OperationDocument<'src>, OperationExtension<'src>);
+
+self_cell!(
+    /// An operation file that owns its source text
+    /// together with the document parsed from it.
+    struct LoadedFile {
+        owner: String,
+        #[covariant]
+        dependent: ParsedOperation,
+    }
+
+    impl {Debug}
+);
+
+impl LoadedFile {
+    /// Parses given source into a self-contained loaded file.
+    fn load(source: String) -> Result<Self> {
+        LoadedFile::try_new(source, |source| {
+            let document = parse_operation_document(source)?;
+            let parsed = resolve_operation_extensions(document)?;
+            Ok(parsed)
+        })
+    }
+}
+
 /// One task of printing.
 #[derive(Debug)]
 pub struct Task {
     /// Name of root file.
     pub root_file_name: PathBuf,
+    /// Index of the project that the root file belongs to.
+    pub project: usize,
     /// Set of loaded operation files.
     /// The root file should be present when initiating the task.
-    loaded_files: HashMap<PathBuf, (OperationDocument<'static>, OperationExtension<'static>)>,
-    source_drop_list: Vec<(*mut u8, usize, usize)>,
+    /// Each entry owns its source, so files can be replaced or dropped independently.
+    loaded_files: HashMap<PathBuf, LoadedFile>,
+    /// Non-GraphQL files (such as package.json) loaded for resolving import paths.
+    /// `None` means that the file does not exist.
+    support_files: HashMap<PathBuf, Option<String>>,
 }
 
 impl Task {
     /// Creates a new task.
-    pub fn new(root_file_name: PathBuf) -> Self {
+    pub fn new(root_file_name: PathBuf, project: usize) -> Self {
         Self {
             root_file_name,
+            project,
             loaded_files: HashMap::new(),
-            source_drop_list: Vec::new(),
+            support_files: HashMap::new(),
         }
     }
     /// Gets the root document.
     /// Panics if the root file is not registered yet.
     pub fn get_root_document(&self) -> (&OperationDocument<'_>, &OperationExtension<'_>) {
         let (doc, extension) = self
-            .loaded_files
-            .get(&self.root_file_name)
+            .get_file(&self.root_file_name)
             .expect("Root file should be present");
         (doc, extension)
     }
-    /// Checks if the given file is loaded.
+    /// Checks if the given file is loaded (or known to be missing).
     pub fn contains_file(&self, file_name: &Path) -> bool {
-        self.loaded_files.contains_key(file_name)
+        self.loaded_files.contains_key(file_name) || self.support_files.contains_key(file_name)
     }
     /// Gets the document and extension for the given file.
-    pub fn get_file(
-        &self,
-        file_name: &Path,
-    ) -> Option<&(OperationDocument<'_>, OperationExtension<'_>)> {
-        self.loaded_files.get(file_name)
+    pub fn get_file(&self, file_name: &Path) -> Option<&ParsedOperation<'_>> {
+        self.loaded_files
+            .get(file_name)
+            .map(|file| file.borrow_dependent())
     }
     /// Returns an iterator over loaded files.
-    pub fn iter_loaded_files(
-        &self,
-    ) -> impl Iterator<
-        Item = (
-            &PathBuf,
-            &(OperationDocument<'static>, OperationExtension<'static>),
-        ),
-    > {
-        self.loaded_files.iter()
+    pub fn iter_loaded_files(&self) -> impl Iterator<Item = (&PathBuf, &ParsedOperation<'_>)> {
+        self.loaded_files
+            .iter()
+            .map(|(path, file)| (path, file.borrow_dependent()))
     }
 
     /// Registers a source.
-    /// Source will be dropped when the task is dropped.
-    pub fn register_file(&mut self, file_name: PathBuf, mut source: String) -> Result<()> {
-        let raw_parts = (source.as_mut_ptr(), source.len(), source.capacity());
-        let source = Box::leak(source.into_boxed_str());
-        self.source_drop_list.push(raw_parts);
-        let document = parse_operation_document(source)?;
-        let (document, extensions) = resolve_operation_extensions(document)?;
-        self.loaded_files.insert(file_name, (document, extensions));
+    /// If the file is already loaded, it is replaced with the new source.
+    /// Files other than GraphQL documents are kept as-is for resolving import paths.
+    pub fn register_file(&mut self, file_name: PathBuf, source: String) -> Result<()> {
+        if file_name.extension().is_some_and(|ext| ext == "json") {
+            self.support_files.insert(file_name, Some(source));
+            return Ok(());
+        }
+        let file = LoadedFile::load(source)?;
+        self.loaded_files.insert(file_name, file);
         Ok(())
     }
+
+    /// Registers that the given file does not exist.
+    pub fn register_missing_file(&mut self, file_name: PathBuf) {
+        self.support_files.insert(file_name, None);
+    }
 }
 
-impl Drop for Task {
-    fn drop(&mut self) {
-        // need to drop loaded_files first
-        self.loaded_files.clear();
-        // then drop sources
-        for (ptr, len, capacity) in self.source_drop_list.drain(..) {
-            let _ = unsafe { String::from_raw_parts(ptr, len, capacity) };
+impl ResolutionHost for Task {
+    fn lookup(&self, path: &Path) -> FileLookup<'_> {
+        if let Some(file) = self.loaded_files.get(path) {
+            return FileLookup::Found(Cow::Borrowed(file.borrow_owner()));
+        }
+        match self.support_files.get(path) {
+            Some(Some(source)) => FileLookup::Found(Cow::Borrowed(source)),
+            Some(None) => FileLookup::NotFound,
+            None => FileLookup::Pending,
         }
     }
 }
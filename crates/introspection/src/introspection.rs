@@ -336,6 +336,8 @@ fn as_field<'src, D: Default>(
         r#type: ty,
         arguments,
         deprecation,
+        semantic_non_null_levels: vec![],
+        brand: None,
     })
 }
 
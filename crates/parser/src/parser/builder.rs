@@ -1,7 +1,8 @@
 //! This module builds ast from raw parser result.
 
 use self::{
-    operation::build_executable_definition, type_system::build_type_system_definition_or_extension,
+    operation::{build_executable_definition, build_import_statement},
+    type_system::build_type_system_definition_or_extension,
     utils::PairExt,
 };
 
@@ -47,12 +48,23 @@ pub fn build_type_system_or_extension_document(
     if let Some(pair) = pairs.into_iter().next() {
         match pair.as_rule() {
             Rule::TypeSystemExtensionDocument => {
-                let definitions: Vec<_> = pair
-                    .into_inner()
-                    .filter(|pair| pair.is_rule(Rule::TypeSystemDefinitionOrExtension))
-                    .map(build_type_system_definition_or_extension)
-                    .collect();
-                return TypeSystemOrExtensionDocument { definitions };
+                let mut definitions = vec![];
+                let mut imports = vec![];
+                for pair in pair.into_inner() {
+                    match pair.as_rule() {
+                        Rule::TypeSystemDefinitionOrExtension => {
+                            definitions.push(build_type_system_definition_or_extension(pair));
+                        }
+                        Rule::ext_ImportStatement => {
+                            imports.push(build_import_statement(pair));
+                        }
+                        _ => {}
+                    }
+                }
+                return TypeSystemOrExtensionDocument {
+                    definitions,
+                    imports,
+                };
             }
             rule => panic!("Unexpected Rule {:?}", rule),
         }
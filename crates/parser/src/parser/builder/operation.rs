@@ -128,36 +128,40 @@ pub fn build_executable_definition(pair: Pair<Rule>) -> ExecutableDefinitionExt
                 selection_set: build_selection_set(selection_set),
             })
         }
-        Rule::ext_ImportStatement => {
-            // pair becomes ext_ImportStatementContent
-            let pair = pair.only_child();
-            let (_, targets, _, path) = parts!(
-                pair,
-                ext_KEYWORD_import,
-                ext_ImportTargets,
-                ext_KEYWORD_from,
-                StringValue
-            );
-            ExecutableDefinitionExt::Import(ImportDefinition {
-                position,
-                targets: targets
-                    .into_inner()
-                    .map(|pair| {
-                        if pair.is_rule(Rule::Name) {
-                            ImportTarget::Name(pair.to_ident())
-                        } else {
-                            // "*"
-                            ImportTarget::Wildcard
-                        }
-                    })
-                    .collect(),
-                path: build_string_value(path),
-            })
-        }
+        Rule::ext_ImportStatement => ExecutableDefinitionExt::Import(build_import_statement(pair)),
         rule => panic!("Unexpected {:?} as a child of ExecutableDefinition", rule),
     }
 }
 
+/// Parses an ext_ImportStatement Pair.
+pub fn build_import_statement(pair: Pair<Rule>) -> ImportDefinition {
+    let position = pair.to_pos();
+    // pair becomes ext_ImportStatementContent
+    let pair = pair.only_child();
+    let (_, targets, _, path) = parts!(
+        pair,
+        ext_KEYWORD_import,
+        ext_ImportTargets,
+        ext_KEYWORD_from,
+        StringValue
+    );
+    ImportDefinition {
+        position,
+        targets: targets
+            .into_inner()
+            .map(|pair| {
+                if pair.is_rule(Rule::Name) {
+                    ImportTarget::Name(pair.to_ident())
+                } else {
+                    // "*"
+                    ImportTarget::Wildcard
+                }
+            })
+            .collect(),
+        path: build_string_value(path),
+    }
+}
+
 pub fn str_to_operation_type(o: &str) -> OperationType {
     match o {
         "query" => OperationType::Query,
@@ -1,5 +1,5 @@
 use nitrogql_ast::{TypeSystemOrExtensionDocument, base::Pos, operation_ext::OperationDocumentExt};
-use nitrogql_error::PositionedError;
+use nitrogql_error::{PositionedError, codes};
 use pest::Parser;
 use pest_derive::Parser;
 use thiserror::Error;
@@ -20,6 +20,8 @@ pub struct ParseErrorMessage(String);
 #[derive(Debug)]
 pub struct ParseError {
     position: Pos,
+    /// End of the erroneous range, if known.
+    end: Option<Pos>,
     message: String,
 }
 
@@ -33,14 +35,22 @@ impl ParseError {
 impl From<pest::error::Error<Rule>> for ParseError {
     fn from(error: pest::error::Error<Rule>) -> Self {
         // convert 1-based line and column to 0-based
-        let (line, col) = match error.line_col {
-            pest::error::LineColLocation::Pos((line, column)) => (line - 1, column - 1),
-            pest::error::LineColLocation::Span((line, column), _) => (line - 1, column - 1),
+        let (position, end) = match error.line_col {
+            pest::error::LineColLocation::Pos((line, column)) => {
+                (Pos::new(line - 1, column - 1), None)
+            }
+            pest::error::LineColLocation::Span((line, column), (end_line, end_column)) => (
+                Pos::new(line - 1, column - 1),
+                Some(Pos::new(end_line - 1, end_column - 1)),
+            ),
         };
-        let position = Pos::new(line, col);
         let message = error.variant.message().into_owned();
 
-        ParseError { position, message }
+        ParseError {
+            position,
+            end,
+            message,
+        }
     }
 }
 
@@ -50,7 +60,12 @@ impl From<ParseError> for PositionedError {
         let additional_info = vec![];
         let inner = ParseErrorMessage(value.message).into();
 
-        PositionedError::new(inner, Some(position), additional_info)
+        let error = PositionedError::new(inner, Some(position), additional_info)
+            .with_code(codes::SYNTAX_ERROR);
+        match value.end {
+            Some(end) => error.with_end(end),
+            None => error,
+        }
     }
 }
 
@@ -157,7 +157,7 @@ sample#comment
 mod import_syntax {
     use insta::assert_snapshot;
 
-    use crate::parser::parse_operation_document;
+    use crate::parser::{parse_operation_document, parse_type_system_document};
     use nitrogql_printer::GraphQLPrinter;
     use sourcemap_writer::JustWriter;
 
@@ -286,6 +286,22 @@ mod import_syntax {
         ));
     }
 
+    #[test]
+    fn schema_import() {
+        assert_snapshot!(print_graphql(
+            parse_type_system_document(
+                r#"
+                #import User, Node from "./user.graphql"
+                type Query {
+                    me: User!
+                }
+                #import * from "./post.graphql"
+                "#
+            )
+            .unwrap()
+        ));
+    }
+
     fn print_graphql<T: GraphQLPrinter>(value: T) -> String {
         let mut result = String::new();
         let mut writer = JustWriter::new(&mut result);
@@ -1,5 +1,6 @@
 /// Representation of the host application of the plugin.
-pub trait PluginHost {
+pub trait PluginHost<'src> {
     /// Load given string as a virtual file.
-    fn load_virtual_file(&mut self, content: String) -> &'static str;
+    /// Returned string lives as long as the host keeps its loaded files.
+    fn load_virtual_file(&mut self, content: String) -> &'src str;
 }
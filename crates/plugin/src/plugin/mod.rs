@@ -43,7 +43,7 @@ impl<'src> Plugin<'src> {
     /// Returns additional schema definition provided by the plugin.
     pub fn schema_addition(
         &self,
-        host: &mut impl PluginHost,
+        host: &mut impl PluginHost<'src>,
     ) -> Result<Option<TypeSystemOrExtensionDocument<'src>>, ParseError> {
         let mut cached = self.parsed_schema_addition.borrow_mut();
         if let Some(cached) = &*cached {
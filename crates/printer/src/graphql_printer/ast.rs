@@ -232,6 +232,9 @@ impl GraphQLPrinter for FragmentDefinition<'_> {
 
 impl GraphQLPrinter for TypeSystemOrExtensionDocument<'_> {
     fn print_graphql(&self, writer: &mut impl SourceMapWriter) {
+        for import in self.imports.iter() {
+            import.print_graphql(writer);
+        }
         for def in self.definitions.iter() {
             def.print_graphql(writer);
             writer.write("\n");
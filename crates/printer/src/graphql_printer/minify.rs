@@ -0,0 +1,98 @@
+/// Removes insignificant whitespace, commas and comments from GraphQL source.
+/// A single space is kept only where two tokens would otherwise merge.
+pub fn minify_graphql(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    // Whether the last token written is a name, a number or a string.
+    let mut last_is_non_punctuator = false;
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            ' ' | '\t' | '\n' | '\r' | ',' | '\u{feff}' => {}
+            '#' => while chars.next_if(|(_, c)| *c != '\n' && *c != '\r').is_some() {},
+            '.' => {
+                // `...` is the only punctuator that starts with a dot
+                chars.next();
+                chars.next();
+                if last_is_non_punctuator {
+                    result.push(' ');
+                }
+                result.push_str("...");
+                last_is_non_punctuator = false;
+            }
+            '"' => {
+                let end = if source[start..].starts_with("\"\"\"") {
+                    block_string_end(source, start)
+                } else {
+                    string_end(source, start)
+                };
+                if last_is_non_punctuator {
+                    result.push(' ');
+                }
+                result.push_str(&source[start..end]);
+                last_is_non_punctuator = true;
+                while chars.next_if(|(idx, _)| *idx < end).is_some() {}
+            }
+            c if c == '-' || c == '_' || c.is_ascii_alphanumeric() => {
+                let is_word_char = if c == '_' || c.is_ascii_alphabetic() {
+                    is_name_char
+                } else {
+                    is_number_char
+                };
+                let mut end = start + c.len_utf8();
+                while let Some((idx, c)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+                    end = idx + c.len_utf8();
+                }
+                if last_is_non_punctuator {
+                    result.push(' ');
+                }
+                result.push_str(&source[start..end]);
+                last_is_non_punctuator = true;
+            }
+            c => {
+                result.push(c);
+                last_is_non_punctuator = false;
+            }
+        }
+    }
+    result
+}
+
+/// Whether given character can continue a name.
+fn is_name_char(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric()
+}
+
+/// Whether given character can continue a number.
+fn is_number_char(c: char) -> bool {
+    c == '.' || c == '+' || c == '-' || c.is_ascii_alphanumeric()
+}
+
+/// Returns the end index of the string literal that starts at `start`.
+fn string_end(source: &str, start: usize) -> usize {
+    let mut escaped = false;
+    for (idx, c) in source[start + 1..].char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return start + 1 + idx + 1,
+            _ => escaped = false,
+        }
+    }
+    source.len()
+}
+
+/// Returns the end index of the block string literal that starts at `start`.
+fn block_string_end(source: &str, start: usize) -> usize {
+    let body = start + 3;
+    let mut idx = body;
+    while idx < source.len() {
+        let rest = &source[idx..];
+        if rest.starts_with("\\\"\"\"") {
+            idx += 4;
+        } else if rest.starts_with("\"\"\"") {
+            return idx + 3;
+        } else {
+            idx += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    source.len()
+}
@@ -3,10 +3,13 @@ use sourcemap_writer::SourceMapWriter;
 mod ast;
 mod base;
 mod ext;
+mod minify;
 mod schema;
 mod tests;
 mod utils;
 
+pub use minify::minify_graphql;
+
 pub trait GraphQLPrinter {
     fn print_graphql(&self, writer: &mut impl SourceMapWriter);
 }
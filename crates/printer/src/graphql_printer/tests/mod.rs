@@ -8,6 +8,8 @@ use sourcemap_writer::JustWriter;
 
 use crate::GraphQLPrinter;
 
+use super::minify_graphql;
+
 #[test]
 fn schema_printing() {
     let doc = parse_type_system_document(
@@ -67,6 +69,50 @@ fn schema_printing() {
     assert_snapshot!(printed);
 }
 
+#[test]
+fn string_escaping() {
+    let doc = parse_type_system_document(
+        r#"
+            "Wraps a value like \"this\" or C:\\path."
+            scalar Quoted
+
+            directive @tag(name: String!) on FIELD_DEFINITION
+
+            type Query {
+                search(keyword: String = "say \"hi\"\\"): Quoted @tag(name: "a\\b")
+            }
+            "#,
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let printed = print_document(&doc);
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn minify() {
+    let source = r#"
+        # comment
+        query Foo($a: Int = -1, $b: [Float!] = [1.5e+3, 2]) @dir(x: "a, b # c \" d") {
+            user(id: $a) {
+                ... Bar
+                ... on User { id }
+                bio(format: """
+                    block "string"
+                    with \""" escaped
+                """)
+            }
+        }
+    "#;
+    assert_eq!(
+        minify_graphql(source),
+        r#"query Foo($a:Int=-1$b:[Float!]=[1.5e+3 2])@dir(x:"a, b # c \" d"){user(id:$a){...Bar ...on User{id}bio(format:"""
+                    block "string"
+                    with \""" escaped
+                """)}}"#
+    );
+}
+
 fn print_document(document: &TypeSystemDocument) -> String {
     let mut result = String::new();
     let mut writer = JustWriter::new(&mut result);
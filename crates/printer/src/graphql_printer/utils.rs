@@ -34,6 +34,8 @@ pub fn print_string(s: &str, writer: &mut impl SourceMapWriter) {
         result.push('"');
         for c in s.chars() {
             match c {
+                '"' => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
                 '\r' => result.push_str("\\r"),
                 '\n' => result.push_str("\\n"),
                 c if c.is_control() => {
@@ -9,6 +9,7 @@ mod schema;
 mod schema_type_printer;
 pub mod ts_types;
 mod utils;
+mod validator_printer;
 
 pub use graphql_printer::GraphQLPrinter;
 pub use schema_type_printer::printer::{SchemaTypePrinter, SchemaTypePrinterOptions};
@@ -20,9 +21,15 @@ pub use resolver_type_printer::{
 };
 
 pub use operation_type_printer::{
+    ClientPresetDocument, ClientPresetError, print_client_preset_module,
     print_types_for_operation_document, visitor::OperationTypePrinterOptions,
 };
 
 pub use operation_js_printer::{
     options::OperationJSPrinterOptions, print_js_for_operation_document,
 };
+
+pub use validator_printer::{
+    options::ValidatorPrinterOptions,
+    printer::{ValidatorOperationDocument, ValidatorPrinter, ValidatorPrinterError},
+};
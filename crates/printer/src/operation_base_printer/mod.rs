@@ -1,6 +1,7 @@
 use nitrogql_ast::operation::{
     ExecutableDefinition, OperationDefinition, OperationDocument, OperationType,
 };
+use nitrogql_semantics::OperationExtension;
 use nitrogql_utils::capitalize;
 use sourcemap_writer::SourceMapWriter;
 
@@ -9,7 +10,9 @@ use self::options::OperationBasePrinterOptions;
 pub mod options;
 mod visitor;
 
-pub use visitor::{OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext};
+pub use visitor::{
+    OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext, PrintReexportContext,
+};
 
 pub struct OperationPrinter<'a, Writer: SourceMapWriter, Visitor: OperationPrinterVisitor> {
     options: OperationBasePrinterOptions,
@@ -34,13 +37,21 @@ where
         }
     }
 
-    pub fn print_document(&mut self, document: &OperationDocument) {
+    /// Prints given document.
+    /// `extension` is used to find the files that imported operations come from.
+    pub fn print_document(&mut self, document: &OperationDocument, extension: &OperationExtension) {
         self.visitor.print_header(self.writer);
 
+        // operations imported from other files are not counted.
         let operation_count = document
             .definitions
             .iter()
-            .filter(|def| matches!(def, ExecutableDefinition::OperationDefinition(_)))
+            .filter(|def| match def {
+                ExecutableDefinition::OperationDefinition(def) => {
+                    document.position.file == def.position.file
+                }
+                ExecutableDefinition::FragmentDefinition(_) => false,
+            })
             .count();
         let fragments = document
             .definitions
@@ -50,11 +61,26 @@ where
                 _ => None,
             })
             .collect();
+        // operations imported from other files, grouped by import path.
+        let mut reexports: Vec<(&str, Vec<OperationNames>)> = vec![];
 
         for d in document.definitions.iter() {
             match d {
                 ExecutableDefinition::OperationDefinition(def) => {
                     let operation_names = operation_variable_name(&self.options, def);
+                    if document.position.file != def.position.file {
+                        let Some(path) = def
+                            .name
+                            .and_then(|name| extension.import_path_of(name.name))
+                        else {
+                            continue;
+                        };
+                        match reexports.iter_mut().find(|(p, _)| *p == path) {
+                            Some((_, names)) => names.push(operation_names),
+                            None => reexports.push((path, vec![operation_names])),
+                        }
+                        continue;
+                    }
                     let context = PrintOperationContext {
                         operation_names: &operation_names,
                         exported: self.options.named_export_for_operation,
@@ -86,6 +112,18 @@ where
                 }
             }
         }
+        // Imported files are assumed to be generated with the same options as this file.
+        for (path, operation_names) in reexports.iter() {
+            let context = PrintReexportContext {
+                path,
+                operation_names,
+                named_exported: self.options.named_export_for_operation,
+                export_input_type: self.options.export_input_type,
+                export_result_type: self.options.export_result_type,
+            };
+            self.visitor
+                .print_reexported_operations(context, self.writer);
+        }
 
         self.visitor.print_trailer(self.writer);
     }
@@ -1,4 +1,4 @@
-use nitrogql_config_file::Config;
+use nitrogql_config_file::GenerateConfig;
 use nitrogql_utils::clone_into;
 
 /// Options for OperationBasePrinter.
@@ -42,33 +42,33 @@ impl Default for OperationBasePrinterOptions {
 
 impl OperationBasePrinterOptions {
     /// Creates a new instance of OperationBasePrinterOptions from
-    /// Config.
-    pub fn from_config(config: &Config) -> Self {
+    /// generate config of a file.
+    pub fn from_config(generate: &GenerateConfig) -> Self {
         let mut result = Self {
-            default_export_for_operation: config.generate.export.default_export_for_operation,
-            named_export_for_operation: !config.generate.export.default_export_for_operation,
-            export_input_type: config.generate.export.variables_type,
-            export_result_type: config.generate.export.operation_result_type,
+            default_export_for_operation: generate.export.default_export_for_operation,
+            named_export_for_operation: !generate.export.default_export_for_operation,
+            export_input_type: generate.export.variables_type,
+            export_result_type: generate.export.operation_result_type,
             ..Self::default()
         };
         clone_into(
-            &config.generate.name.capitalize_operation_names,
+            &generate.name.capitalize_operation_names,
             &mut result.capitalize_operation_names,
         );
         clone_into(
-            &config.generate.name.query_variable_suffix,
+            &generate.name.query_variable_suffix,
             &mut result.query_variable_suffix,
         );
         clone_into(
-            &config.generate.name.mutation_variable_suffix,
+            &generate.name.mutation_variable_suffix,
             &mut result.mutation_variable_suffix,
         );
         clone_into(
-            &config.generate.name.subscription_variable_suffix,
+            &generate.name.subscription_variable_suffix,
             &mut result.subscription_variable_suffix,
         );
         clone_into(
-            &config.generate.name.fragment_variable_suffix,
+            &generate.name.fragment_variable_suffix,
             &mut result.fragment_variable_suffix,
         );
         result
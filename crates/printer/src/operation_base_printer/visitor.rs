@@ -28,6 +28,12 @@ pub trait OperationPrinterVisitor {
         context: PrintOperationContext,
         writer: &mut impl SourceMapWriter,
     );
+    /// Prints re-exports of operations imported from one file.
+    fn print_reexported_operations(
+        &self,
+        context: PrintReexportContext,
+        writer: &mut impl SourceMapWriter,
+    );
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -57,3 +63,18 @@ pub struct PrintFragmentContext<'a> {
     /// Map from fragment names to fragment definitions.
     pub fragments: &'a HashMap<&'a str, &'a FragmentDefinition<'a>>,
 }
+
+#[derive(Copy, Clone, Debug)]
+pub struct PrintReexportContext<'a> {
+    /// Path of the imported file, as written in the import.
+    pub path: &'a str,
+    /// Names of the operations imported from the file.
+    pub operation_names: &'a [OperationNames],
+    /// Whether the operations are exported by name from the imported file.
+    /// Otherwise they are default exports.
+    pub named_exported: bool,
+    /// Whether input types of the operations are exported from the imported file.
+    pub export_input_type: bool,
+    /// Whether result types of the operations are exported from the imported file.
+    pub export_result_type: bool,
+}
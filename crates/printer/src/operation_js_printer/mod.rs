@@ -1,4 +1,5 @@
 use nitrogql_ast::OperationDocument;
+use nitrogql_semantics::OperationExtension;
 use sourcemap_writer::SourceMapWriter;
 
 use crate::{OperationJSPrinterOptions, operation_base_printer::OperationPrinter};
@@ -13,13 +14,15 @@ pub mod visitor;
 pub use printers::{print_fragment_runtime, print_operation_runtime};
 
 /// Print a JavaScript module for given operation document.
+/// Operations imported into the document are re-exported from the files in `extension`.
 pub fn print_js_for_operation_document(
     options: OperationJSPrinterOptions,
     operation: &OperationDocument,
+    extension: &OperationExtension,
     writer: &mut impl SourceMapWriter,
 ) {
     let base_options = options.base_options;
-    let visitor = OperationJSPrinterVisitor::new();
+    let visitor = OperationJSPrinterVisitor::new(options.document_format);
     let mut printer = OperationPrinter::new(base_options, visitor, writer);
-    printer.print_document(operation);
+    printer.print_document(operation, extension);
 }
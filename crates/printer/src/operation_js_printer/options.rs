@@ -1,17 +1,20 @@
-use nitrogql_config_file::Config;
+use nitrogql_config_file::{DocumentFormat, GenerateConfig};
 
 use crate::operation_base_printer::options::OperationBasePrinterOptions;
 
 #[derive(Clone, Debug, Default)]
 pub struct OperationJSPrinterOptions {
     pub base_options: OperationBasePrinterOptions,
+    /// Format of runtime values of operations.
+    pub document_format: DocumentFormat,
 }
 
 impl OperationJSPrinterOptions {
-    /// Generate from Config.
-    pub fn from_config(config: &Config) -> Self {
+    /// Generate from generate config of a file.
+    pub fn from_config(generate: &GenerateConfig) -> Self {
         Self {
-            base_options: OperationBasePrinterOptions::from_config(config),
+            base_options: OperationBasePrinterOptions::from_config(generate),
+            document_format: generate.document_format,
         }
     }
 }
@@ -1,17 +1,21 @@
 use std::collections::HashMap;
 
 use crate::{
+    GraphQLPrinter,
+    graphql_printer::minify_graphql,
     json_printer::{ExecutableDefinitionRef, print_to_json_string},
     utils::fragment_names_in_selection_set,
 };
 use nitrogql_ast::operation::{FragmentDefinition, OperationDefinition};
-use sourcemap_writer::SourceMapWriter;
+use nitrogql_config_file::DocumentFormat;
+use sourcemap_writer::{JustWriter, SourceMapWriter};
 
 /// Print the runtime value of given operation.
 pub fn print_operation_runtime(
     writer: &mut impl SourceMapWriter,
     operation: &OperationDefinition,
     fragments: &HashMap<&str, &FragmentDefinition>,
+    format: DocumentFormat,
 ) {
     let fragments_to_include = fragment_names_in_selection_set(&operation.selection_set, |name| {
         fragments.get(name).copied()
@@ -26,7 +30,7 @@ pub fn print_operation_runtime(
         .into_iter()
         .chain(fragments_to_include)
         .collect::<Vec<_>>();
-    writer.write(&print_to_json_string(&this_document[..]));
+    print_document_runtime(writer, &this_document, format);
 }
 
 /// Print the runtime value of given fragment.
@@ -34,6 +38,7 @@ pub fn print_fragment_runtime(
     writer: &mut impl SourceMapWriter,
     fragment: &FragmentDefinition,
     fragments: &HashMap<&str, &FragmentDefinition>,
+    format: DocumentFormat,
 ) {
     let fragments_to_include = fragment_names_in_selection_set(&fragment.selection_set, |name| {
         fragments.get(name).copied()
@@ -55,5 +60,59 @@ pub fn print_fragment_runtime(
         .into_iter()
         .chain(fragments_to_include)
         .collect::<Vec<_>>();
-    writer.write(&print_to_json_string(&this_document[..]));
+    print_document_runtime(writer, &this_document, format);
+}
+
+fn print_document_runtime(
+    writer: &mut impl SourceMapWriter,
+    document: &[ExecutableDefinitionRef],
+    format: DocumentFormat,
+) {
+    match format {
+        DocumentFormat::DocumentNode => {
+            writer.write(&print_to_json_string(document));
+        }
+        DocumentFormat::String => {
+            writer.write(&json_writer::to_json_string(&print_to_graphql_string(
+                document,
+            )));
+        }
+        DocumentFormat::MinifiedString => {
+            writer.write(&json_writer::to_json_string(&minify_graphql(
+                &print_to_graphql_string(document),
+            )));
+        }
+    }
+}
+
+/// Prints given definitions as GraphQL source to be sent to servers.
+/// Descriptions are omitted as they are not part of executable documents.
+fn print_to_graphql_string(document: &[ExecutableDefinitionRef]) -> String {
+    let mut result = String::new();
+    let mut writer = JustWriter::new(&mut result);
+    for (idx, def) in document.iter().enumerate() {
+        if idx > 0 {
+            writer.write("\n");
+        }
+        match def {
+            ExecutableDefinitionRef::OperationDefinition(operation) => {
+                let mut operation = (*operation).clone();
+                operation.description = None;
+                for variable in operation
+                    .variables_definition
+                    .iter_mut()
+                    .flat_map(|def| def.definitions.iter_mut())
+                {
+                    variable.description = None;
+                }
+                operation.print_graphql(&mut writer);
+            }
+            ExecutableDefinitionRef::FragmentDefinition(fragment) => {
+                let mut fragment = (*fragment).clone();
+                fragment.description = None;
+                fragment.print_graphql(&mut writer);
+            }
+        }
+    }
+    result
 }
@@ -1,9 +1,10 @@
 #![cfg(test)]
 
 use insta::assert_snapshot;
-use nitrogql_ast::OperationDocument;
+use nitrogql_ast::{OperationDocument, set_current_file_of_pos};
+use nitrogql_config_file::DocumentFormat;
 use nitrogql_parser::parse_operation_document;
-use nitrogql_semantics::resolve_operation_extensions;
+use nitrogql_semantics::{OperationExtension, resolve_operation_extensions};
 use sourcemap_writer::JustWriter;
 
 use crate::{OperationJSPrinterOptions, print_js_for_operation_document};
@@ -167,6 +168,86 @@ fn can_print_mutually_recursive_fragments() {
     assert_snapshot!(print_js(&document));
 }
 
+#[test]
+fn print_as_string() {
+    let document = parse(
+        r#"
+        "Operation description"
+        query MyQuery($id: ID!, $keyword: String = "a \"quoted\" word") {
+            user(id: $id) {
+                ...Foo
+                posts(keyword: $keyword, first: 10) {
+                    id
+                }
+            }
+        }
+
+        fragment Foo on User {
+            id name
+        }
+    "#,
+    );
+
+    assert_snapshot!(print_js_with_format(&document, DocumentFormat::String));
+}
+
+#[test]
+fn print_as_minified_string() {
+    let document = parse(
+        r#"
+        "Operation description"
+        query MyQuery($id: ID!, $keyword: String = "a \"quoted\" word") {
+            user(id: $id) {
+                ...Foo
+                posts(keyword: $keyword, first: 10) {
+                    id
+                }
+            }
+        }
+
+        fragment Foo on User {
+            id name
+        }
+    "#,
+    );
+
+    assert_snapshot!(print_js_with_format(
+        &document,
+        DocumentFormat::MinifiedString
+    ));
+}
+
+#[test]
+fn reexport_imported_operation() {
+    set_current_file_of_pos(1);
+    let base = parse(
+        r#"
+        query BaseMe {
+            me { id }
+        }
+    "#,
+    );
+    set_current_file_of_pos(0);
+    let doc = parse_operation_document(
+        r#"
+        #import BaseMe from "./base.graphql"
+        query MyQuery {
+            me { name }
+        }
+    "#,
+    )
+    .unwrap();
+    let (mut document, extension) = resolve_operation_extensions(doc).unwrap();
+    // imported definitions are appended by import resolution
+    document.definitions.extend(base.definitions);
+
+    let mut buffer = String::new();
+    let mut writer = JustWriter::new(&mut buffer);
+    let options = OperationJSPrinterOptions::default();
+    print_js_for_operation_document(options, &document, &extension, &mut writer);
+    assert_snapshot!(buffer);
+}
+
 fn parse(str: &str) -> OperationDocument<'_> {
     let doc = parse_operation_document(str).unwrap();
     let (document, _) = resolve_operation_extensions(doc).unwrap();
@@ -177,6 +258,27 @@ fn print_js(document: &OperationDocument) -> String {
     let mut buffer = String::new();
     let mut writer = JustWriter::new(&mut buffer);
     let options = OperationJSPrinterOptions::default();
-    print_js_for_operation_document(options, document, &mut writer);
+    print_js_for_operation_document(
+        options,
+        document,
+        &OperationExtension::default(),
+        &mut writer,
+    );
+    buffer
+}
+
+fn print_js_with_format(document: &OperationDocument, document_format: DocumentFormat) -> String {
+    let mut buffer = String::new();
+    let mut writer = JustWriter::new(&mut buffer);
+    let options = OperationJSPrinterOptions {
+        document_format,
+        ..OperationJSPrinterOptions::default()
+    };
+    print_js_for_operation_document(
+        options,
+        document,
+        &OperationExtension::default(),
+        &mut writer,
+    );
     buffer
 }
@@ -1,16 +1,19 @@
+use nitrogql_config_file::DocumentFormat;
 use sourcemap_writer::SourceMapWriter;
 
 use crate::operation_base_printer::{
-    OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext,
+    OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext, PrintReexportContext,
 };
 
 use super::printers::{print_fragment_runtime, print_operation_runtime};
 
-pub struct OperationJSPrinterVisitor {}
+pub struct OperationJSPrinterVisitor {
+    document_format: DocumentFormat,
+}
 
 impl OperationJSPrinterVisitor {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(document_format: DocumentFormat) -> Self {
+        Self { document_format }
     }
 }
 
@@ -32,7 +35,7 @@ impl OperationPrinterVisitor for OperationJSPrinterVisitor {
             &operation.name_pos(),
         );
         writer.write(" = ");
-        print_operation_runtime(writer, operation, context.fragments);
+        print_operation_runtime(writer, operation, context.fragments, self.document_format);
         writer.write(";\n\n");
     }
 
@@ -50,7 +53,7 @@ impl OperationPrinterVisitor for OperationJSPrinterVisitor {
 
         writer.write_for(context.var_name, fragment);
         writer.write(" = ");
-        print_fragment_runtime(writer, fragment, context.fragments);
+        print_fragment_runtime(writer, fragment, context.fragments, self.document_format);
         writer.write(";\n\n");
     }
     fn print_default_exported_operation_definition(
@@ -62,4 +65,24 @@ impl OperationPrinterVisitor for OperationJSPrinterVisitor {
         writer.write(&context.operation_names.operation_variable_name);
         writer.write(" as default };\n\n");
     }
+
+    fn print_reexported_operations(
+        &self,
+        context: PrintReexportContext,
+        writer: &mut impl SourceMapWriter,
+    ) {
+        writer.write("export { ");
+        for (idx, names) in context.operation_names.iter().enumerate() {
+            if idx > 0 {
+                writer.write(", ");
+            }
+            if !context.named_exported {
+                writer.write("default as ");
+            }
+            writer.write(&names.operation_variable_name);
+        }
+        writer.write(" } from \"");
+        writer.write(context.path);
+        writer.write("\";\n\n");
+    }
 }
@@ -1,11 +1,39 @@
-use graphql_type_system::ObjectDefinition;
+use graphql_type_system::{ObjectDefinition, Text, TypeDefinition};
 use nitrogql_ast::base::Pos;
 
 /// Branching condition used for printing types of selection sets.
 #[derive(Debug)]
 pub struct BranchingCondition<'a, S> {
-    /// Concrete object type on which selection set is applied.
-    pub parent_obj: &'a ObjectDefinition<S, Pos>,
+    /// Object type on which selection set is applied.
+    pub parent: BranchTarget<'a, S>,
     /// Values of boolean variables that are used in `if` directives.
     pub boolean_variables: Vec<(&'a str, bool)>,
 }
+
+/// Object type that a branch stands for.
+#[derive(Debug)]
+pub enum BranchTarget<'a, S> {
+    /// Concrete object type known at build time.
+    Object(&'a ObjectDefinition<S, Pos>),
+    /// Object types of given abstract type that are not known at build time.
+    CatchAll(&'a TypeDefinition<S, Pos>),
+}
+
+// derive would require S: Clone
+impl<S> Clone for BranchTarget<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for BranchTarget<'_, S> {}
+
+impl<'src, S: Text<'src>> BranchTarget<'_, S> {
+    /// Name of the object type, or of the abstract type for a catch-all branch.
+    pub fn name(&self) -> &S {
+        match self {
+            BranchTarget::Object(obj) => obj.name.inner_ref(),
+            BranchTarget::CatchAll(def) => def.name(),
+        }
+    }
+}
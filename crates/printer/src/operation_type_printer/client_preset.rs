@@ -0,0 +1,347 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use graphql_type_system::Schema;
+use nitrogql_ast::{
+    OperationDocument,
+    base::{HasPos, Pos},
+    operation::ExecutableDefinition,
+};
+use nitrogql_semantics::OperationExtension;
+use sourcemap_writer::SourceMapWriter;
+use thiserror::Error;
+
+use crate::operation_base_printer::{
+    OperationPrinter, OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext,
+    PrintReexportContext, operation_variable_name, options::OperationBasePrinterOptions,
+};
+
+use super::visitor::{OperationTypePrinterOptions, OperationTypePrinterVisitor};
+
+/// One operation document included in a client preset module.
+#[derive(Debug, Clone)]
+pub struct ClientPresetDocument<'a, 'src> {
+    /// Source text of the document. The `graphql` lookup function is typed for the text as is
+    /// and looks up documents by its normalized form at runtime.
+    pub source: &'a str,
+    pub document: &'a OperationDocument<'src>,
+    /// Options that apply to the file of the document.
+    pub options: OperationTypePrinterOptions,
+}
+
+/// Problems found in documents of a client preset module.
+#[derive(Error, Debug)]
+pub enum ClientPresetError {
+    #[error("Operation '{name}' is also defined in another document of the client preset module")]
+    OperationNameConflict {
+        name: String,
+        position: Pos,
+        other_position: Pos,
+    },
+    #[error("Fragment '{name}' is also defined in another document of the client preset module")]
+    FragmentNameConflict {
+        name: String,
+        position: Pos,
+        other_position: Pos,
+    },
+    #[error(
+        "This document cannot be looked up by 'graphql' because it does not define exactly one operation or one fragment"
+    )]
+    NoLookupTarget { position: Pos },
+}
+
+/// Print a single TypeScript module that contains all given operation documents
+/// and a `graphql` function that looks up documents by their source text.
+///
+/// Definitions with the same name in different documents are reported as an error.
+/// On success, documents that cannot be looked up are returned as
+/// `ClientPresetError::NoLookupTarget`. They are still exported by name.
+pub fn print_client_preset_module(
+    schema: &Schema<Cow<str>, Pos>,
+    documents: &[ClientPresetDocument],
+    writer: &mut impl SourceMapWriter,
+) -> Result<Vec<ClientPresetError>, ClientPresetError> {
+    check_name_conflicts(documents)?;
+
+    for (index, document) in documents.iter().enumerate() {
+        // everything lives in one module, so definitions are exported by name
+        let mut options = document.options.clone();
+        options.print_values = true;
+        options.base_options.default_export_for_operation = false;
+        options.base_options.named_export_for_operation = true;
+        let base_options = options.base_options.clone();
+
+        let visitor = ClientPresetVisitor {
+            inner: OperationTypePrinterVisitor::new(options, schema, document.document),
+            file: document.document.position.file,
+            print_header: index == 0,
+        };
+        let mut printer = OperationPrinter::new(base_options, visitor, writer);
+        // imported operations are defined by their own documents in the same module
+        printer.print_document(document.document, &OperationExtension::default());
+    }
+
+    let mut not_found = vec![];
+    let mut seen_sources = HashSet::new();
+    let lookup_entries = documents
+        .iter()
+        .filter_map(|document| {
+            let source = normalize_source(document.source);
+            if !seen_sources.insert(source.clone()) {
+                return None;
+            }
+            let var_name = lookup_target(&document.options.base_options, document.document);
+            if var_name.is_none() {
+                not_found.push(ClientPresetError::NoLookupTarget {
+                    position: document.document.position,
+                });
+            }
+            var_name.map(|var_name| LookupEntry {
+                source: json_writer::to_json_string(document.source),
+                normalized_source: json_writer::to_json_string(&source),
+                var_name,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    writer.write("const documents = {");
+    for entry in lookup_entries.iter() {
+        writer.write("\n  ");
+        writer.write(&entry.normalized_source);
+        writer.write(": ");
+        writer.write(&entry.var_name);
+        writer.write(",");
+    }
+    if !lookup_entries.is_empty() {
+        writer.write("\n");
+    }
+    writer.write("};\n\n");
+
+    writer.write(
+        "/**\n * Returns the document whose source text is given.\n * Comments and differences in whitespace are ignored at runtime,\n * but the return type is inferred only for the exact source text.\n */\n",
+    );
+    // TypeScript matches string literals exactly, so overloads use the source as is
+    for entry in lookup_entries.iter() {
+        writer.write("export function graphql(source: ");
+        writer.write(&entry.source);
+        writer.write("): typeof ");
+        writer.write(&entry.var_name);
+        writer.write(";\n");
+    }
+    writer.write("export function graphql(source: string): unknown;\n");
+    writer.write("export function graphql(source: string): unknown {\n");
+    writer.write("  return (documents as Record<string, unknown>)[normalize(source)];\n");
+    writer.write("}\n\n");
+    writer.write(NORMALIZE_FUNCTION);
+    Ok(not_found)
+}
+
+/// Document that can be looked up by the `graphql` function.
+struct LookupEntry {
+    /// Source text as a JSON string, used as the type of an overload.
+    source: String,
+    /// Normalized source text as a JSON string, used as the key at runtime.
+    normalized_source: String,
+    /// Name of the variable that holds the document.
+    var_name: String,
+}
+
+/// Runtime counterpart of `normalize_source`.
+const NORMALIZE_FUNCTION: &str = r#"function normalize(source: string): string {
+  // strings are kept as is; comments and whitespace become a single space
+  return source
+    .replace(
+      /("""(?:\\"""|[^])*?"""|"(?:\\.|[^"\\\n\r])*")|(?:#[^\n\r]*|[ \t\n\r\ufeff])+/g,
+      (_, string) => string ?? " ",
+    )
+    .trim();
+}
+"#;
+
+/// Normalizes the source text of a document for lookup.
+/// Comments and runs of whitespace outside strings are replaced with a single space,
+/// so `#import` lines and formatting do not affect the key.
+pub(super) fn normalize_source(source: &str) -> String {
+    let is_whitespace = |ch: char| matches!(ch, ' ' | '\t' | '\n' | '\r' | '\u{feff}');
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(ch) = rest.chars().next() {
+        if ch == '#' || is_whitespace(ch) {
+            while let Some(ch) = rest.chars().next() {
+                if ch == '#' {
+                    rest = rest.trim_start_matches(|ch| ch != '\n' && ch != '\r');
+                } else if is_whitespace(ch) {
+                    rest = &rest[ch.len_utf8()..];
+                } else {
+                    break;
+                }
+            }
+            result.push(' ');
+            continue;
+        }
+        let len = if rest.starts_with(r#"""""#) {
+            block_string_len(rest)
+        } else if ch == '"' {
+            string_len(rest)
+        } else {
+            ch.len_utf8()
+        };
+        result.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    result.trim().to_owned()
+}
+
+/// Length of the block string at the start of given text.
+fn block_string_len(text: &str) -> usize {
+    let mut index = 3;
+    while let Some(ch) = text[index..].chars().next() {
+        if text[index..].starts_with(r#"\""""#) {
+            index += 4;
+        } else if text[index..].starts_with(r#"""""#) {
+            return index + 3;
+        } else {
+            index += ch.len_utf8();
+        }
+    }
+    text.len()
+}
+
+/// Length of the string at the start of given text.
+fn string_len(text: &str) -> usize {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return index + 1,
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Checks that no two documents define operations or fragments with the same name.
+/// Such definitions would be exported with the same name from the module.
+fn check_name_conflicts(documents: &[ClientPresetDocument]) -> Result<(), ClientPresetError> {
+    let mut operations = HashMap::new();
+    let mut fragments = HashMap::new();
+    for document in documents {
+        let own_definitions = document
+            .document
+            .definitions
+            .iter()
+            .filter(|def| def.position().file == document.document.position.file);
+        for def in own_definitions {
+            match def {
+                ExecutableDefinition::OperationDefinition(operation) => {
+                    let name = operation_variable_name(&document.options.base_options, operation)
+                        .operation_name;
+                    let position = operation
+                        .name
+                        .map_or(operation.position, |name| name.position);
+                    if let Some(other_position) = operations.insert(name.clone(), position) {
+                        return Err(ClientPresetError::OperationNameConflict {
+                            name,
+                            position,
+                            other_position,
+                        });
+                    }
+                }
+                ExecutableDefinition::FragmentDefinition(fragment) => {
+                    let name = fragment.name.name.to_owned();
+                    let position = fragment.name.position;
+                    if let Some(other_position) = fragments.insert(name.clone(), position) {
+                        return Err(ClientPresetError::FragmentNameConflict {
+                            name,
+                            position,
+                            other_position,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the name of the variable returned by the lookup function for given document.
+/// That is the only operation in the document, or the only fragment if there is no operation.
+/// Documents with more definitions cannot be looked up.
+fn lookup_target(
+    options: &OperationBasePrinterOptions,
+    document: &OperationDocument,
+) -> Option<String> {
+    let (operations, fragments): (Vec<_>, Vec<_>) = document
+        .definitions
+        .iter()
+        .filter(|def| def.position().file == document.position.file)
+        .partition(|def| matches!(def, ExecutableDefinition::OperationDefinition(_)));
+    match (&operations[..], &fragments[..]) {
+        ([ExecutableDefinition::OperationDefinition(operation)], _) => {
+            Some(operation_variable_name(options, operation).operation_variable_name)
+        }
+        ([], [ExecutableDefinition::FragmentDefinition(fragment)]) => Some(format!(
+            "{}{}",
+            fragment.name.name, options.fragment_variable_suffix
+        )),
+        _ => None,
+    }
+}
+
+/// Visitor that prints one document of a client preset module.
+/// Definitions imported from other documents are skipped
+/// as they are printed as part of their own document.
+struct ClientPresetVisitor<'a, 'src> {
+    inner: OperationTypePrinterVisitor<'a, 'src>,
+    /// File index of the document.
+    file: usize,
+    /// Whether to print the header of the module.
+    print_header: bool,
+}
+
+impl OperationPrinterVisitor for ClientPresetVisitor<'_, '_> {
+    fn print_header(&self, writer: &mut impl SourceMapWriter) {
+        if self.print_header {
+            self.inner.print_header(writer);
+        }
+    }
+    fn print_trailer(&self, writer: &mut impl SourceMapWriter) {
+        self.inner.print_trailer(writer);
+    }
+    fn print_operation_definition(
+        &self,
+        context: PrintOperationContext,
+        writer: &mut impl SourceMapWriter,
+    ) {
+        if context.operation.position.file == self.file {
+            self.inner.print_operation_definition(context, writer);
+        }
+    }
+    fn print_fragment_definition(
+        &self,
+        context: PrintFragmentContext,
+        writer: &mut impl SourceMapWriter,
+    ) {
+        if context.fragment.position.file == self.file {
+            self.inner.print_fragment_definition(context, writer);
+        }
+    }
+    fn print_default_exported_operation_definition(
+        &self,
+        _context: PrintOperationContext,
+        _writer: &mut impl SourceMapWriter,
+    ) {
+        // no default export in a module with many documents
+    }
+    fn print_reexported_operations(
+        &self,
+        _context: PrintReexportContext,
+        _writer: &mut impl SourceMapWriter,
+    ) {
+        // imported operations are already exported from the module
+    }
+}
@@ -99,6 +99,7 @@ fn merge_selection_trees<'src, S: Text<'src>>(
                     Some(right_branch) => {
                         new_selection.push(SelectionTreeBranch {
                             type_name: left_branch.type_name,
+                            catch_all: left_branch.catch_all,
                             unaliased_fields: deep_merge_selection_tree(
                                 left_branch
                                     .unaliased_fields
@@ -111,6 +112,11 @@ fn merge_selection_trees<'src, S: Text<'src>>(
                                     .into_iter()
                                     .chain(right_branch.aliased_fields),
                             ),
+                            // masked fragments are kept as references; their fields are not merged
+                            fragment_refs: merge_fragment_refs(
+                                left_branch.fragment_refs,
+                                right_branch.fragment_refs,
+                            ),
                         });
                     }
                     None => {
@@ -131,3 +137,12 @@ fn merge_selection_trees<'src, S: Text<'src>>(
         _ => panic!("Cannot merge selection trees of different types"),
     }
 }
+
+fn merge_fragment_refs(mut left: Vec<String>, right: Vec<String>) -> Vec<String> {
+    for name in right {
+        if !left.contains(&name) {
+            left.push(name);
+        }
+    }
+    left
+}
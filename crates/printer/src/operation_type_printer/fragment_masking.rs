@@ -0,0 +1,33 @@
+//! Types used for fragment masking.
+//!
+//! When fragment masking is enabled, a fragment spread adds a reference to the fragment
+//! instead of the fields of the fragment. Fragment types carry their own name so that
+//! references can be unmasked by `useFragment` from `@nitrogql/core/fragment`.
+
+use crate::ts_types::{ObjectField, TSType};
+
+/// Key of the field that holds references to masked fragments.
+/// Starts with a space so that it never conflicts with GraphQL field names.
+pub const FRAGMENT_REFS_KEY: &str = " $fragmentRefs";
+/// Key of the field that holds name of a fragment.
+pub const FRAGMENT_NAME_KEY: &str = " $fragmentName";
+
+/// Generates the type of references to given fragments.
+pub fn fragment_refs_type<'a>(fragment_names: impl IntoIterator<Item = &'a str>) -> TSType {
+    TSType::object(
+        fragment_names
+            .into_iter()
+            .map(|name| (name, TSType::TypeVariable("true".into()), None)),
+    )
+}
+
+/// Generates the type that marks a type as data of given fragment.
+pub fn fragment_name_type(fragment_name: &str) -> TSType {
+    TSType::Object(vec![ObjectField {
+        key: FRAGMENT_NAME_KEY.into(),
+        r#type: TSType::StringLiteral(fragment_name.to_owned()),
+        readonly: false,
+        optional: true,
+        description: None,
+    }])
+}
@@ -2,14 +2,20 @@ use std::borrow::Cow;
 
 use graphql_type_system::Schema;
 use nitrogql_ast::{OperationDocument, base::Pos};
+use nitrogql_semantics::OperationExtension;
 use sourcemap_writer::SourceMapWriter;
 
 use crate::operation_base_printer::OperationPrinter;
 
+pub use self::client_preset::{
+    ClientPresetDocument, ClientPresetError, print_client_preset_module,
+};
 use self::visitor::{OperationTypePrinterOptions, OperationTypePrinterVisitor};
 
 mod branching;
+mod client_preset;
 mod deep_merge;
+mod fragment_masking;
 mod selection_set_visitor;
 mod selection_tree;
 #[cfg(test)]
@@ -18,14 +24,16 @@ pub mod type_printer;
 pub mod visitor;
 
 /// Print a TypeScript module for given operation document.
+/// Operations imported into the document are re-exported from the files in `extension`.
 pub fn print_types_for_operation_document(
     options: OperationTypePrinterOptions,
     schema: &Schema<Cow<str>, Pos>,
     operation: &OperationDocument,
+    extension: &OperationExtension,
     writer: &mut impl SourceMapWriter,
 ) {
     let base_options = options.base_options.clone();
     let visitor = OperationTypePrinterVisitor::new(options, schema, operation);
     let mut printer = OperationPrinter::new(base_options, visitor, writer);
-    printer.print_document(operation);
+    printer.print_document(operation, extension);
 }
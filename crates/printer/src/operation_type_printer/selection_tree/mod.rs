@@ -19,10 +19,16 @@ pub enum SelectionTree<S> {
 pub struct SelectionTreeBranch<S> {
     /// Name of GraphQL type that this selection corresponds to.
     pub type_name: String,
+    /// Whether this branch is for object types unknown at build time.
+    /// If true, `type_name` is the name of the abstract type.
+    pub catch_all: bool,
     /// List of unaliased fields that are selected.
     pub unaliased_fields: Vec<SelectionTreeField<S>>,
     /// List of aliased fields that are selected.
     pub aliased_fields: Vec<SelectionTreeField<S>>,
+    /// Names of fragments spread into this selection whose fields are masked.
+    /// Always empty unless fragment masking is enabled.
+    pub fragment_refs: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +62,8 @@ pub struct SelectionTreeLeaf<S> {
     pub name: S,
     /// GraphQL type of the field.
     pub r#type: Type<S, Pos>,
+    /// Brand given to the field by `@brand`.
+    pub brand: Option<S>,
 }
 
 /// Object field in a selection.
@@ -1,9 +1,15 @@
 use graphql_type_system::{NamedType, Text, Type};
 use nitrogql_config_file::TypeTarget;
 
-use crate::ts_types::{ObjectField, TSType, ts_types_util::ts_union};
+use crate::{
+    operation_type_printer::fragment_masking::{FRAGMENT_REFS_KEY, fragment_refs_type},
+    ts_types::{
+        ObjectField, TSType,
+        ts_types_util::{ts_branded, ts_union},
+    },
+};
 
-use super::{SelectionTree, SelectionTreeField};
+use super::{SelectionTree, SelectionTreeBranch, SelectionTreeField};
 
 #[derive(Debug, Copy, Clone)]
 pub struct GenerateSelectionTreeTypeContext<'a> {
@@ -46,19 +52,21 @@ fn generate_selection_tree_type_impl<'src, S: Text<'src>>(
                     TypeTarget::OperationOutput.to_string(),
                     branch.type_name.clone(),
                 );
+                let typename_type = typename_type(branch);
 
                 let unaliased_object = TSType::Object(
                     branch
                         .unaliased_fields
                         .iter()
-                        .map(|field| field_to_type(context, field, &branch.type_name))
+                        .map(|field| field_to_type(context, field, &typename_type))
                         .collect(),
                 );
                 let aliased_object = TSType::Object(
                     branch
                         .aliased_fields
                         .iter()
-                        .map(|field| field_to_type(context, field, &branch.type_name))
+                        .map(|field| field_to_type(context, field, &typename_type))
+                        .chain(fragment_refs_field(&branch.fragment_refs))
                         .collect(),
                 );
                 TSType::TypeFunc(
@@ -78,7 +86,7 @@ fn generate_selection_tree_type_impl<'src, S: Text<'src>>(
 fn field_to_type<'src, S: Text<'src>>(
     context: &GenerateSelectionTreeTypeContext,
     field: &SelectionTreeField<S>,
-    parent_type_name: &str,
+    typename_type: &TSType,
 ) -> ObjectField {
     match field {
         SelectionTreeField::Empty(empty) => ObjectField {
@@ -91,14 +99,18 @@ fn field_to_type<'src, S: Text<'src>>(
         SelectionTreeField::Leaf(leaf) => {
             let field_type = if leaf.name == "__typename" {
                 // special case for __typename
-                TSType::StringLiteral(parent_type_name.to_string())
+                typename_type.clone()
             } else {
                 map_to_tstype(&leaf.r#type, |ty| {
-                    TSType::NamespaceMember3(
+                    let ty = TSType::NamespaceMember3(
                         context.schema_root_namespace.into(),
                         TypeTarget::OperationOutput.to_string(),
                         ty.to_string(),
-                    )
+                    );
+                    match leaf.brand {
+                        Some(ref brand) => ts_branded(ty, brand),
+                        None => ty,
+                    }
                 })
             };
             ObjectField {
@@ -119,6 +131,35 @@ fn field_to_type<'src, S: Text<'src>>(
     }
 }
 
+/// `__typename` of the catch-all branch.
+/// This is a literal that no GraphQL type name can be, so that checking `__typename`
+/// narrows the result type to known branches.
+const CATCH_ALL_TYPENAME: &str = "%other";
+
+/// Type of `__typename` in given branch.
+fn typename_type<S>(branch: &SelectionTreeBranch<S>) -> TSType {
+    if branch.catch_all {
+        TSType::StringLiteral(CATCH_ALL_TYPENAME.into())
+    } else {
+        TSType::StringLiteral(branch.type_name.clone())
+    }
+}
+
+/// Generates the field that holds references to masked fragments.
+/// Fragment references are not fields of the schema type, so this goes to the aliased object.
+fn fragment_refs_field(fragment_refs: &[String]) -> Option<ObjectField> {
+    if fragment_refs.is_empty() {
+        return None;
+    }
+    Some(ObjectField {
+        key: FRAGMENT_REFS_KEY.into(),
+        r#type: fragment_refs_type(fragment_refs.iter().map(String::as_str)),
+        description: None,
+        optional: false,
+        readonly: false,
+    })
+}
+
 /// Map given Type to TSType.
 fn map_to_tstype<Str, OriginalNode>(
     ty: &Type<Str, OriginalNode>,
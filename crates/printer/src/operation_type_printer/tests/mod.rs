@@ -7,7 +7,8 @@ use nitrogql_ast::{OperationDocument, TypeSystemDocument};
 use nitrogql_ast::{OperationDocumentExt, set_current_file_of_pos};
 use nitrogql_parser::{parse_operation_document, parse_type_system_document};
 use nitrogql_semantics::{
-    OperationExtension, OperationResolver, ast_to_type_system, resolve_operation_extensions,
+    OperationExtension, OperationResolver, add_typename, ast_to_type_system,
+    resolve_operation_extensions,
 };
 use nitrogql_semantics::{resolve_operation_imports, resolve_schema_extensions};
 use sourcemap_writer::JustWriter;
@@ -25,6 +26,9 @@ fn type_system() -> TypeSystemDocument<'static> {
                 type: UserType!
                 age: Int
                 posts: [HasID!]!
+                nickname: String @semanticNonNull
+                friends: [User] @semanticNonNull(levels: [0, 1])
+                email: String! @brand(name: \"Email\")
             }
             interface HasID {
                 id: ID!
@@ -59,7 +63,10 @@ fn type_system() -> TypeSystemDocument<'static> {
                 me: User!
                 posts: [Post!]
                 nullablePosts: [Post]!
+                contents: [Content!]!
             }
+
+            union Content = Post | Tweet
             ",
     )
     .unwrap();
@@ -266,6 +273,27 @@ fn typename_field_on_union() {
     assert_snapshot!(printed);
 }
 
+#[test]
+fn added_typename() {
+    let doc = parse_operation_document(
+        "
+        query {
+            me {
+                id
+                posts {
+                    id
+                }
+            }
+        }
+        ",
+    )
+    .unwrap();
+    let (doc, _) = resolve_operation_extensions(doc).unwrap();
+    let doc = add_typename(doc, &["Query", "Mutation", "Subscription"]);
+    let printed = print_document(&doc, OperationTypePrinterOptions::default());
+    assert_snapshot!(printed);
+}
+
 #[test]
 fn query_variables() {
     let doc = parse_operation_document(
@@ -327,7 +355,13 @@ fn print_values() {
         print_values: true,
         ..Default::default()
     };
-    print_types_for_operation_document(options, &schema, &doc, &mut writer);
+    print_types_for_operation_document(
+        options,
+        &schema,
+        &doc,
+        &OperationExtension::default(),
+        &mut writer,
+    );
     assert_snapshot!(result);
 }
 
@@ -830,6 +864,357 @@ mod fragment_merging {
     }
 }
 
+mod fragment_masking {
+    use super::*;
+
+    fn print_masked(document: &OperationDocumentExt) -> String {
+        set_current_file_of_pos(0);
+        let (document, extensions) = resolve_operation_extensions(document.clone()).unwrap();
+        let document = resolve_operation_imports(
+            (Path::new("/path/to/main.graphql"), &document, &extensions),
+            &TestOperationResolver,
+        )
+        .unwrap();
+        print_document(
+            &document,
+            OperationTypePrinterOptions {
+                fragment_masking: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn masked_fragment_spread() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                id
+                ...F
+                ...P
+            }
+        }
+        fragment F on User {
+            id
+            name
+        }
+        fragment P on User {
+            age
+            posts {
+                id
+                ...F
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_masked(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn masked_fragment_on_abstract_type() {
+        let doc = parse_operation_document(
+            "
+        query($cond: Boolean!) {
+            me {
+                posts {
+                    ...F
+                    ... on Post {
+                        ...P @include(if: $cond)
+                    }
+                }
+            }
+        }
+        fragment F on HasID {
+            id
+        }
+        fragment P on Post {
+            title
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_masked(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn masked_fields_are_not_merged() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                posts {
+                    id
+                }
+                ...F
+            }
+        }
+        fragment F on User {
+            posts {
+                ... on Post {
+                    title
+                }
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_masked(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn masked_imported_fragment() {
+        let doc = parse_operation_document(
+            "
+            #import UserProfile from \"./user-profile.graphql\"
+            query myQuery {
+                me {
+                    id
+                    ...UserProfile
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_masked(&doc);
+        assert_snapshot!(printed);
+    }
+}
+
+mod semantic_non_null {
+    use super::*;
+
+    fn print_throw_on_field_error(document: &OperationDocumentExt) -> String {
+        set_current_file_of_pos(0);
+        let (document, _) = resolve_operation_extensions(document.clone()).unwrap();
+        print_document(
+            &document,
+            OperationTypePrinterOptions {
+                throw_on_field_error: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn semantic_non_null_fields() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                nickname
+                alias: nickname
+                friends { id }
+                aliasedFriends: friends { nickname }
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_throw_on_field_error(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn semantic_non_null_fields_without_throw_on_field_error() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                alias: nickname
+                aliasedFriends: friends { id }
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_document_default(&doc);
+        assert_snapshot!(printed);
+    }
+}
+
+mod brand {
+    use super::*;
+
+    #[test]
+    fn branded_fields() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                id
+                email
+                primaryEmail: email
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_document_default(&doc);
+        assert_snapshot!(printed);
+    }
+}
+
+mod future_proof_abstract_types {
+    use super::*;
+
+    fn print_future_proof(document: &OperationDocumentExt) -> String {
+        set_current_file_of_pos(0);
+        let (document, _) = resolve_operation_extensions(document.clone()).unwrap();
+        print_document(
+            &document,
+            OperationTypePrinterOptions {
+                future_proof_abstract_types: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn interface_catch_all_branch() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                posts {
+                    __typename
+                    id
+                    ... on Post {
+                        title
+                    }
+                    ... on HasID {
+                        hasId: id
+                    }
+                }
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_future_proof(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn union_catch_all_branch() {
+        let doc = parse_operation_document(
+            "
+        query {
+            contents {
+                __typename
+                ... on Post {
+                    title
+                }
+                ... on HasID {
+                    id
+                }
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_future_proof(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn object_has_no_catch_all_branch() {
+        let doc = parse_operation_document(
+            "
+        query {
+            me {
+                __typename
+                id
+            }
+        }
+        ",
+        )
+        .unwrap();
+        let printed = print_future_proof(&doc);
+        assert_snapshot!(printed);
+    }
+}
+
+mod document_format {
+    use super::*;
+    use nitrogql_config_file::DocumentFormat;
+
+    fn print_with_format(
+        document: &OperationDocumentExt,
+        print_values: bool,
+        document_format: DocumentFormat,
+    ) -> String {
+        set_current_file_of_pos(0);
+        let (document, _) = resolve_operation_extensions(document.clone()).unwrap();
+        let options = OperationTypePrinterOptions {
+            print_values,
+            document_format,
+            ..OperationTypePrinterOptions::default()
+        };
+        print_document(&document, options)
+    }
+
+    #[test]
+    fn string_format() {
+        let doc = parse_operation_document(
+            "
+            query Me {
+                me {
+                    ...F
+                }
+            }
+            fragment F on User {
+                id
+                name
+            }
+        ",
+        )
+        .unwrap();
+        let printed = print_with_format(&doc, true, DocumentFormat::String);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn minified_string_format() {
+        let doc = parse_operation_document(
+            "
+            query Me {
+                me {
+                    ...F
+                }
+            }
+            fragment F on User {
+                id
+                name
+            }
+        ",
+        )
+        .unwrap();
+        let printed = print_with_format(&doc, true, DocumentFormat::MinifiedString);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn declaration_only() {
+        let doc = parse_operation_document(
+            "
+            query Me {
+                me {
+                    id
+                }
+            }
+        ",
+        )
+        .unwrap();
+        let printed = print_with_format(&doc, false, DocumentFormat::String);
+        assert_snapshot!(printed);
+    }
+}
+
 mod import_fragments {
     use super::*;
 
@@ -855,6 +1240,245 @@ mod import_fragments {
     }
 }
 
+mod import_operations {
+    use super::*;
+
+    #[test]
+    fn import_operation() {
+        let doc = parse_operation_document(
+            "
+            #import BaseMe from \"./base.graphql\"
+            query myQuery {
+                me {
+                    id
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_document_default(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn import_operation_named_export() {
+        let doc = parse_operation_document(
+            "
+            #import BaseMe from \"./base.graphql\"
+            query myQuery {
+                me {
+                    id
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_document_with_imports(
+            &doc,
+            OperationTypePrinterOptions {
+                base_options: OperationBasePrinterOptions {
+                    default_export_for_operation: false,
+                    named_export_for_operation: true,
+                    export_input_type: true,
+                    export_result_type: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        assert_snapshot!(printed);
+    }
+}
+
+mod client_preset {
+    use super::*;
+    use crate::{
+        ClientPresetDocument, ClientPresetError,
+        operation_type_printer::client_preset::normalize_source, print_client_preset_module,
+    };
+
+    fn document<'a, 'src>(
+        source: &'a str,
+        document: &'a OperationDocument<'src>,
+    ) -> ClientPresetDocument<'a, 'src> {
+        ClientPresetDocument {
+            source,
+            document,
+            options: OperationTypePrinterOptions::default(),
+        }
+    }
+
+    fn print_module(documents: &[ClientPresetDocument]) -> (String, Vec<ClientPresetError>) {
+        let mut result = String::new();
+        let mut writer = JustWriter::new(&mut result);
+        let schema = type_system();
+        let schema = ast_to_type_system(&schema);
+        let warnings = print_client_preset_module(&schema, documents, &mut writer).unwrap();
+        (result, warnings)
+    }
+
+    #[test]
+    fn client_preset_module() {
+        let main_source = r#"#import UserProfile from "./user-profile.graphql"
+query myQuery {
+    me {
+        id
+        ...UserProfile
+    }
+}
+"#;
+        let profile_source = r#"fragment UserProfile on User {
+    name
+    age
+}
+"#;
+        let multi_source = r#"query A { me { id } }
+query B { me { name } }
+"#;
+        set_current_file_of_pos(0);
+        let main = parse_operation_document(main_source).unwrap();
+        let (main, extensions) = resolve_operation_extensions(main).unwrap();
+        let main = resolve_operation_imports(
+            (Path::new("/path/to/main.graphql"), &main, &extensions),
+            &TestOperationResolver,
+        )
+        .unwrap();
+        let (profile, _) = static_parse(profile_source, 1);
+        let (multi, _) = static_parse(multi_source, 3);
+
+        let (printed, warnings) = print_module(&[
+            document(main_source, &main),
+            document(profile_source, profile),
+            document(multi_source, multi),
+        ]);
+        assert_snapshot!(printed);
+        assert!(matches!(
+            warnings[..],
+            [ClientPresetError::NoLookupTarget { position }] if position.file == 3
+        ));
+    }
+
+    /// Returns the return type of the `graphql` overload selected for given argument.
+    /// Like TypeScript, a literal type is selected only when it equals the argument exactly.
+    fn selected_overload<'a>(printed: &'a str, argument: &str) -> Option<&'a str> {
+        let prefix = format!(
+            "export function graphql(source: {}): ",
+            json_writer::to_json_string(argument)
+        );
+        printed
+            .lines()
+            .find_map(|line| line.strip_prefix(&prefix)?.strip_suffix(';'))
+    }
+
+    #[test]
+    fn overloads_match_exact_sources() {
+        let source = r#"#import UserProfile from "./user-profile.graphql"
+
+# comment
+query myQuery {
+    me {
+        id
+        ...UserProfile
+    }
+}
+"#;
+        set_current_file_of_pos(0);
+        let main = parse_operation_document(source).unwrap();
+        let (main, extensions) = resolve_operation_extensions(main).unwrap();
+        let main = resolve_operation_imports(
+            (Path::new("/path/to/main.graphql"), &main, &extensions),
+            &TestOperationResolver,
+        )
+        .unwrap();
+        let (profile, _) = static_parse("fragment UserProfile on User { name }", 1);
+
+        let (printed, _) = print_module(&[
+            document(source, &main),
+            document("fragment UserProfile on User { name }", profile),
+        ]);
+        assert_eq!(
+            selected_overload(&printed, source),
+            Some("typeof MyQueryQuery")
+        );
+        assert_eq!(
+            selected_overload(&printed, "fragment UserProfile on User { name }"),
+            Some("typeof UserProfile")
+        );
+        // the normalized source is only a runtime key
+        let normalized = normalize_source(source);
+        assert_eq!(selected_overload(&printed, &normalized), None);
+        assert!(printed.contains(&format!(
+            "\n  {}: MyQueryQuery,\n",
+            json_writer::to_json_string(&normalized)
+        )));
+    }
+
+    #[test]
+    fn operation_name_conflict() {
+        let (first, _) = static_parse("query Me { me { id } }", 1);
+        let (second, _) = static_parse("\nquery me { me { name } }", 2);
+        let mut result = String::new();
+        let mut writer = JustWriter::new(&mut result);
+        let schema = type_system();
+        let schema = ast_to_type_system(&schema);
+        let error = print_client_preset_module(
+            &schema,
+            &[document("", first), document("", second)],
+            &mut writer,
+        )
+        .unwrap_err();
+        let ClientPresetError::OperationNameConflict {
+            name,
+            position,
+            other_position,
+        } = error
+        else {
+            panic!("unexpected error: {error}");
+        };
+        assert_eq!(name, "Me");
+        assert_eq!((position.file, position.line), (2, 1));
+        assert_eq!((other_position.file, other_position.line), (1, 0));
+    }
+
+    #[test]
+    fn fragment_name_conflict() {
+        let (first, _) = static_parse("fragment F on User { id }", 1);
+        let (second, _) = static_parse("fragment F on User { name }", 2);
+        let mut result = String::new();
+        let mut writer = JustWriter::new(&mut result);
+        let schema = type_system();
+        let schema = ast_to_type_system(&schema);
+        let error = print_client_preset_module(
+            &schema,
+            &[document("", first), document("", second)],
+            &mut writer,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            ClientPresetError::FragmentNameConflict { ref name, .. } if name == "F"
+        ));
+    }
+
+    #[test]
+    fn normalized_sources() {
+        assert_eq!(
+            normalize_source(
+                "#import F from \"./f.graphql\"\nquery A {\n  a # comment\n  ...F\n}\n"
+            ),
+            "query A { a ...F }"
+        );
+        assert_eq!(
+            normalize_source("query A {\r\n\ta(s: \"  # not a comment \\\"  \")\n}"),
+            "query A { a(s: \"  # not a comment \\\"  \") }"
+        );
+        assert_eq!(
+            normalize_source("query A {\n  a(s: \"\"\"\n  keep \\\"\"\" # this\n\"\"\")\n}"),
+            "query A { a(s: \"\"\"\n  keep \\\"\"\" # this\n\"\"\") }"
+        );
+    }
+}
+
 struct TestOperationResolver;
 impl<'src> OperationResolver<'src> for TestOperationResolver {
     fn resolve(
@@ -874,6 +1498,20 @@ fragment UserProfile on User {
 "#,
                 1,
             )),
+            "/path/to/base.graphql" => Some(static_parse(
+                r#"
+query BaseMe {
+    me {
+        ...BaseUser
+    }
+}
+fragment BaseUser on User {
+    id
+    name
+}
+"#,
+                2,
+            )),
             _ => None,
         }
     }
@@ -894,6 +1532,14 @@ fn static_parse(
 }
 
 fn print_document_default(document: &OperationDocumentExt) -> String {
+    print_document_with_imports(document, OperationTypePrinterOptions::default())
+}
+
+/// Prints given document after resolving its imports.
+fn print_document_with_imports(
+    document: &OperationDocumentExt,
+    options: OperationTypePrinterOptions,
+) -> String {
     set_current_file_of_pos(0);
     let (document, extensions) = resolve_operation_extensions(document.clone()).unwrap();
     let document = resolve_operation_imports(
@@ -901,14 +1547,22 @@ fn print_document_default(document: &OperationDocumentExt) -> String {
         &TestOperationResolver,
     )
     .unwrap();
-    print_document(&document, OperationTypePrinterOptions::default())
+    print_document_with_extension(&document, &extensions, options)
 }
 
 fn print_document(document: &OperationDocument, options: OperationTypePrinterOptions) -> String {
+    print_document_with_extension(document, &OperationExtension::default(), options)
+}
+
+fn print_document_with_extension(
+    document: &OperationDocument,
+    extension: &OperationExtension,
+    options: OperationTypePrinterOptions,
+) -> String {
     let mut result = String::new();
     let mut writer = JustWriter::new(&mut result);
     let schema = type_system();
     let schema = ast_to_type_system(&schema);
-    print_types_for_operation_document(options, &schema, document, &mut writer);
+    print_types_for_operation_document(options, &schema, document, extension, &mut writer);
     result
 }
@@ -4,7 +4,7 @@ use crate::{
     ts_types::{ObjectField, ts_types_util::ts_union, type_to_ts_type::get_ts_type_of_type},
     utils::interface_implementers,
 };
-use graphql_type_system::{NamedType, Node, ObjectDefinition, Schema, Text, Type, TypeDefinition};
+use graphql_type_system::{NamedType, Node, Schema, Text, Type, TypeDefinition};
 use itertools::{Either, Itertools};
 use nitrogql_ast::{
     base::Pos,
@@ -19,7 +19,7 @@ use nitrogql_semantics::direct_fields_of_output_type;
 
 use super::{
     super::ts_types::{TSType, ts_types_util::ts_intersection},
-    branching::BranchingCondition,
+    branching::{BranchTarget, BranchingCondition},
     deep_merge::deep_merge_selection_tree,
     selection_set_visitor::visit_fields_in_selection_set,
     selection_tree::{
@@ -72,16 +72,16 @@ fn generate_branching_conditions<'a, 'src, S: Text<'src>>(
         .schema
         .get_type(parent_type)
         .expect("Type system error");
-    let parent_objects = match **parent_type_def {
+    let mut parent_objects: Vec<_> = match **parent_type_def {
         TypeDefinition::Scalar(_) | TypeDefinition::Enum(_) | TypeDefinition::InputObject(_) => {
             panic!("Type system error")
         }
         TypeDefinition::Object(ref obj_def) => {
-            vec![obj_def]
+            vec![BranchTarget::Object(obj_def)]
         }
         TypeDefinition::Interface(ref interface_def) => {
             let object_defs = interface_implementers(context.schema, &interface_def.name);
-            object_defs.collect()
+            object_defs.map(BranchTarget::Object).collect()
         }
         TypeDefinition::Union(ref union_def) => {
             let object_defs = union_def.possible_types.iter().map(|member| {
@@ -91,9 +91,18 @@ fn generate_branching_conditions<'a, 'src, S: Text<'src>>(
                     .and_then(|def| def.as_object())
                     .expect("Type system error")
             });
-            object_defs.collect()
+            object_defs.map(BranchTarget::Object).collect()
         }
     };
+    if context.options.future_proof_abstract_types
+        && matches!(
+            **parent_type_def,
+            TypeDefinition::Interface(_) | TypeDefinition::Union(_)
+        )
+    {
+        // object types added to the schema later fall into this branch
+        parent_objects.push(BranchTarget::CatchAll(parent_type_def));
+    }
     // multi_cartesian_product cannot handle the case where there are no variables.
     // See: https://github.com/rust-itertools/itertools/issues/337
     let boolean_variables = get_boolean_variables(context, selection_set);
@@ -112,7 +121,7 @@ fn generate_branching_conditions<'a, 'src, S: Text<'src>>(
         .into_iter()
         .cartesian_product(boolean_variables)
         .map(|(obj, vars)| BranchingCondition {
-            parent_obj: obj,
+            parent: obj,
             boolean_variables: vars,
         })
         .collect()
@@ -162,13 +171,66 @@ fn get_object_type_for_selection_set<'src, S: Text<'src>>(
             .partition_map(identity);
     let unaliased = deep_merge_selection_tree(unaliased);
     let aliased = deep_merge_selection_tree(aliased);
+    let fragment_refs = if context.options.fragment_masking {
+        get_fragment_refs_for_selection_set(context, selection_set, branch)
+            .into_iter()
+            .unique()
+            .map(|name| name.to_owned())
+            .collect()
+    } else {
+        vec![]
+    };
     SelectionTreeBranch {
-        type_name: branch.parent_obj.name.to_string(),
+        type_name: branch.parent.name().to_string(),
+        catch_all: matches!(branch.parent, BranchTarget::CatchAll(_)),
         unaliased_fields: unaliased,
         aliased_fields: aliased,
+        fragment_refs,
     }
 }
 
+/// Returns names of fragments spread into given selection set under given branch.
+/// Used when fragment masking is enabled.
+fn get_fragment_refs_for_selection_set<'src, S: Text<'src>>(
+    context: &QueryTypePrinterContext<'_, 'src, S>,
+    selection_set: &SelectionSet<'src>,
+    branch: &BranchingCondition<S>,
+) -> Vec<&'src str> {
+    selection_set
+        .selections
+        .iter()
+        .flat_map(|sel| match sel {
+            Selection::Field(_) => vec![],
+            Selection::FragmentSpread(fragment) => {
+                let fragment_def = context
+                    .fragment_definitions
+                    .get(fragment.fragment_name.name)
+                    .expect("Type system error");
+                if check_fragment_condition(
+                    context,
+                    &branch.parent,
+                    fragment_def.type_condition.name,
+                ) && !check_skip_directive(branch, &fragment.directives)
+                {
+                    vec![fragment.fragment_name.name]
+                } else {
+                    vec![]
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                let matches = fragment.type_condition.as_ref().is_none_or(|cond| {
+                    check_fragment_condition(context, &branch.parent, cond.name)
+                });
+                if matches && !check_skip_directive(branch, &fragment.directives) {
+                    get_fragment_refs_for_selection_set(context, &fragment.selection_set, branch)
+                } else {
+                    vec![]
+                }
+            }
+        })
+        .collect()
+}
+
 /// Returns an iterator of object fields.
 /// Left is for non-aliased fields.
 /// Right is for aliased fields.
@@ -179,7 +241,7 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
 ) -> Vec<Either<SelectionTreeField<S>, SelectionTreeField<S>>> {
     let parent_type_def = context
         .schema
-        .get_type(&branch.parent_obj.name)
+        .get_type(branch.parent.name())
         .expect("Type system error");
 
     let parent_fields = direct_fields_of_output_type(parent_type_def).expect("Type system error");
@@ -205,6 +267,7 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
                                 S::from("String"),
                                 Pos::builtin(),
                             ))),
+                            brand: None,
                         })
                     } else {
                         let field_def = parent_fields
@@ -213,17 +276,25 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
                                 parent_field.name.inner_ref().borrow() == field_name
                             })
                             .expect("Type system error");
+                        let field_def_type = if context.options.throw_on_field_error {
+                            field_def
+                                .r#type
+                                .with_non_null_levels(&field_def.semantic_non_null_levels)
+                        } else {
+                            field_def.r#type.clone()
+                        };
 
                         match field.selection_set {
                             None => SelectionTreeField::Leaf(SelectionTreeLeaf {
                                 name: selection_key.into(),
-                                r#type: field_def.r#type.clone(),
+                                r#type: field_def_type,
+                                brand: field_def.brand.clone(),
                             }),
                             Some(ref selection_set) => {
                                 let object_type = get_type_for_selection_set(
                                     context,
                                     selection_set,
-                                    &field_def.r#type,
+                                    &field_def_type,
                                 );
                                 SelectionTreeField::Object(SelectionTreeObject {
                                     name: selection_key.into(),
@@ -246,6 +317,8 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
         .iter()
         .flat_map(move |sel| match sel {
             Selection::Field(_) => vec![],
+            // fields of masked fragments are not visible from the parent
+            Selection::FragmentSpread(_) if context.options.fragment_masking => vec![],
             Selection::FragmentSpread(fragment) => {
                 let fragment_def = context
                     .fragment_definitions
@@ -253,7 +326,7 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
                     .expect("Type system error");
                 if check_fragment_condition(
                     context,
-                    branch.parent_obj,
+                    &branch.parent,
                     fragment_def.type_condition.name,
                 ) {
                     let fields =
@@ -296,7 +369,7 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
                     }
                 }
                 Some(ref cond) => {
-                    if check_fragment_condition(context, branch.parent_obj, cond.name) {
+                    if check_fragment_condition(context, &branch.parent, cond.name) {
                         let fields =
                             get_fields_for_selection_set(context, &fragment.selection_set, branch);
                         if check_skip_directive(branch, &fragment.directives) {
@@ -385,12 +458,25 @@ fn check_skip_directive<'src, S: Text<'src>>(
     false
 }
 
-/// Returns whether given object type implements given condition.
+/// Returns whether given branch target implements given condition.
+/// A catch-all branch only matches conditions that every possible type satisfies.
 fn check_fragment_condition<'src, S: Text<'src>>(
     context: &QueryTypePrinterContext<'_, 'src, S>,
-    object_def: &ObjectDefinition<S, Pos>,
+    target: &BranchTarget<S>,
     cond: &str,
 ) -> bool {
+    let object_def = match target {
+        BranchTarget::Object(object_def) => object_def,
+        BranchTarget::CatchAll(abstract_def) => {
+            return abstract_def.name().borrow() == cond
+                || abstract_def.as_interface().is_some_and(|interface| {
+                    interface
+                        .interfaces
+                        .iter()
+                        .any(|imp| imp.inner_ref().borrow() == cond)
+                });
+        }
+    };
     let cond_type = context.schema.get_type(cond).expect("Type system error");
     match **cond_type {
         TypeDefinition::Object(ref obj) => object_def.name.inner_ref() == &*obj.name,
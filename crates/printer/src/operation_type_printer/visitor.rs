@@ -6,14 +6,14 @@ use nitrogql_ast::{
     base::Pos,
     operation::{ExecutableDefinition, FragmentDefinition, OperationType},
 };
-use nitrogql_config_file::{Config, GenerateMode};
+use nitrogql_config_file::{DocumentFormat, GenerateConfig, GenerateMode};
 use nitrogql_utils::clone_into;
 use sourcemap_writer::SourceMapWriter;
 
 use crate::{
     jsdoc::print_description,
     operation_base_printer::{
-        OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext,
+        OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext, PrintReexportContext,
         options::OperationBasePrinterOptions,
     },
     operation_js_printer::{print_fragment_runtime, print_operation_runtime},
@@ -21,6 +21,7 @@ use crate::{
 };
 
 use super::{
+    fragment_masking::{FRAGMENT_REFS_KEY, fragment_name_type, fragment_refs_type},
     selection_tree::{GenerateSelectionTreeTypeContext, generate_selection_tree_type},
     type_printer::{
         QueryTypePrinterContext, get_type_for_selection_set, get_type_for_variable_definitions,
@@ -46,6 +47,15 @@ pub struct OperationTypePrinterOptions {
     pub fragment_type_suffix: String,
     /// Whether to allow undefined as input value.
     pub allow_undefined_as_optional_input: bool,
+    /// Whether fragment spreads are masked in result types.
+    pub fragment_masking: bool,
+    /// Whether positions marked by `@semanticNonNull` are non-null in result types.
+    pub throw_on_field_error: bool,
+    /// Whether result types of abstract types have a catch-all branch
+    /// for object types unknown at build time.
+    pub future_proof_abstract_types: bool,
+    /// Format of runtime values of operations.
+    pub document_format: DocumentFormat,
 }
 
 impl Default for OperationTypePrinterOptions {
@@ -60,34 +70,53 @@ impl Default for OperationTypePrinterOptions {
             operation_result_type_suffix: "Result".to_owned(),
             fragment_type_suffix: "".to_owned(),
             allow_undefined_as_optional_input: true,
+            fragment_masking: false,
+            throw_on_field_error: false,
+            future_proof_abstract_types: false,
+            document_format: DocumentFormat::DocumentNode,
         }
     }
 }
 
 impl OperationTypePrinterOptions {
-    /// Generate options from config.
-    pub fn from_config(config: &Config) -> Self {
+    /// Generate options from generate config of a file.
+    pub fn from_config(generate: &GenerateConfig) -> Self {
         let mut result = Self {
-            base_options: OperationBasePrinterOptions::from_config(config),
+            base_options: OperationBasePrinterOptions::from_config(generate),
             ..Self::default()
         };
-        if config.generate.mode == GenerateMode::StandaloneTS4_0 {
+        if matches!(
+            generate.mode,
+            GenerateMode::StandaloneTS4_0 | GenerateMode::ClientPreset
+        ) {
             result.print_values = true;
         }
         clone_into(
-            &config.generate.name.operation_result_type_suffix,
+            &generate.name.operation_result_type_suffix,
             &mut result.operation_result_type_suffix,
         );
         clone_into(
-            &config.generate.name.variables_type_suffix,
+            &generate.name.variables_type_suffix,
             &mut result.variables_type_suffix,
         );
         clone_into(
-            &config.generate.name.fragment_type_suffix,
+            &generate.name.fragment_type_suffix,
             &mut result.fragment_type_suffix,
         );
+        result.fragment_masking = generate.r#type.fragment_masking;
+        result.throw_on_field_error = generate.r#type.throw_on_field_error;
+        result.future_proof_abstract_types = generate.r#type.future_proof_abstract_types;
+        result.document_format = generate.document_format;
         result
     }
+
+    /// Name of the type of runtime values of operations.
+    fn document_type_name(&self) -> &'static str {
+        match self.document_format {
+            DocumentFormat::DocumentNode => "TypedDocumentNode",
+            DocumentFormat::String | DocumentFormat::MinifiedString => "TypedDocumentString",
+        }
+    }
 }
 
 pub struct OperationTypePrinterVisitor<'a, 'src> {
@@ -129,16 +158,26 @@ pub struct OperationTypePrinterContext<'a, 'src, S: Text<'src>> {
 
 impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
     fn print_header(&self, writer: &mut impl SourceMapWriter) {
+        let document_type_import = match self.options.document_format {
+            DocumentFormat::DocumentNode => "TypedDocumentNode",
+            DocumentFormat::String | DocumentFormat::MinifiedString => "DocumentTypeDecoration",
+        };
         writeln!(
             writer,
-            "import type {{ TypedDocumentNode }} from \"{}\";",
-            self.options.typed_document_node_source
+            "import type {{ {} }} from \"{}\";",
+            document_type_import, self.options.typed_document_node_source
         );
         write!(
             writer,
             "import type * as {} from \"{}\";\n\n",
             self.options.schema_root_namespace, self.options.schema_source,
         );
+        if self.options.document_format != DocumentFormat::DocumentNode {
+            // printed source typed with result and variables types
+            writer.write(
+                "type TypedDocumentString<Result, Variables> = string & DocumentTypeDecoration<Result, Variables>;\n\n",
+            );
+        }
     }
     fn print_trailer(&self, _writer: &mut impl SourceMapWriter) {}
     fn print_operation_definition(
@@ -220,7 +259,8 @@ impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
             &operation.name_pos(),
         );
         writer.write_for(": ", &operation.selection_set);
-        writer.write("TypedDocumentNode<");
+        writer.write(self.options.document_type_name());
+        writer.write("<");
         writer.write(&result_type_name);
         writer.write(", ");
         writer.write(&input_variable_name);
@@ -229,11 +269,18 @@ impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
             return;
         }
         writer.write("> = ");
-        print_operation_runtime(writer, operation, context.fragments);
+        print_operation_runtime(
+            writer,
+            operation,
+            context.fragments,
+            self.options.document_format,
+        );
         // Use the `as unknown as` technique to avoid the type system complaining about
         // the type of the JSON object not matching the type of the TypedDocumentNode
         // (because of the use of enums in the TypedDocumentNode type)
-        writer.write(" as unknown as TypedDocumentNode<");
+        writer.write(" as unknown as ");
+        writer.write(self.options.document_type_name());
+        writer.write("<");
         writer.write(&result_type_name);
         writer.write(", ");
         writer.write(&input_variable_name);
@@ -288,9 +335,30 @@ impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
             },
             &fragment_type,
         );
-        fragment_type.print_type(writer);
+        if self.options.fragment_masking {
+            writer.write("(");
+            fragment_type.print_type(writer);
+            writer.write(") & ");
+            fragment_name_type(fragment.name.name).print_type(writer);
+        } else {
+            fragment_type.print_type(writer);
+        }
         writer.write(";\n\n");
 
+        if self.options.fragment_masking && context.exported {
+            // masked reference to this fragment, for use in places that receive fragment data
+            writer.write("export type ");
+            writer.write_for(&format!("{fragment_type_name}Ref"), fragment);
+            writer.write(" = ");
+            TSType::object([(
+                FRAGMENT_REFS_KEY,
+                fragment_refs_type([fragment.name.name]),
+                None,
+            )])
+            .print_type(writer);
+            writer.write(";\n\n");
+        }
+
         // runtime value
         if let Some(ref description) = fragment.description {
             print_description(description, writer);
@@ -308,7 +376,8 @@ impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
         );
         writer.write_for(&fragment_variable_name, fragment);
         writer.write(": ");
-        writer.write("TypedDocumentNode<");
+        writer.write(self.options.document_type_name());
+        writer.write("<");
         writer.write_for(&fragment_type_name, fragment);
         writer.write(", never>");
         if !self.options.print_values {
@@ -316,8 +385,15 @@ impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
             return;
         }
         writer.write(" = ");
-        print_fragment_runtime(writer, fragment, context.fragments);
-        writer.write(" as unknown as TypedDocumentNode<");
+        print_fragment_runtime(
+            writer,
+            fragment,
+            context.fragments,
+            self.options.document_format,
+        );
+        writer.write(" as unknown as ");
+        writer.write(self.options.document_type_name());
+        writer.write("<");
         writer.write_for(&fragment_type_name, fragment);
         writer.write(", never>;\n\n");
     }
@@ -330,6 +406,49 @@ impl OperationPrinterVisitor for OperationTypePrinterVisitor<'_, '_> {
         writer.write(&context.operation_names.operation_variable_name);
         writer.write(" as default };\n\n");
     }
+
+    fn print_reexported_operations(
+        &self,
+        context: PrintReexportContext,
+        writer: &mut impl SourceMapWriter,
+    ) {
+        writer.write("export { ");
+        for (idx, names) in context.operation_names.iter().enumerate() {
+            if idx > 0 {
+                writer.write(", ");
+            }
+            if !context.named_exported {
+                writer.write("default as ");
+            }
+            writer.write(&names.operation_variable_name);
+        }
+        let path = context.path;
+        writeln!(writer, " }} from \"{path}\";");
+
+        let mut type_names = vec![];
+        for names in context.operation_names.iter() {
+            if context.export_result_type {
+                type_names.push(format!(
+                    "{}{}",
+                    names.operation_name, self.options.operation_result_type_suffix
+                ));
+            }
+            if context.export_input_type {
+                type_names.push(format!(
+                    "{}{}",
+                    names.operation_name, self.options.variables_type_suffix
+                ));
+            }
+        }
+        if !type_names.is_empty() {
+            writeln!(
+                writer,
+                "export type {{ {} }} from \"{path}\";",
+                type_names.join(", ")
+            );
+        }
+        writer.write("\n");
+    }
 }
 
 fn select_root_type<T>(root_types: &RootTypes<T>, operation_type: OperationType) -> &T {
@@ -1,4 +1,5 @@
-use nitrogql_config_file::Config;
+use nitrogql_config_file::{Config, TypeImportConfig};
+use nitrogql_utils::clone_into;
 
 #[derive(Debug)]
 pub struct ResolverTypePrinterOptions {
@@ -10,6 +11,10 @@ pub struct ResolverTypePrinterOptions {
     pub schema_source: String,
     /// Name of the root TypeScript namespace that contains schema types.
     pub schema_root_namespace: String,
+    /// Default type of context. If None, context type must be given by users.
+    pub context_type: Option<TypeImportConfig>,
+    /// Type of the `info` argument of resolvers.
+    pub info_type: TypeImportConfig,
 }
 
 impl Default for ResolverTypePrinterOptions {
@@ -19,12 +24,32 @@ impl Default for ResolverTypePrinterOptions {
             resolver_output_type: "ResolverOutput".into(),
             schema_source: "".into(),
             schema_root_namespace: "Schema".into(),
+            context_type: None,
+            info_type: TypeImportConfig {
+                module: "graphql".into(),
+                name: "GraphQLResolveInfo".into(),
+            },
         }
     }
 }
 
 impl ResolverTypePrinterOptions {
-    pub fn from_config(_config: &Config) -> Self {
-        ResolverTypePrinterOptions::default()
+    pub fn from_config(config: &Config) -> Self {
+        let resolvers = &config.generate.resolvers;
+        let mut result = ResolverTypePrinterOptions {
+            context_type: resolvers.context_type.clone(),
+            ..ResolverTypePrinterOptions::default()
+        };
+        clone_into(&resolvers.root_type_name, &mut result.root_resolver_type);
+        clone_into(
+            &resolvers.output_type_name,
+            &mut result.resolver_output_type,
+        );
+        clone_into(
+            &resolvers.schema_namespace,
+            &mut result.schema_root_namespace,
+        );
+        clone_into(&resolvers.info_type, &mut result.info_type);
+        result
     }
 }
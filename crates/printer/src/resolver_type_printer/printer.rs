@@ -6,6 +6,7 @@ use nitrogql_ast::{
     base::Pos,
     type_system::{TypeDefinition, TypeSystemDefinition},
 };
+use nitrogql_config_file::TypeImportConfig;
 use nitrogql_semantics::ast_to_type_system;
 use sourcemap_writer::SourceMapWriter;
 
@@ -50,10 +51,14 @@ where
             schema: &schema,
         };
 
-        writeln!(
+        print_type_import(
             self.writer,
-            "import type {{ GraphQLResolveInfo }} from \"graphql\";"
+            &context.options.info_type,
+            "GraphQLResolveInfo",
         );
+        if let Some(context_type) = &context.options.context_type {
+            print_type_import(self.writer, context_type, "__Context");
+        }
         writeln!(
             self.writer,
             "import type * as {} from \"{}\";",
@@ -67,6 +72,20 @@ where
             self.writer,
             "type __TypeResolver<Obj, Context, Result> = (object: Obj, context: Context, info: GraphQLResolveInfo) => Result | Promise<Result>;"
         );
+        writeln!(
+            self.writer,
+            "type __IsTypeOfResolver<Obj, Context> = (object: Obj, context: Context, info: GraphQLResolveInfo) => boolean | Promise<boolean>;"
+        );
+        writeln!(
+            self.writer,
+            "type __SubscriptionResolver<Parent, Args, Context, Result, Key extends string> = {{
+  subscribe: (parent: Parent, args: Args, context: Context, info: GraphQLResolveInfo) => AsyncIterable<{{ [K in Key]: Result }}> | Promise<AsyncIterable<{{ [K in Key]: Result }}>>;
+  resolve?: undefined;
+}} | {{
+  subscribe: (parent: Parent, args: Args, context: Context, info: GraphQLResolveInfo) => AsyncIterable<unknown> | Promise<AsyncIterable<unknown>>;
+  resolve: (payload: any, args: Args, context: Context, info: GraphQLResolveInfo) => Result | Promise<Result>;
+}};"
+        );
 
         let ts_types: HashMap<&str, TSType> = document
             .definitions
@@ -162,8 +181,13 @@ where
 
         write!(
             self.writer,
-            "export type {}<Context> = ",
-            &context.options.root_resolver_type
+            "export type {}<Context{}> = ",
+            context.options.root_resolver_type,
+            if context.options.context_type.is_some() {
+                " = __Context"
+            } else {
+                ""
+            }
         );
         root_resolvers_type.print_type(self.writer);
         writeln!(self.writer, ";");
@@ -171,7 +195,7 @@ where
         write!(
             self.writer,
             "export type {}<T extends ",
-            &context.options.resolver_output_type
+            context.options.resolver_output_type
         );
         type_names_type.print_type(self.writer);
         writeln!(self.writer, "> = ");
@@ -182,6 +206,27 @@ where
     }
 }
 
+/// Prints an import of given type as `local_name`.
+fn print_type_import(
+    writer: &mut impl SourceMapWriter,
+    type_import: &TypeImportConfig,
+    local_name: &str,
+) {
+    if type_import.name == local_name {
+        writeln!(
+            writer,
+            "import type {{ {local_name} }} from \"{}\";",
+            type_import.module
+        );
+    } else {
+        writeln!(
+            writer,
+            "import type {{ {} as {local_name} }} from \"{}\";",
+            type_import.name, type_import.module
+        );
+    }
+}
+
 fn is_empty_object(ty: &TSType) -> bool {
     if let TSType::Object(fields) = ty {
         fields.is_empty()
@@ -2,6 +2,7 @@
 
 use insta::assert_snapshot;
 use nitrogql_ast::TypeSystemDocument;
+use nitrogql_config_file::TypeImportConfig;
 use nitrogql_parser::parse_type_system_document;
 use nitrogql_semantics::resolve_schema_extensions;
 use sourcemap_writer::JustWriter;
@@ -89,6 +90,94 @@ fn resolver_printing() {
     assert_snapshot!(printed);
 }
 
+#[test]
+fn custom_type_names() {
+    let doc = parse_type_system_document(
+        "
+            type User {
+                id: ID!
+                name: String!
+            }
+
+            type Query {
+                me: User!
+            }
+            ",
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            root_resolver_type: "GraphQLResolvers".into(),
+            resolver_output_type: "GraphQLResolverOutput".into(),
+            schema_source: "schema".into(),
+            schema_root_namespace: "GraphQLSchema".into(),
+            context_type: Some(TypeImportConfig {
+                module: "@/server/context".into(),
+                name: "Context".into(),
+            }),
+            info_type: TypeImportConfig {
+                module: "@/server/info".into(),
+                name: "ResolveInfo".into(),
+            },
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn subscription_and_abstract_types() {
+    let doc = parse_type_system_document(
+        "
+            schema {
+                query: Query
+                subscription: Sub
+            }
+            interface Node {
+                id: ID!
+            }
+            type User implements Node {
+                id: ID!
+            }
+            type Post implements Node {
+                id: ID!
+            }
+            type Comment {
+                body: String!
+            }
+            type Tag {
+                name: String!
+            }
+            union Searchable = Post | Comment
+
+            type Query {
+                node(id: ID!): Node
+                search: [Searchable!]!
+                tags: [Tag!]!
+            }
+            type Sub {
+                postAdded(authorId: ID!): Post!
+                counter: Int!
+            }
+            ",
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
 fn print_document(
     document: &TypeSystemDocument,
     options: ResolverTypePrinterOptions,
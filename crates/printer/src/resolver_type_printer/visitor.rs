@@ -1,6 +1,7 @@
 use crate::{
     ts_types::{
-        ObjectField, ObjectKey, TSType, ts_types_util::ts_union,
+        ObjectField, ObjectKey, TSType,
+        ts_types_util::{ts_branded, ts_union},
         type_to_ts_type::get_ts_type_of_type,
     },
     utils::interface_implementers,
@@ -10,6 +11,7 @@ use nitrogql_ast::type_system::{
     UnionTypeDefinition,
 };
 use nitrogql_config_file::TypeTarget;
+use nitrogql_semantics::brand_of;
 
 use super::printer::ResolverTypePrinterContext;
 
@@ -62,6 +64,8 @@ fn get_object_resolver_type(
     context: &ResolverTypePrinterContext,
 ) -> Option<TSType> {
     let parent_type = TSType::TypeVariable((&def.name).into());
+    let root_types = context.schema.root_types().unwrap_or_default();
+    let is_subscription_root = *root_types.subscription_type == def.name.name;
     let fields = def
         .fields
         .iter()
@@ -71,22 +75,44 @@ fn get_object_resolver_type(
                 |arguments| arguments_definition_to_ts(context, arguments),
             );
             let result_type = get_ts_type_of_type(&field.r#type, |name| {
-                TSType::TypeVariable((&name.name).into())
+                let ty = TSType::TypeVariable((&name.name).into());
+                match brand_of(&field.directives) {
+                    Some(brand) => ts_branded(ty, brand.name),
+                    None => ty,
+                }
             });
 
-            let resolver_type = TSType::TypeFunc(
-                Box::new(TSType::TypeVariable("__Resolver".into())),
-                vec![
-                    // Parent
-                    parent_type.clone(),
-                    // Args
-                    arguments_type,
-                    // Context
-                    TSType::TypeVariable("Context".into()),
-                    // Result
-                    result_type,
-                ],
-            );
+            let resolver_type = if is_subscription_root {
+                TSType::TypeFunc(
+                    Box::new(TSType::TypeVariable("__SubscriptionResolver".into())),
+                    vec![
+                        // Parent
+                        parent_type.clone(),
+                        // Args
+                        arguments_type,
+                        // Context
+                        TSType::TypeVariable("Context".into()),
+                        // Result
+                        result_type,
+                        // Key of the payload used by the default resolve function
+                        TSType::StringLiteral(field.name.name.to_owned()),
+                    ],
+                )
+            } else {
+                TSType::TypeFunc(
+                    Box::new(TSType::TypeVariable("__Resolver".into())),
+                    vec![
+                        // Parent
+                        parent_type.clone(),
+                        // Args
+                        arguments_type,
+                        // Context
+                        TSType::TypeVariable("Context".into()),
+                        // Result
+                        result_type,
+                    ],
+                )
+            };
 
             ObjectField {
                 key: ObjectKey::from(&field.name),
@@ -96,10 +122,70 @@ fn get_object_resolver_type(
                 description: None,
             }
         })
+        .chain(get_is_type_of_field(def, context))
         .collect();
     Some(TSType::Object(fields))
 }
 
+/// Generates the optional `__isTypeOf` field for objects that belong to
+/// some abstract types. Given object is one returned for a field of such abstract types.
+fn get_is_type_of_field(
+    def: &ObjectTypeDefinition<'_>,
+    context: &ResolverTypePrinterContext,
+) -> Option<ObjectField> {
+    let interfaces = def
+        .implements
+        .iter()
+        .map(|name| name.name)
+        .collect::<Vec<_>>();
+    let unions = context
+        .schema
+        .iter_types()
+        .filter_map(|(_, ty)| ty.as_union())
+        .filter(|union| {
+            union
+                .possible_types
+                .iter()
+                .any(|member| *member.inner_ref() == def.name.name)
+        })
+        .collect::<Vec<_>>();
+    if interfaces.is_empty() && unions.is_empty() {
+        return None;
+    }
+    // objects that share an abstract type with this object
+    let candidates = context
+        .schema
+        .iter_types()
+        .filter_map(|(_, ty)| ty.as_object())
+        .filter(|obj| {
+            obj.interfaces
+                .iter()
+                .any(|imp| interfaces.contains(&imp.inner_ref().as_ref()))
+                || unions.iter().any(|union| {
+                    union
+                        .possible_types
+                        .iter()
+                        .any(|member| member.inner_ref() == obj.name.inner_ref())
+                })
+        });
+    let resolver_type = TSType::TypeFunc(
+        Box::new(TSType::TypeVariable("__IsTypeOfResolver".into())),
+        vec![
+            // Obj
+            ts_union(candidates.map(|obj| TSType::TypeVariable(obj.name.as_ref().into()))),
+            // Context
+            TSType::TypeVariable("Context".into()),
+        ],
+    );
+    Some(ObjectField {
+        key: "__isTypeOf".into(),
+        r#type: resolver_type,
+        optional: true,
+        readonly: false,
+        description: None,
+    })
+}
+
 fn get_interface_resolver_type(
     def: &InterfaceTypeDefinition,
     context: &ResolverTypePrinterContext,
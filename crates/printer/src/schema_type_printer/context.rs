@@ -11,7 +11,7 @@ use nitrogql_ast::{
 };
 use nitrogql_config_file::{ScalarTypeConfig, SeparateScalarTypeConfig, TypeTarget};
 
-use crate::SchemaTypePrinterOptions;
+use crate::{SchemaTypePrinterOptions, utils::identifiers_in_code};
 
 pub struct SchemaTypePrinterContext<'src> {
     pub options: &'src SchemaTypePrinterOptions,
@@ -109,27 +109,11 @@ fn get_scalar_types(
 }
 
 fn get_bag_of_identifiers(scalar_types: &HashMap<String, ScalarTypeConfig>) -> HashSet<&str> {
-    let mut result = vec![];
-    for value in scalar_types.values().flat_map(|v| v.type_names()) {
-        let mut start_index = 0;
-        let mut in_identifier = false;
-        for (index, c) in value.char_indices() {
-            if !in_identifier {
-                if c.is_ascii_alphabetic() || c == '_' {
-                    in_identifier = true;
-                    start_index = index;
-                }
-            } else if !c.is_ascii_alphanumeric() && c != '_' {
-                // end of identifier
-                result.push(&value[start_index..index]);
-                in_identifier = false;
-            }
-        }
-        if in_identifier {
-            result.push(&value[start_index..]);
-        }
-    }
-    result.into_iter().collect()
+    scalar_types
+        .values()
+        .flat_map(|v| v.type_names())
+        .flat_map(|value| identifiers_in_code(value))
+        .collect()
 }
 
 fn make_local_type_names(
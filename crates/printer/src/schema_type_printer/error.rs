@@ -6,6 +6,15 @@ use nitrogql_ast::base::Pos;
 pub enum SchemaTypePrinterError {
     #[error("Type for scalar '{name}' is not provided")]
     ScalarTypeNotProvided { position: Pos, name: String },
+    #[error(
+        "Enum values '{first}' and '{second}' of '{enum_name}' both become '{member_name}' after case conversion"
+    )]
+    EnumMemberNameConflict {
+        enum_name: String,
+        member_name: String,
+        first: String,
+        second: String,
+    },
 }
 
 pub type SchemaTypePrinterResult<T> = Result<T, SchemaTypePrinterError>;
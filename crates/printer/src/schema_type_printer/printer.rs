@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use nitrogql_ast::type_system::{TypeDefinition, TypeSystemDefinition, TypeSystemDocument};
-use nitrogql_config_file::{Config, ScalarTypeConfig, TypeTarget};
+use nitrogql_config_file::{Config, EnumMemberCase, EnumStyle, ScalarTypeConfig, TypeTarget};
 use nitrogql_semantics::ast_to_type_system;
 use sourcemap_writer::SourceMapWriter;
 
@@ -20,6 +20,14 @@ pub struct SchemaTypePrinterOptions {
     pub input_nullable_field_is_optional: bool,
     /// Whether to emit runtime for generated schema types.
     pub emit_schema_runtime: bool,
+    /// Whether positions marked by `@semanticNonNull` are non-null in operation outputs.
+    pub throw_on_field_error: bool,
+    /// Scalars whose types are branded with their names.
+    pub branded_scalars: Vec<String>,
+    /// How enums are emitted. If None, depends on `emit_schema_runtime`.
+    pub enum_style: Option<EnumStyle>,
+    /// Casing of enum member names.
+    pub enum_member_case: EnumMemberCase,
 }
 
 impl Default for SchemaTypePrinterOptions {
@@ -29,6 +37,10 @@ impl Default for SchemaTypePrinterOptions {
             schema_metadata_type: "__nitrogql_schema".into(),
             input_nullable_field_is_optional: true,
             emit_schema_runtime: false,
+            throw_on_field_error: false,
+            branded_scalars: vec![],
+            enum_style: None,
+            enum_member_case: EnumMemberCase::default(),
         }
     }
 }
@@ -42,6 +54,10 @@ impl SchemaTypePrinterOptions {
                 .generate
                 .r#type
                 .allow_undefined_as_optional_input,
+            throw_on_field_error: config.generate.r#type.throw_on_field_error,
+            branded_scalars: config.generate.r#type.branded_scalars.clone(),
+            enum_style: Some(config.generate.enum_style()),
+            enum_member_case: config.generate.r#type.enum_member_case,
             ..SchemaTypePrinterOptions::default()
         };
         result.scalar_types.extend(
@@ -54,6 +70,15 @@ impl SchemaTypePrinterOptions {
         );
         result
     }
+
+    /// Returns the style of enums, taking the default into account.
+    pub fn enum_style(&self) -> EnumStyle {
+        self.enum_style.unwrap_or(if self.emit_schema_runtime {
+            EnumStyle::ConstObject
+        } else {
+            EnumStyle::Union
+        })
+    }
 }
 
 pub struct SchemaTypePrinter<'a, Writer: SourceMapWriter> {
@@ -2,7 +2,7 @@
 
 use insta::assert_snapshot;
 use nitrogql_ast::type_system::TypeSystemDocument;
-use nitrogql_config_file::ScalarTypeConfig;
+use nitrogql_config_file::{EnumMemberCase, EnumStyle, ScalarTypeConfig};
 
 use crate::schema_type_printer::{
     error::SchemaTypePrinterResult,
@@ -345,6 +345,178 @@ fn enum_runtime() {
     assert_snapshot!(printed);
 }
 
+#[test]
+fn semantic_non_null_with_throw_on_field_error() {
+    let doc = parse_type_system_document(
+        r#"
+        scalar ID
+        scalar String
+
+        type User {
+            id: ID!
+            name: String @semanticNonNull
+            nickname: String
+            friends: [User] @semanticNonNull(levels: [1])
+            tags: [[String]] @semanticNonNull(levels: [0, 2])
+        }
+
+        type Query {
+            me: User @semanticNonNull
+        }
+        "#,
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let options = SchemaTypePrinterOptions {
+        throw_on_field_error: true,
+        ..SchemaTypePrinterOptions::default()
+    };
+    let printed = print_document(&doc, options).unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn branded_types() {
+    let doc = parse_type_system_document(
+        r#"
+        scalar ID
+        scalar String
+        scalar UserId
+
+        type User {
+            id: UserId!
+            email: String! @brand(name: "Email")
+            aliases: [String!] @brand(name: "Alias")
+            ownerId: UserId! @brand(name: "Owner")
+        }
+
+        input UserInput {
+            email: String! @brand(name: "Email")
+        }
+        "#,
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let mut options = SchemaTypePrinterOptions {
+        branded_scalars: vec!["UserId".to_owned()],
+        ..SchemaTypePrinterOptions::default()
+    };
+    options.scalar_types.insert(
+        "UserId".to_owned(),
+        ScalarTypeConfig::Single("string".to_owned()),
+    );
+    let printed = print_document(&doc, options).unwrap();
+    assert_snapshot!(printed);
+}
+
+mod enum_style {
+    use super::*;
+
+    fn enum_document() -> TypeSystemDocument<'static> {
+        let doc = parse_type_system_document(
+            r#"
+            "Type of user."
+            enum UserType {
+                NORMAL_USER
+                PREMIUM_USER
+                admin
+            }
+            "#,
+        )
+        .unwrap();
+        resolve_schema_extensions(doc).unwrap()
+    }
+
+    #[test]
+    fn ts_enum() {
+        let options = SchemaTypePrinterOptions {
+            enum_style: Some(EnumStyle::Enum),
+            enum_member_case: EnumMemberCase::PascalCase,
+            ..SchemaTypePrinterOptions::default()
+        };
+        let printed = print_document(&enum_document(), options).unwrap();
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn const_object() {
+        let options = SchemaTypePrinterOptions {
+            enum_style: Some(EnumStyle::ConstObject),
+            enum_member_case: EnumMemberCase::CamelCase,
+            ..SchemaTypePrinterOptions::default()
+        };
+        let printed = print_document(&enum_document(), options).unwrap();
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn union_with_fallback() {
+        let options = SchemaTypePrinterOptions {
+            enum_style: Some(EnumStyle::UnionWithFallback),
+            ..SchemaTypePrinterOptions::default()
+        };
+        let printed = print_document(&enum_document(), options).unwrap();
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn member_case_with_acronyms_and_underscores() {
+        let doc = parse_type_system_document(
+            r#"
+            enum Protocol {
+                HTTPServer
+                HTTP2_SERVER
+                _internal
+            }
+            "#,
+        )
+        .unwrap();
+        let doc = resolve_schema_extensions(doc).unwrap();
+        let options = SchemaTypePrinterOptions {
+            enum_style: Some(EnumStyle::Enum),
+            enum_member_case: EnumMemberCase::PascalCase,
+            ..SchemaTypePrinterOptions::default()
+        };
+        let printed = print_document(&doc, options).unwrap();
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn member_name_conflict() {
+        let doc = parse_type_system_document(
+            r#"
+            enum UserType {
+                NORMAL_USER
+                normalUser
+            }
+            "#,
+        )
+        .unwrap();
+        let doc = resolve_schema_extensions(doc).unwrap();
+        let options = SchemaTypePrinterOptions {
+            enum_style: Some(EnumStyle::ConstObject),
+            enum_member_case: EnumMemberCase::CamelCase,
+            ..SchemaTypePrinterOptions::default()
+        };
+        let error = print_document(&doc, options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Enum values 'NORMAL_USER' and 'normalUser' of 'UserType' both become 'normalUser' after case conversion"
+        );
+    }
+
+    #[test]
+    fn explicit_union_with_runtime() {
+        let options = SchemaTypePrinterOptions {
+            emit_schema_runtime: true,
+            enum_style: Some(EnumStyle::Union),
+            ..SchemaTypePrinterOptions::default()
+        };
+        let printed = print_document(&enum_document(), options).unwrap();
+        assert_snapshot!(printed);
+    }
+}
+
 fn print_document(
     document: &TypeSystemDocument,
     options: SchemaTypePrinterOptions,
@@ -1,9 +1,12 @@
-use std::{borrow::Borrow, fmt::Display};
+use std::{
+    borrow::{Borrow, Cow},
+    fmt::Display,
+};
 
 use crate::{
     ts_types::{
         ObjectField, TSType,
-        ts_types_util::ts_union,
+        ts_types_util::{ts_branded, ts_union},
         type_to_ts_type::{get_ts_type_of_type, get_ts_type_of_type_non_null},
     },
     utils::interface_implementers,
@@ -17,10 +20,12 @@ use nitrogql_ast::{
     },
     value::StringValue,
 };
-use nitrogql_config_file::TypeTarget;
+use nitrogql_config_file::{EnumMemberCase, EnumStyle, TypeTarget};
+use nitrogql_semantics::{Brand, brand_of, type_system_utils::convert_type_to_ast};
+use nitrogql_utils::{camel_case, pascal_case, screaming_snake_case};
 use sourcemap_writer::SourceMapWriter;
 
-use crate::jsdoc::print_description as jsdoc_print_description;
+use crate::{SchemaTypePrinterOptions, jsdoc::print_description as jsdoc_print_description};
 
 use super::{
     context::SchemaTypePrinterContext,
@@ -122,7 +127,18 @@ impl TypePrinter for ScalarTypeDefinition<'_> {
             &self.name,
             local_name,
             |writer| {
-                writer.write(scalar_type_str.get_type(context.type_target));
+                let scalar_type_str = scalar_type_str.get_type(context.type_target);
+                if context
+                    .options
+                    .branded_scalars
+                    .iter()
+                    .any(|name| name == self.name.name)
+                {
+                    ts_branded(TSType::Raw(scalar_type_str.to_owned()), self.name.name)
+                        .print_type(writer);
+                } else {
+                    writer.write(scalar_type_str);
+                }
             },
         );
         Ok(())
@@ -173,14 +189,31 @@ impl TypePrinter for ObjectTypeDefinition<'_> {
                 let schema_field = schema_type
                     .and_then(|ty| ty.as_object())
                     .and_then(|ty| ty.fields.iter().find(|f| f.name == field.name.name));
+                // Positions marked by @semanticNonNull are never null in operation results
+                // when the client throws on field errors.
+                let non_null_type;
+                let field_type = match schema_field {
+                    Some(schema_field)
+                        if context.options.throw_on_field_error
+                            && matches!(context.type_target, TypeTarget::OperationOutput)
+                            && !schema_field.semantic_non_null_levels.is_empty() =>
+                    {
+                        non_null_type = schema_field
+                            .r#type
+                            .with_non_null_levels(&schema_field.semantic_non_null_levels);
+                        Cow::Owned(convert_type_to_ast(&non_null_type))
+                    }
+                    _ => Cow::Borrowed(&field.r#type),
+                };
+                let brand = brand_of(&field.directives);
                 (
                     &field.name,
-                    get_ts_type_of_type(&field.r#type, |name| {
+                    get_ts_type_of_type(&field_type, |name| {
                         let local_name = context
                             .local_type_names
                             .get(name.name.name)
                             .expect("Local type name not generated");
-                        TSType::TypeVariable(local_name.as_str().into())
+                        with_brand(TSType::TypeVariable(local_name.as_str().into()), brand)
                     }),
                     make_ts_description(
                         &field.description,
@@ -341,12 +374,32 @@ impl TypePrinter for EnumTypeDefinition<'_> {
         context: &SchemaTypePrinterContext,
         writer: &mut impl SourceMapWriter,
     ) -> SchemaTypePrinterResult<()> {
-        let enum_type = TSType::Union(
-            self.values
-                .iter()
-                .map(|mem| TSType::StringLiteral(mem.name.to_string()))
-                .collect(),
-        );
+        let values = self
+            .values
+            .iter()
+            .map(|mem| TSType::StringLiteral(mem.name.to_string()));
+        let enum_type = match context.options.enum_style() {
+            EnumStyle::Union | EnumStyle::ConstObject => TSType::Union(values.collect()),
+            EnumStyle::UnionWithFallback => {
+                if matches!(context.type_target, TypeTarget::OperationOutput) {
+                    // Values added to the schema later are received as unknown strings.
+                    TSType::Union(
+                        values
+                            .chain(std::iter::once(TSType::Intersection(vec![
+                                TSType::TypeVariable("string".into()),
+                                TSType::empty_object(),
+                            ])))
+                            .collect(),
+                    )
+                } else {
+                    TSType::Union(values.collect())
+                }
+            }
+            // Refers to the enum declared outside namespaces.
+            EnumStyle::Enum => {
+                TSType::TypeVariable(enum_alias_name(self.name.name).as_str().into())
+            }
+        };
 
         print_description(&self.description, writer);
         let local_name = context
@@ -369,6 +422,30 @@ impl TypePrinter for EnumTypeDefinition<'_> {
         context: &SchemaTypePrinterContext,
         writer: &mut impl SourceMapWriter,
     ) -> SchemaTypePrinterResult<()> {
+        let enum_style = context.options.enum_style();
+        if enum_style == EnumStyle::Enum {
+            print_description(&self.description, writer);
+            writer.write_for("export enum ", &self.enum_keyword);
+            writer.write_for(self.name.name, &self.name);
+            writer.write(" {\n");
+            writer.indent();
+            let member_names = enum_member_names(self, context.options)?;
+            for (value, member_name) in self.values.iter().zip(member_names) {
+                writer.write_for(&member_name, &value.name);
+                writer.write(" = \"");
+                writer.write_for(value.name.name, &value.name);
+                writer.write("\",\n");
+            }
+            writer.dedent();
+            writer.write("}\n");
+            writeln!(
+                writer,
+                "type {} = {};",
+                enum_alias_name(self.name.name),
+                self.name.name
+            );
+            return Ok(());
+        }
         let local_name = context
             .local_type_names
             .get(self.name.name)
@@ -380,13 +457,14 @@ impl TypePrinter for EnumTypeDefinition<'_> {
             local_name,
             TypeTarget::OperationOutput,
         );
-        if context.options.emit_schema_runtime {
+        if enum_style == EnumStyle::ConstObject {
             writer.write_for("export const ", &self.enum_keyword);
             writer.write_for(self.name.name, &self.name);
             writer.write(" = {\n");
             writer.indent();
-            for value in &self.values {
-                writer.write_for(value.name.name, &value.name);
+            let member_names = enum_member_names(self, context.options)?;
+            for (value, member_name) in self.values.iter().zip(member_names) {
+                writer.write_for(&member_name, &value.name);
                 writer.write(": \"");
                 writer.write_for(value.name.name, &value.name);
                 writer.write("\",\n");
@@ -398,6 +476,38 @@ impl TypePrinter for EnumTypeDefinition<'_> {
     }
 }
 
+/// Name of the type alias through which namespaces refer to a TypeScript enum.
+fn enum_alias_name(enum_name: &str) -> String {
+    format!("__enum_{enum_name}")
+}
+
+/// Names of the members of a TypeScript enum or a const object for given enum.
+/// Fails if two values become the same name after case conversion.
+fn enum_member_names(
+    def: &EnumTypeDefinition,
+    options: &SchemaTypePrinterOptions,
+) -> SchemaTypePrinterResult<Vec<String>> {
+    let mut names: Vec<String> = Vec::with_capacity(def.values.len());
+    for value in &def.values {
+        let name = match options.enum_member_case {
+            EnumMemberCase::Keep => value.name.name.to_owned(),
+            EnumMemberCase::PascalCase => pascal_case(value.name.name),
+            EnumMemberCase::CamelCase => camel_case(value.name.name),
+            EnumMemberCase::ScreamingSnakeCase => screaming_snake_case(value.name.name),
+        };
+        if let Some(index) = names.iter().position(|other| *other == name) {
+            return Err(SchemaTypePrinterError::EnumMemberNameConflict {
+                enum_name: def.name.name.to_owned(),
+                member_name: name,
+                first: def.values[index].name.name.to_owned(),
+                second: value.name.name.to_owned(),
+            });
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
+
 impl TypePrinter for InputObjectTypeDefinition<'_> {
     fn print_type(
         &self,
@@ -441,7 +551,10 @@ impl TypePrinter for InputObjectTypeDefinition<'_> {
                                     .local_type_names
                                     .get(name.name.name)
                                     .expect("Local type name not generated");
-                                TSType::TypeVariable(local_name.as_str().into())
+                                with_brand(
+                                    TSType::TypeVariable(local_name.as_str().into()),
+                                    brand_of(&field.directives),
+                                )
                             })
                             .into_readonly();
                             ObjectField {
@@ -473,7 +586,10 @@ impl TypePrinter for InputObjectTypeDefinition<'_> {
                                 .local_type_names
                                 .get(name.name.name)
                                 .expect("Local type name not generated");
-                            TSType::TypeVariable(local_name.as_str().into())
+                            with_brand(
+                                TSType::TypeVariable(local_name.as_str().into()),
+                                brand_of(&field.directives),
+                            )
                         })
                         .into_readonly();
                         let is_optional = context.options.input_nullable_field_is_optional
@@ -583,6 +699,14 @@ fn export_representative(
     }
 }
 
+/// Applies the brand given by `@brand` to the type of a field.
+fn with_brand(ty: TSType, brand: Option<Brand>) -> TSType {
+    match brand {
+        Some(brand) => ts_branded(ty, brand.name),
+        None => ty,
+    }
+}
+
 fn print_description(description: &Option<StringValue>, writer: &mut impl SourceMapWriter) {
     if let Some(description) = description {
         jsdoc_print_description(description, writer);
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use graphql_type_system::{Node, OriginalNodeRef};
 use nitrogql_ast::base::{HasPos, Ident, Pos};
 use sourcemap_writer::SourceMapWriter;
@@ -203,9 +205,7 @@ impl TSType {
                     if is_raw_ident(&field.key.name) {
                         writer.write_for(&field.key.name, &field.key);
                     } else {
-                        writer.write("\"");
-                        writer.write(&field.key.name);
-                        writer.write("\"");
+                        writer.write(&print_object_key(&field.key.name));
                     }
                     if field.optional {
                         writer.write("?");
@@ -333,6 +333,15 @@ impl TSType {
     }
 }
 
+/// Prints given key as an object property, quoted if needed.
+pub fn print_object_key(key: &str) -> Cow<'_, str> {
+    if is_raw_ident(key) {
+        Cow::Borrowed(key)
+    } else {
+        Cow::Owned(format!("\"{key}\""))
+    }
+}
+
 /// Returns true if given key can be printed as object property without quotations.
 fn is_raw_ident(key: &str) -> bool {
     let mut chars = key.chars();
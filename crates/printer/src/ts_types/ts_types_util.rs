@@ -2,7 +2,7 @@ use std::ops::Not;
 
 use itertools::Itertools;
 
-use super::{TSType, fast_equal};
+use super::{ObjectField, TSType, fast_equal};
 use fast_equal::fast_equal;
 
 /// Calculates intersection of given list of types.
@@ -59,3 +59,24 @@ pub fn ts_union(types: impl IntoIterator<Item = TSType>) -> TSType {
 
     TSType::Union(types)
 }
+
+/// Makes a branded type from given type, e.g. `string & { readonly __brand: { readonly UserId: true } }`.
+/// Each brand has its own key, so that a type can have more than one brand.
+pub fn ts_branded(ty: TSType, brand: &str) -> TSType {
+    TSType::Intersection(vec![
+        ty,
+        TSType::Object(vec![ObjectField {
+            key: "__brand".into(),
+            r#type: TSType::Object(vec![ObjectField {
+                key: brand.into(),
+                r#type: TSType::TypeVariable("true".into()),
+                readonly: true,
+                optional: false,
+                description: None,
+            }]),
+            readonly: true,
+            optional: false,
+            description: None,
+        }]),
+    ])
+}
@@ -57,3 +57,27 @@ pub fn fragment_names_in_selection_set<'a, 'src>(
         }
     }
 }
+
+/// Returns identifiers that appear in given TypeScript code.
+/// Used to avoid name collisions with user-provided code.
+pub fn identifiers_in_code(code: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut start_index = 0;
+    let mut in_identifier = false;
+    for (index, c) in code.char_indices() {
+        if !in_identifier {
+            if c.is_ascii_alphabetic() || c == '_' {
+                in_identifier = true;
+                start_index = index;
+            }
+        } else if !c.is_ascii_alphanumeric() && c != '_' {
+            // end of identifier
+            result.push(&code[start_index..index]);
+            in_identifier = false;
+        }
+    }
+    if in_identifier {
+        result.push(&code[start_index..]);
+    }
+    result
+}
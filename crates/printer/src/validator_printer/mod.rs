@@ -0,0 +1,3 @@
+pub mod options;
+pub mod printer;
+mod tests;
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use nitrogql_config_file::{Config, EnumStyle};
+
+#[derive(Debug)]
+pub struct ValidatorPrinterOptions {
+    /// Source of schema type to import from.
+    pub schema_source: String,
+    /// Name of the root TypeScript namespace that contains schema types.
+    pub schema_root_namespace: String,
+    /// Validators of scalars. Provided as raw TypeScript code.
+    pub scalar_validators: HashMap<String, String>,
+    /// Scalars whose types are branded with their names.
+    pub branded_scalars: Vec<String>,
+    /// Whether to make input nullable fields optional.
+    pub input_nullable_field_is_optional: bool,
+    /// How enums are emitted in schema types.
+    pub enum_style: EnumStyle,
+}
+
+impl Default for ValidatorPrinterOptions {
+    fn default() -> Self {
+        ValidatorPrinterOptions {
+            schema_source: "".into(),
+            schema_root_namespace: "Schema".into(),
+            scalar_validators: get_builtin_scalar_validators(),
+            branded_scalars: vec![],
+            input_nullable_field_is_optional: true,
+            enum_style: EnumStyle::Union,
+        }
+    }
+}
+
+impl ValidatorPrinterOptions {
+    pub fn from_config(config: &Config) -> Self {
+        let mut result = ValidatorPrinterOptions {
+            branded_scalars: config.generate.r#type.branded_scalars.clone(),
+            input_nullable_field_is_optional: config
+                .generate
+                .r#type
+                .allow_undefined_as_optional_input,
+            enum_style: config.generate.enum_style(),
+            ..ValidatorPrinterOptions::default()
+        };
+        result.scalar_validators.extend(
+            config
+                .generate
+                .r#type
+                .scalar_validators
+                .iter()
+                .map(|(key, value)| (key.to_owned(), value.clone())),
+        );
+        result
+    }
+}
+
+/// Generates validators for built-in scalars.
+fn get_builtin_scalar_validators() -> HashMap<String, String> {
+    vec![
+        ("ID".into(), "z.union([z.string(), z.number()])".into()),
+        ("String".into(), "z.string()".into()),
+        ("Int".into(), "z.number().int()".into()),
+        ("Float".into(), "z.number()".into()),
+        ("Boolean".into(), "z.boolean()".into()),
+    ]
+    .into_iter()
+    .collect()
+}
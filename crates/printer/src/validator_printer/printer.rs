@@ -0,0 +1,527 @@
+use std::collections::{HashMap, HashSet};
+
+use nitrogql_ast::{
+    OperationDocument, TypeSystemDocument,
+    base::Pos,
+    operation::{ExecutableDefinition, OperationDefinition},
+    r#type::Type,
+    type_system::{
+        EnumTypeDefinition, InputObjectTypeDefinition, InputValueDefinition, ScalarTypeDefinition,
+        TypeDefinition, TypeSystemDefinition,
+    },
+};
+use nitrogql_config_file::{EnumStyle, GenerateConfig};
+use nitrogql_semantics::brand_of;
+use sourcemap_writer::SourceMapWriter;
+use thiserror::Error;
+
+use crate::{
+    jsdoc::print_description,
+    operation_base_printer::{operation_variable_name, options::OperationBasePrinterOptions},
+    ts_types::print_object_key,
+    utils::identifiers_in_code,
+};
+
+use super::options::ValidatorPrinterOptions;
+
+/// One operation document whose variables get validators.
+#[derive(Debug, Copy, Clone)]
+pub struct ValidatorOperationDocument<'a, 'src> {
+    pub document: &'a OperationDocument<'src>,
+    /// Generate config for the file, used to name validators.
+    pub generate: &'a GenerateConfig,
+}
+
+/// Problems that prevent validators from being generated.
+#[derive(Error, Debug)]
+pub enum ValidatorPrinterError {
+    #[error("Scalar '{name}' is used in inputs but has no validator in 'scalarValidators'")]
+    MissingScalarValidator {
+        name: String,
+        /// Position of the input field or variable that uses the scalar.
+        position: Pos,
+        /// Position of the scalar definition.
+        definition: Pos,
+    },
+    #[error("Validator '{name}' is also generated for another operation or schema type")]
+    ValidatorNameConflict {
+        name: String,
+        /// Position of the operation name.
+        position: Pos,
+        /// Position of the other operation or schema type.
+        other_position: Pos,
+    },
+}
+
+pub struct ValidatorPrinter<'a, Writer> {
+    options: ValidatorPrinterOptions,
+    writer: &'a mut Writer,
+}
+
+impl<'a, Writer> ValidatorPrinter<'a, Writer>
+where
+    Writer: SourceMapWriter,
+{
+    pub fn new(options: ValidatorPrinterOptions, writer: &'a mut Writer) -> Self {
+        Self { options, writer }
+    }
+
+    /// Prints Zod validators for input types of the schema
+    /// and variables of given operations.
+    ///
+    /// Fails without printing anything if a custom scalar used in inputs has no validator
+    /// or if two validators would be exported by the same name.
+    pub fn print_document(
+        &mut self,
+        schema: &TypeSystemDocument,
+        operations: &[ValidatorOperationDocument],
+    ) -> Result<(), ValidatorPrinterError> {
+        check_scalar_validators(schema, operations, &self.options)?;
+        let variables_validators = variables_validators(schema, operations)?;
+        let local_names = make_local_names(
+            schema,
+            variables_validators.iter().map(|(name, _)| name.as_str()),
+            &self.options,
+        );
+        let context = ValidatorPrinterContext {
+            options: &self.options,
+            local_names: &local_names,
+        };
+
+        writeln!(self.writer, "import {{ z }} from \"zod\";");
+        writeln!(
+            self.writer,
+            "import {}* as {} from \"{}\";",
+            // TypeScript enums are referred to as values
+            if self.options.enum_style == EnumStyle::Enum {
+                ""
+            } else {
+                "type "
+            },
+            self.options.schema_root_namespace,
+            self.options.schema_source,
+        );
+        self.writer.write("\n");
+
+        // Scalars and enums come first because other validators refer to them eagerly.
+        let type_definitions = schema
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                TypeSystemDefinition::TypeDefinition(def) => Some(def),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for def in type_definitions.iter() {
+            match def {
+                TypeDefinition::Scalar(def) => print_scalar_validator(&context, def, self.writer),
+                TypeDefinition::Enum(def) => print_enum_validator(&context, def, self.writer),
+                _ => {}
+            }
+        }
+        for def in type_definitions.iter() {
+            if let TypeDefinition::InputObject(def) = def {
+                print_input_object_validator(&context, def, self.writer);
+            }
+        }
+        for (name, def) in variables_validators.iter() {
+            print_variables_validator(&context, def, name, self.writer);
+        }
+        Ok(())
+    }
+}
+
+/// Names variables validators of named operations.
+/// Fails if a name is used by another variables validator or by a validator of a schema type.
+fn variables_validators<'a, 'src>(
+    schema: &TypeSystemDocument,
+    operations: &[ValidatorOperationDocument<'a, 'src>],
+) -> Result<Vec<(String, &'a OperationDefinition<'src>)>, ValidatorPrinterError> {
+    let mut names = schema
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(
+                def @ (TypeDefinition::Scalar(_)
+                | TypeDefinition::Enum(_)
+                | TypeDefinition::InputObject(_)),
+            ) => Some((def.name().name.to_owned(), def.name().position)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut result = vec![];
+    for operation in operations {
+        let base_options = OperationBasePrinterOptions::from_config(operation.generate);
+        let variables_type_suffix = operation
+            .generate
+            .name
+            .variables_type_suffix
+            .as_deref()
+            .unwrap_or("Variables");
+        for def in operation.document.definitions.iter() {
+            let ExecutableDefinition::OperationDefinition(def) = def else {
+                continue;
+            };
+            let Some(operation_name) = def.name else {
+                // no way to name the validator
+                continue;
+            };
+            let operation_names = operation_variable_name(&base_options, def);
+            let name = format!(
+                "{}{}",
+                operation_names.operation_name, variables_type_suffix
+            );
+            let position = operation_name.position;
+            if let Some(other_position) = names.insert(name.clone(), position) {
+                return Err(ValidatorPrinterError::ValidatorNameConflict {
+                    name,
+                    position,
+                    other_position,
+                });
+            }
+            result.push((name, def));
+        }
+    }
+    Ok(result)
+}
+
+/// Checks that every scalar reachable from input types or variables of named operations has a validator.
+/// Without one, any value would pass validation.
+fn check_scalar_validators(
+    schema: &TypeSystemDocument,
+    operations: &[ValidatorOperationDocument],
+    options: &ValidatorPrinterOptions,
+) -> Result<(), ValidatorPrinterError> {
+    let scalars = schema
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::Scalar(def)) => {
+                Some((def.name.name, def.name.position))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let input_fields = schema
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::InputObject(def)) => {
+                Some(def.fields.iter())
+            }
+            _ => None,
+        })
+        .flatten()
+        .map(|field| (&field.r#type, field.name.position));
+    let variables = operations
+        .iter()
+        .flat_map(|operation| operation.document.definitions.iter())
+        .filter_map(|def| match def {
+            ExecutableDefinition::OperationDefinition(def) if def.name.is_some() => {
+                def.variables_definition.as_ref()
+            }
+            _ => None,
+        })
+        .flat_map(|def| def.definitions.iter())
+        .map(|def| (&def.r#type, def.name.position));
+    for (ty, position) in input_fields.chain(variables) {
+        let name = ty.unwrapped_type().name.name;
+        let Some(definition) = scalars.get(name) else {
+            continue;
+        };
+        if !options.scalar_validators.contains_key(name) {
+            return Err(ValidatorPrinterError::MissingScalarValidator {
+                name: name.to_owned(),
+                position,
+                definition: *definition,
+            });
+        }
+    }
+    Ok(())
+}
+
+struct ValidatorPrinterContext<'a> {
+    options: &'a ValidatorPrinterOptions,
+    /// Mapping from schema type name to the name of the local variable that holds its validator.
+    local_names: &'a HashMap<String, String>,
+}
+
+impl ValidatorPrinterContext<'_> {
+    fn local_name(&self, name: &str) -> &str {
+        self.local_names
+            .get(name)
+            .expect("Local validator name not generated")
+    }
+
+    /// Reference to the schema type of given name.
+    fn schema_type(&self, name: &str) -> String {
+        format!(
+            "{}.__OperationInput.{name}",
+            self.options.schema_root_namespace
+        )
+    }
+}
+
+/// Generates local variable names for validators of schema types and variables.
+/// Names that appear in scalar validators are avoided
+/// so that user-provided code is not affected.
+fn make_local_names<'a>(
+    document: &'a TypeSystemDocument,
+    variables_validators: impl Iterator<Item = &'a str>,
+    options: &ValidatorPrinterOptions,
+) -> HashMap<String, String> {
+    let mut bag = options
+        .scalar_validators
+        .values()
+        .flat_map(|code| identifiers_in_code(code))
+        .collect::<HashSet<_>>();
+    bag.insert("z");
+    bag.insert(&options.schema_root_namespace);
+    document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(def) => Some(def.name().name),
+            _ => None,
+        })
+        .chain(variables_validators)
+        .map(|name| {
+            let local_name = if bag.contains(name) {
+                format!("__tmp_{name}")
+            } else {
+                name.to_owned()
+            };
+            (name.to_owned(), local_name)
+        })
+        .collect()
+}
+
+fn print_scalar_validator(
+    context: &ValidatorPrinterContext,
+    def: &ScalarTypeDefinition,
+    writer: &mut impl SourceMapWriter,
+) {
+    let schema_type = context.schema_type(def.name.name);
+    let validator = context.options.scalar_validators.get(def.name.name);
+    let is_branded = context
+        .options
+        .branded_scalars
+        .iter()
+        .any(|name| name == def.name.name);
+    let validator = match validator {
+        // Branded types cannot be produced by plain validators.
+        Some(validator) if is_branded => custom_validator(&schema_type, validator),
+        Some(validator) => validator.clone(),
+        // Only scalars not used in inputs lack validators, so this validator is never used by others.
+        None => format!("z.custom<{schema_type}>()"),
+    };
+    if let Some(description) = &def.description {
+        print_description(description, writer);
+    }
+    export_const(context, def.name.name, None, &validator, writer);
+}
+
+fn print_enum_validator(
+    context: &ValidatorPrinterContext,
+    def: &EnumTypeDefinition,
+    writer: &mut impl SourceMapWriter,
+) {
+    let validator = if context.options.enum_style == EnumStyle::Enum {
+        format!(
+            "z.nativeEnum({}.{})",
+            context.options.schema_root_namespace, def.name.name
+        )
+    } else {
+        format!(
+            "z.enum([{}])",
+            def.values
+                .iter()
+                .map(|value| format!("\"{}\"", value.name.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    if let Some(description) = &def.description {
+        print_description(description, writer);
+    }
+    export_const(context, def.name.name, None, &validator, writer);
+}
+
+fn print_input_object_validator(
+    context: &ValidatorPrinterContext,
+    def: &InputObjectTypeDefinition,
+    writer: &mut impl SourceMapWriter,
+) {
+    let is_one_of = def.directives.iter().any(|d| d.name.name == "oneOf");
+    let object = if is_one_of {
+        // Exactly one field must be given a non-null value.
+        let branches = def
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "z.object({{\n    {}: {},\n  }}).strict()",
+                    field.name.name,
+                    non_null_validator(&field_named_validator(context, field), &field.r#type)
+                )
+            })
+            .collect::<Vec<_>>();
+        if branches.len() == 1 {
+            branches.into_iter().next().unwrap()
+        } else {
+            format!("z.union([\n  {},\n])", branches.join(",\n  "))
+        }
+    } else {
+        let fields = def
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "  {}: {},\n",
+                    field.name.name,
+                    field_validator(
+                        context,
+                        &field_named_validator(context, field),
+                        &field.r#type
+                    )
+                )
+            })
+            .collect::<String>();
+        format!("z.object({{\n{fields}}})")
+    };
+    if let Some(description) = &def.description {
+        print_description(description, writer);
+    }
+    // Wrapped in z.lazy so that input objects can refer to each other in any order.
+    // The type annotation is required for recursive input objects.
+    export_const(
+        context,
+        def.name.name,
+        Some(&format!(
+            "z.ZodType<{}>",
+            context.schema_type(def.name.name)
+        )),
+        &format!("z.lazy(() => {object})"),
+        writer,
+    );
+}
+
+fn print_variables_validator(
+    context: &ValidatorPrinterContext,
+    operation: &OperationDefinition,
+    name: &str,
+    writer: &mut impl SourceMapWriter,
+) {
+    let fields = operation
+        .variables_definition
+        .iter()
+        .flat_map(|def| def.definitions.iter())
+        .map(|def| {
+            format!(
+                "  {}: {},\n",
+                def.name.name,
+                field_validator(
+                    context,
+                    &|name| context.local_name(name).to_owned(),
+                    &def.r#type
+                )
+            )
+        })
+        .collect::<String>();
+    if let Some(description) = &operation.description {
+        print_description(description, writer);
+    }
+    export_const(
+        context,
+        name,
+        None,
+        &format!("z.object({{\n{fields}}})"),
+        writer,
+    );
+}
+
+/// Returns a function that generates the validator for the named type of given field,
+/// taking `@brand` into account.
+fn field_named_validator<'a>(
+    context: &'a ValidatorPrinterContext,
+    field: &'a InputValueDefinition,
+) -> impl Fn(&str) -> String + 'a {
+    move |name| {
+        let local_name = context.local_name(name);
+        match brand_of(&field.directives) {
+            // Same shape as `ts_branded`, printed on one line
+            Some(brand) => custom_validator(
+                &format!(
+                    "{} & {{ readonly __brand: {{ readonly {}: true }} }}",
+                    context.schema_type(name),
+                    print_object_key(brand.name)
+                ),
+                local_name,
+            ),
+            None => local_name.to_owned(),
+        }
+    }
+}
+
+/// Generates the validator of an input field or a variable.
+/// Nullable ones may be omitted if input nullable fields are optional.
+fn field_validator(
+    context: &ValidatorPrinterContext,
+    named_validator: &dyn Fn(&str) -> String,
+    ty: &Type,
+) -> String {
+    if let Type::NonNull(ty) = ty {
+        return non_null_validator(named_validator, &ty.r#type);
+    }
+    let validator = non_null_validator(named_validator, ty);
+    if context.options.input_nullable_field_is_optional {
+        format!("{validator}.nullish()")
+    } else {
+        format!("{validator}.nullable()")
+    }
+}
+
+/// Generates the validator of given type, ignoring the nullability of the outermost type.
+fn non_null_validator(named_validator: &dyn Fn(&str) -> String, ty: &Type) -> String {
+    match ty {
+        Type::Named(ty) => named_validator(ty.name.name),
+        Type::NonNull(ty) => non_null_validator(named_validator, &ty.r#type),
+        Type::List(ty) => {
+            let item = non_null_validator(named_validator, &ty.r#type);
+            if ty.r#type.is_nonnull() {
+                format!("z.array({item})")
+            } else {
+                format!("z.array({item}.nullable())")
+            }
+        }
+    }
+}
+
+/// Generates a validator that checks values by given validator and types them as given type.
+fn custom_validator(ts_type: &str, validator: &str) -> String {
+    format!("z.custom<{ts_type}>((value) => {validator}.safeParse(value).success)")
+}
+
+fn export_const(
+    context: &ValidatorPrinterContext,
+    name: &str,
+    type_annotation: Option<&str>,
+    validator: &str,
+    writer: &mut impl SourceMapWriter,
+) {
+    let local_name = context.local_name(name);
+    let type_annotation = type_annotation
+        .map(|ty| format!(": {ty}"))
+        .unwrap_or_default();
+    if local_name == name {
+        writeln!(
+            writer,
+            "export const {local_name}{type_annotation} = {validator};\n"
+        );
+    } else {
+        writeln!(
+            writer,
+            "const {local_name}{type_annotation} = {validator};\nexport {{ {local_name} as {name} }};\n"
+        );
+    }
+}
@@ -0,0 +1,297 @@
+#![cfg(test)]
+
+use insta::assert_snapshot;
+use nitrogql_ast::TypeSystemDocument;
+use nitrogql_config_file::{EnumStyle, GenerateConfig};
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_semantics::{resolve_operation_extensions, resolve_schema_extensions};
+use sourcemap_writer::JustWriter;
+
+use crate::validator_printer::{
+    options::ValidatorPrinterOptions,
+    printer::{ValidatorOperationDocument, ValidatorPrinter, ValidatorPrinterError},
+};
+
+fn type_system() -> TypeSystemDocument<'static> {
+    let doc = parse_type_system_document(
+        r#"
+        scalar ID
+        scalar String
+        scalar Int
+        scalar Boolean
+        scalar Date
+        scalar URL
+
+        enum UserType {
+            NormalUser
+            PremiumUser
+        }
+
+        "Condition of user search."
+        input UserSearchQuery {
+            name: String
+            type: UserType!
+            tags: [String]
+            registeredAfter: Date
+            friendOf: UserSearchQuery
+        }
+
+        input UserBy @oneOf {
+            id: ID
+            email: String @brand(name: "Email")
+        }
+
+        type User {
+            id: ID!
+            name: String!
+        }
+
+        type Query {
+            users(query: UserSearchQuery!): [User!]!
+            user(by: UserBy!): User
+        }
+        "#,
+    )
+    .unwrap();
+    resolve_schema_extensions(doc).unwrap()
+}
+
+/// Options with validators of all scalars used in inputs of `type_system()`.
+fn options() -> ValidatorPrinterOptions {
+    let mut options = ValidatorPrinterOptions::default();
+    options
+        .scalar_validators
+        .insert("Date".to_owned(), "z.coerce.date()".to_owned());
+    options
+}
+
+#[test]
+fn input_types() {
+    let printed = print_document(&type_system(), &[], options());
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn input_nullable_field_is_not_optional() {
+    let options = ValidatorPrinterOptions {
+        input_nullable_field_is_optional: false,
+        ..options()
+    };
+    let printed = print_document(&type_system(), &[], options);
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn scalar_validators() {
+    let mut options = ValidatorPrinterOptions {
+        branded_scalars: vec!["URL".to_owned()],
+        ..options()
+    };
+    options.scalar_validators.extend(vec![
+        ("Date".to_owned(), "z.instanceof(Date)".to_owned()),
+        ("URL".to_owned(), "z.string().url()".to_owned()),
+    ]);
+    let printed = print_document(&type_system(), &[], options);
+    // Date should be emitted as __tmp_Date
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn ts_enum() {
+    let options = ValidatorPrinterOptions {
+        enum_style: EnumStyle::Enum,
+        ..options()
+    };
+    let printed = print_document(&type_system(), &[], options);
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn operation_variables() {
+    let operation = parse_operation_document(
+        "
+        query searchUsers($query: UserSearchQuery!, $limit: Int) {
+            users(query: $query) { id }
+        }
+        mutation doSomething($ids: [ID!]!, $flag: Boolean) {
+            __typename
+        }
+        query {
+            __typename
+        }
+        ",
+    )
+    .unwrap();
+    let (operation, _) = resolve_operation_extensions(operation).unwrap();
+    let generate = GenerateConfig::default();
+    let printed = print_document(
+        &type_system(),
+        &[ValidatorOperationDocument {
+            document: &operation,
+            generate: &generate,
+        }],
+        options(),
+    );
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn missing_scalar_validator_in_input() {
+    let error =
+        try_print_document(&type_system(), &[], ValidatorPrinterOptions::default()).unwrap_err();
+    let ValidatorPrinterError::MissingScalarValidator { name, position, .. } = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(name, "Date");
+    // registeredAfter field of UserSearchQuery
+    assert_eq!((position.line, position.column), (18, 12));
+}
+
+#[test]
+fn missing_scalar_validator_in_variables() {
+    let schema = parse_type_system_document(
+        "
+        scalar String
+        scalar Date
+
+        type Query {
+            posts(since: Date): [String!]!
+        }
+        ",
+    )
+    .unwrap();
+    let schema = resolve_schema_extensions(schema).unwrap();
+    let operation = parse_operation_document(
+        "
+        query {
+            posts(since: \"2024-01-01\")
+        }
+        query posts($since: Date) {
+            posts(since: $since)
+        }
+        ",
+    )
+    .unwrap();
+    let (operation, _) = resolve_operation_extensions(operation).unwrap();
+    let generate = GenerateConfig::default();
+    let operations = [ValidatorOperationDocument {
+        document: &operation,
+        generate: &generate,
+    }];
+    // Unused scalars do not need validators
+    assert!(try_print_document(&schema, &[], ValidatorPrinterOptions::default()).is_ok());
+    let error =
+        try_print_document(&schema, &operations, ValidatorPrinterOptions::default()).unwrap_err();
+    let ValidatorPrinterError::MissingScalarValidator { name, position, .. } = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(name, "Date");
+    assert_eq!((position.line, position.column), (4, 20));
+}
+
+#[test]
+fn variables_validator_name_conflict_between_documents() {
+    let first = parse_operation_document("query Me { __typename }").unwrap();
+    let (first, _) = resolve_operation_extensions(first).unwrap();
+    let second = parse_operation_document("\n  query Me { __typename }").unwrap();
+    let (second, _) = resolve_operation_extensions(second).unwrap();
+    let generate = GenerateConfig::default();
+    let operations = [
+        ValidatorOperationDocument {
+            document: &first,
+            generate: &generate,
+        },
+        ValidatorOperationDocument {
+            document: &second,
+            generate: &generate,
+        },
+    ];
+    let error = try_print_document(&type_system(), &operations, options()).unwrap_err();
+    let ValidatorPrinterError::ValidatorNameConflict {
+        name,
+        position,
+        other_position,
+    } = error
+    else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(name, "MeVariables");
+    assert_eq!((position.line, position.column), (1, 8));
+    assert_eq!((other_position.line, other_position.column), (0, 6));
+}
+
+#[test]
+fn variables_validator_name_conflict_with_schema_type() {
+    let operation = parse_operation_document(
+        "
+        query UserSearch($query: UserSearchQuery!) {
+            users(query: $query) { id }
+        }
+        ",
+    )
+    .unwrap();
+    let (operation, _) = resolve_operation_extensions(operation).unwrap();
+    let mut generate = GenerateConfig::default();
+    generate.name.variables_type_suffix = Some("Query".to_owned());
+    let operations = [ValidatorOperationDocument {
+        document: &operation,
+        generate: &generate,
+    }];
+    let error = try_print_document(&type_system(), &operations, options()).unwrap_err();
+    let ValidatorPrinterError::ValidatorNameConflict {
+        name,
+        position,
+        other_position,
+    } = error
+    else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(name, "UserSearchQuery");
+    assert_eq!((position.line, position.column), (1, 14));
+    // input UserSearchQuery
+    assert_eq!((other_position.line, other_position.column), (14, 14));
+}
+
+#[test]
+fn variables_validator_name_used_in_scalar_validator() {
+    let operation = parse_operation_document("query Date($date: Date) { __typename }").unwrap();
+    let (operation, _) = resolve_operation_extensions(operation).unwrap();
+    let mut generate = GenerateConfig::default();
+    generate.name.variables_type_suffix = Some("Schema".to_owned());
+    let mut options = options();
+    options
+        .scalar_validators
+        .insert("Date".to_owned(), "DateSchema".to_owned());
+    let printed = print_document(
+        &type_system(),
+        &[ValidatorOperationDocument {
+            document: &operation,
+            generate: &generate,
+        }],
+        options,
+    );
+    // the variables validator must not shadow the identifier used by the scalar validator
+    assert!(printed.contains("export const Date = DateSchema;"));
+    assert!(printed.contains("const __tmp_DateSchema = z.object({"));
+    assert!(printed.contains("export { __tmp_DateSchema as DateSchema };"));
+}
+
+fn print_document(
+    schema: &TypeSystemDocument,
+    operations: &[ValidatorOperationDocument],
+    options: ValidatorPrinterOptions,
+) -> String {
+    try_print_document(schema, operations, options).unwrap()
+}
+
+fn try_print_document(
+    schema: &TypeSystemDocument,
+    operations: &[ValidatorOperationDocument],
+    options: ValidatorPrinterOptions,
+) -> Result<String, ValidatorPrinterError> {
+    let mut result = String::new();
+    let mut writer = JustWriter::new(&mut result);
+    let mut printer = ValidatorPrinter::new(options, &mut writer);
+    printer.print_document(schema, operations)?;
+    Ok(result)
+}
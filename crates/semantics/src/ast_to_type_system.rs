@@ -17,7 +17,10 @@ use nitrogql_ast::{
     value::{StringValue, Value},
 };
 
-use crate::type_system_utils::{convert_type, ident_to_node};
+use crate::{
+    brand_of, semantic_non_null_level_numbers,
+    type_system_utils::{convert_type, ident_to_node},
+};
 
 /// Convert TypeSystemDocument AST to type system struct.
 pub fn ast_to_type_system<'src>(
@@ -224,6 +227,8 @@ fn convert_field<'src>(field: &FieldDefinition<'src>) -> Field<Cow<'src, str>, P
         r#type: convert_type(&field.r#type),
         arguments: convert_arguments(&field.arguments),
         deprecation: convert_deprecation(&field.directives),
+        semantic_non_null_levels: semantic_non_null_level_numbers(&field.directives),
+        brand: brand_of(&field.directives).map(|brand| Cow::Owned(brand.name.to_owned())),
     }
 }
 
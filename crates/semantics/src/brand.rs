@@ -0,0 +1,29 @@
+use nitrogql_ast::{base::Pos, directive::Directive, value::Value};
+
+/// Brand given to a field definition by the `@brand` directive.
+#[derive(Copy, Clone, Debug)]
+pub struct Brand<'a> {
+    /// Position of the directive.
+    pub position: Pos,
+    /// Name of the brand.
+    pub name: &'a str,
+}
+
+/// Reads the brand given by `@brand` applied to a field definition.
+/// Returns None if the directive is not applied or its argument is not a string,
+/// as the latter is reported by the argument type check.
+pub fn brand_of<'a>(directives: &'a [Directive]) -> Option<Brand<'a>> {
+    let directive = directives.iter().find(|dir| dir.name.name == "brand")?;
+    directive
+        .arguments
+        .iter()
+        .flat_map(|args| args.arguments.iter())
+        .find(|(name, _)| name.name == "name")
+        .and_then(|(_, value)| match value {
+            Value::StringValue(string) => Some(Brand {
+                position: directive.position,
+                name: &string.value,
+            }),
+            _ => None,
+        })
+}
@@ -12,6 +12,8 @@ fn get_typename_meta_field<'a, S: From<&'a str>, D: Default>() -> Field<S, D> {
             Node::from("String", D::default()),
         ))))),
         deprecation: None,
+        semantic_non_null_levels: vec![],
+        brand: None,
     }
 }
 
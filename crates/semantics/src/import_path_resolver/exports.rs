@@ -0,0 +1,50 @@
+use serde_json::Value;
+
+use super::match_pattern;
+
+/// Conditions that are considered when resolving conditional exports, in order of priority.
+static CONDITIONS: &[&str] = &["graphql", "import", "require", "node", "default"];
+
+/// Resolves a subpath (`.` or `./path`) with the `exports` field of package.json.
+/// Returns the target path relative to the package directory.
+pub fn resolve_package_exports(exports: &Value, subpath: &str) -> Option<String> {
+    let is_subpath_map = exports
+        .as_object()
+        .is_some_and(|map| map.keys().any(|key| key.starts_with('.')));
+    if !is_subpath_map {
+        // Shorthand for `{ ".": exports }`
+        return (subpath == ".")
+            .then(|| resolve_target(exports, ""))
+            .flatten();
+    }
+    let map = exports.as_object()?;
+    if let Some(target) = map.get(subpath) {
+        return resolve_target(target, "");
+    }
+    // Like Node.js, the pattern with the longest prefix wins.
+    let (target, matched) = map
+        .iter()
+        .filter(|(key, _)| key.contains('*'))
+        .filter_map(|(key, target)| {
+            match_pattern(key, subpath).map(|matched| (key, target, matched))
+        })
+        .max_by_key(|(key, _, _)| key.find('*'))
+        .map(|(_, target, matched)| (target, matched))?;
+    resolve_target(target, matched)
+}
+
+fn resolve_target(target: &Value, matched: &str) -> Option<String> {
+    match target {
+        Value::String(target) => target
+            .starts_with("./")
+            .then(|| target.replace('*', matched)),
+        Value::Array(targets) => targets
+            .iter()
+            .find_map(|target| resolve_target(target, matched)),
+        Value::Object(conditions) => CONDITIONS
+            .iter()
+            .filter_map(|condition| conditions.get(*condition))
+            .find_map(|target| resolve_target(target, matched)),
+        _ => None,
+    }
+}
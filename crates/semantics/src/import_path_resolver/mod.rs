@@ -0,0 +1,215 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+use nitrogql_utils::{normalize_path, resolve_relative_path};
+use thiserror::Error;
+
+use self::exports::resolve_package_exports;
+
+mod exports;
+#[cfg(test)]
+mod tests;
+
+/// Result of looking up a file needed for resolving import paths.
+pub enum FileLookup<'a> {
+    /// The file exists with given content.
+    Found(Cow<'a, str>),
+    /// The file does not exist.
+    NotFound,
+    /// The file has not been loaded yet.
+    /// Used by hosts that cannot access the file system synchronously.
+    Pending,
+}
+
+/// Host that provides access to files needed for resolving import paths.
+pub trait ResolutionHost {
+    /// Looks up the file at given path.
+    fn lookup(&self, path: &Path) -> FileLookup<'_>;
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ImportPathError {
+    #[error("File '{}' is required to resolve the import path", .0.display())]
+    FileRequired(PathBuf),
+    #[error("Package '{package}' is not found")]
+    PackageNotFound { package: String },
+    #[error("Path '{subpath}' is not exported from package '{package}'")]
+    NotExported { package: String, subpath: String },
+    #[error("'{}' is not a valid package.json: {message}", path.display())]
+    InvalidPackageJson { path: PathBuf, message: String },
+    #[error("None of the paths aliased by '{pattern}' exists")]
+    AliasNotFound { pattern: String },
+}
+
+/// Resolves paths in `#import` to full paths.
+///
+/// Paths are resolved in the following order:
+///
+/// 1. Relative paths (`./` and `../`) and absolute paths are resolved against the importing file.
+/// 2. Paths that match an alias in the config are resolved against the config root.
+/// 3. Other paths are resolved as packages in `node_modules`, respecting `exports` in `package.json`.
+///    If no such package is found, the path is resolved against the importing file for compatibility.
+#[derive(Debug, Default, Clone)]
+pub struct ImportPathResolver {
+    root_dir: PathBuf,
+    /// List of (pattern, targets).
+    aliases: Vec<(String, Vec<String>)>,
+}
+
+impl ImportPathResolver {
+    /// Creates a resolver with given root directory and path aliases.
+    pub fn new(root_dir: PathBuf, aliases: &BTreeMap<String, Vec<String>>) -> Self {
+        Self {
+            root_dir,
+            aliases: aliases
+                .iter()
+                .map(|(pattern, targets)| (pattern.clone(), targets.clone()))
+                .collect(),
+        }
+    }
+
+    /// Resolves given import path in `from_file`.
+    pub fn resolve(
+        &self,
+        from_file: &Path,
+        import_path: &str,
+        host: &impl ResolutionHost,
+    ) -> Result<PathBuf, ImportPathError> {
+        if is_relative_or_absolute(import_path) {
+            return Ok(resolve_relative_path(from_file, Path::new(import_path)));
+        }
+        if let Some(result) = self.resolve_alias(import_path, host) {
+            return result;
+        }
+        match resolve_package(from_file, import_path, host) {
+            Err(ImportPathError::PackageNotFound { .. }) if !import_path.starts_with('@') => {
+                // Previously, bare paths were relative to the importing file.
+                Ok(resolve_relative_path(from_file, Path::new(import_path)))
+            }
+            result => result,
+        }
+    }
+
+    /// Resolves given import path with aliases. Returns None if no alias matches.
+    fn resolve_alias(
+        &self,
+        import_path: &str,
+        host: &impl ResolutionHost,
+    ) -> Option<Result<PathBuf, ImportPathError>> {
+        // Like TypeScript, the pattern with the longest prefix wins.
+        let (pattern, targets, matched) = self
+            .aliases
+            .iter()
+            .filter_map(|(pattern, targets)| {
+                match_pattern(pattern, import_path).map(|matched| (pattern, targets, matched))
+            })
+            .max_by_key(|(pattern, _, _)| pattern.find('*').unwrap_or(pattern.len()))?;
+        let candidates = targets
+            .iter()
+            .map(|target| normalize_path(&self.root_dir.join(target.replacen('*', matched, 1))))
+            .collect::<Vec<_>>();
+        if let [candidate] = &candidates[..] {
+            return Some(Ok(candidate.clone()));
+        }
+        for candidate in candidates {
+            match host.lookup(&candidate) {
+                FileLookup::Found(_) => return Some(Ok(candidate)),
+                FileLookup::NotFound => continue,
+                FileLookup::Pending => return Some(Err(ImportPathError::FileRequired(candidate))),
+            }
+        }
+        Some(Err(ImportPathError::AliasNotFound {
+            pattern: pattern.clone(),
+        }))
+    }
+}
+
+/// Resolves given import path as a package in `node_modules`.
+fn resolve_package(
+    from_file: &Path,
+    import_path: &str,
+    host: &impl ResolutionHost,
+) -> Result<PathBuf, ImportPathError> {
+    let (package, subpath) = split_package_path(import_path);
+    let mut dir = from_file.parent();
+    while let Some(current) = dir {
+        dir = current.parent();
+        if current.file_name() == Some("node_modules".as_ref()) {
+            continue;
+        }
+        let package_dir = current.join("node_modules").join(package);
+        let package_json_path = package_dir.join("package.json");
+        let package_json = match host.lookup(&package_json_path) {
+            FileLookup::Found(content) => content,
+            FileLookup::NotFound => continue,
+            FileLookup::Pending => return Err(ImportPathError::FileRequired(package_json_path)),
+        };
+        let package_json: serde_json::Value =
+            serde_json::from_str(&package_json).map_err(|err| {
+                ImportPathError::InvalidPackageJson {
+                    path: package_json_path.clone(),
+                    message: err.to_string(),
+                }
+            })?;
+        let target = match package_json.get("exports") {
+            None | Some(serde_json::Value::Null) => subpath.to_owned(),
+            Some(exports) => {
+                let export_subpath = if subpath.is_empty() {
+                    ".".to_owned()
+                } else {
+                    format!("./{subpath}")
+                };
+                resolve_package_exports(exports, &export_subpath).ok_or_else(|| {
+                    ImportPathError::NotExported {
+                        package: package.to_owned(),
+                        subpath: export_subpath,
+                    }
+                })?
+            }
+        };
+        return Ok(normalize_path(&package_dir.join(target)));
+    }
+    Err(ImportPathError::PackageNotFound {
+        package: package.to_owned(),
+    })
+}
+
+fn is_relative_or_absolute(import_path: &str) -> bool {
+    let path = Path::new(import_path);
+    path.is_absolute()
+        || matches!(
+            path.components().next(),
+            Some(Component::CurDir | Component::ParentDir | Component::RootDir)
+        )
+}
+
+/// Splits an import path into a package name and a subpath.
+/// `@scope/name/sub/path` is split into `@scope/name` and `sub/path`.
+fn split_package_path(import_path: &str) -> (&str, &str) {
+    let name_segments = if import_path.starts_with('@') { 2 } else { 1 };
+    let mut split_index = None;
+    for (count, (index, _)) in import_path.match_indices('/').enumerate() {
+        if count + 1 == name_segments {
+            split_index = Some(index);
+            break;
+        }
+    }
+    match split_index {
+        Some(index) => (&import_path[..index], &import_path[index + 1..]),
+        None => (import_path, ""),
+    }
+}
+
+/// Matches given path against a pattern which may contain one `*`.
+/// Returns the part matched by `*` (empty if the pattern has no `*`).
+fn match_pattern<'a>(pattern: &str, path: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        None => (pattern == path).then_some(""),
+        Some((prefix, suffix)) => path
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix)),
+    }
+}
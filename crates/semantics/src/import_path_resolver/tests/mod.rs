@@ -0,0 +1,198 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use super::{FileLookup, ImportPathError, ImportPathResolver, ResolutionHost};
+
+struct TestHost {
+    files: HashMap<PathBuf, &'static str>,
+    pending: Vec<PathBuf>,
+}
+
+impl TestHost {
+    fn new(files: &[(&str, &'static str)]) -> Self {
+        Self {
+            files: files
+                .iter()
+                .map(|(path, content)| (PathBuf::from(path), *content))
+                .collect(),
+            pending: vec![],
+        }
+    }
+}
+
+impl ResolutionHost for TestHost {
+    fn lookup(&self, path: &Path) -> FileLookup<'_> {
+        if self.pending.iter().any(|pending| pending == path) {
+            return FileLookup::Pending;
+        }
+        match self.files.get(path) {
+            Some(content) => FileLookup::Found(Cow::Borrowed(content)),
+            None => FileLookup::NotFound,
+        }
+    }
+}
+
+fn resolver() -> ImportPathResolver {
+    let aliases = BTreeMap::from([
+        ("~/*".to_owned(), vec!["./src/*".to_owned()]),
+        (
+            "@shared/*".to_owned(),
+            vec!["./shared/*".to_owned(), "./packages/shared/*".to_owned()],
+        ),
+        (
+            "@shared/special/*".to_owned(),
+            vec!["./special/*".to_owned()],
+        ),
+    ]);
+    ImportPathResolver::new(PathBuf::from("/repo"), &aliases)
+}
+
+fn test_host() -> TestHost {
+    TestHost::new(&[
+        ("/repo/packages/shared/user.graphql", ""),
+        (
+            "/repo/node_modules/@acme/ui-fragments/package.json",
+            r#"{
+                "name": "@acme/ui-fragments",
+                "exports": {
+                    ".": "./index.graphql",
+                    "./*.graphql": {
+                        "types": "./types/*.d.ts",
+                        "graphql": "./src/*.graphql"
+                    },
+                    "./internal/*": null
+                }
+            }"#,
+        ),
+        (
+            "/repo/app/node_modules/plain/package.json",
+            r#"{ "name": "plain" }"#,
+        ),
+        ("/repo/node_modules/broken/package.json", "{"),
+    ])
+}
+
+fn resolve(import_path: &str) -> Result<PathBuf, ImportPathError> {
+    resolver().resolve(
+        Path::new("/repo/app/src/main.graphql"),
+        import_path,
+        &test_host(),
+    )
+}
+
+#[test]
+fn relative_path() {
+    assert_eq!(
+        resolve("./frag.graphql"),
+        Ok(PathBuf::from("/repo/app/src/frag.graphql"))
+    );
+    assert_eq!(
+        resolve("../frag.graphql"),
+        Ok(PathBuf::from("/repo/app/frag.graphql"))
+    );
+    assert_eq!(
+        resolve("/abs/frag.graphql"),
+        Ok(PathBuf::from("/abs/frag.graphql"))
+    );
+}
+
+#[test]
+fn alias() {
+    assert_eq!(
+        resolve("~/fragments/user.graphql"),
+        Ok(PathBuf::from("/repo/src/fragments/user.graphql"))
+    );
+    // second candidate exists
+    assert_eq!(
+        resolve("@shared/user.graphql"),
+        Ok(PathBuf::from("/repo/packages/shared/user.graphql"))
+    );
+    // longest prefix wins
+    assert_eq!(
+        resolve("@shared/special/user.graphql"),
+        Ok(PathBuf::from("/repo/special/user.graphql"))
+    );
+    assert_eq!(
+        resolve("@shared/nonexistent.graphql"),
+        Err(ImportPathError::AliasNotFound {
+            pattern: "@shared/*".to_owned()
+        })
+    );
+}
+
+#[test]
+fn package_exports() {
+    assert_eq!(
+        resolve("@acme/ui-fragments/user.graphql"),
+        Ok(PathBuf::from(
+            "/repo/node_modules/@acme/ui-fragments/src/user.graphql"
+        ))
+    );
+    assert_eq!(
+        resolve("@acme/ui-fragments"),
+        Ok(PathBuf::from(
+            "/repo/node_modules/@acme/ui-fragments/index.graphql"
+        ))
+    );
+    assert_eq!(
+        resolve("@acme/ui-fragments/internal/user.graphql"),
+        Err(ImportPathError::NotExported {
+            package: "@acme/ui-fragments".to_owned(),
+            subpath: "./internal/user.graphql".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn package_without_exports() {
+    // nearest node_modules is used
+    assert_eq!(
+        resolve("plain/fragments/user.graphql"),
+        Ok(PathBuf::from(
+            "/repo/app/node_modules/plain/fragments/user.graphql"
+        ))
+    );
+}
+
+#[test]
+fn package_not_found() {
+    assert_eq!(
+        resolve("@acme/nonexistent/user.graphql"),
+        Err(ImportPathError::PackageNotFound {
+            package: "@acme/nonexistent".to_owned()
+        })
+    );
+    // unscoped paths fall back to relative paths
+    assert_eq!(
+        resolve("frag.graphql"),
+        Ok(PathBuf::from("/repo/app/src/frag.graphql"))
+    );
+}
+
+#[test]
+fn invalid_package_json() {
+    assert!(matches!(
+        resolve("broken/user.graphql"),
+        Err(ImportPathError::InvalidPackageJson { .. })
+    ));
+}
+
+#[test]
+fn pending_file() {
+    let mut host = test_host();
+    host.pending
+        .push(PathBuf::from("/repo/app/node_modules/plain/package.json"));
+    assert_eq!(
+        resolver().resolve(
+            Path::new("/repo/app/src/main.graphql"),
+            "plain/user.graphql",
+            &host,
+        ),
+        Err(ImportPathError::FileRequired(PathBuf::from(
+            "/repo/app/node_modules/plain/package.json"
+        )))
+    );
+}
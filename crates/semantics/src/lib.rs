@@ -1,21 +1,39 @@
 mod ast_to_type_system;
+mod brand;
 mod definition_map;
 mod direct_fields_of_output_type;
+mod import_path_resolver;
 mod operation_extension_resolver;
+mod operation_import_checker;
 mod operation_import_resolver;
 mod schema_extension_resolver;
+mod schema_import_resolver;
+mod semantic_non_null;
 #[cfg(test)]
 mod tests;
 mod type_system_to_ast;
 pub mod type_system_utils;
+mod typename_inserter;
 
 pub use ast_to_type_system::ast_to_type_system;
+pub use brand::{Brand, brand_of};
 pub use definition_map::{DefinitionMap, generate_definition_map};
 pub use direct_fields_of_output_type::direct_fields_of_output_type;
+pub use import_path_resolver::{FileLookup, ImportPathError, ImportPathResolver, ResolutionHost};
 pub use operation_extension_resolver::{
     operation_extension::{Import, ImportTargets, OperationExtension},
     resolve_operation_extensions,
 };
+pub use operation_import_checker::{
+    ImportCheckOptions, ReexportOptions, check_operation_imports, check_reexported_operations,
+};
 pub use operation_import_resolver::{OperationResolver, resolve_operation_imports};
 pub use schema_extension_resolver::resolve_schema_extensions;
+pub use schema_import_resolver::{
+    SchemaImportError, SchemaImportErrorMessage, SchemaResolver, resolve_schema_imports,
+};
+pub use semantic_non_null::{
+    SemanticNonNullLevel, semantic_non_null_level_numbers, semantic_non_null_levels,
+};
 pub use type_system_to_ast::type_system_to_ast;
+pub use typename_inserter::add_typename;
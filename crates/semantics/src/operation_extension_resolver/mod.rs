@@ -2,9 +2,9 @@ use nitrogql_ast::{
     OperationDocument, OperationDocumentExt,
     base::Pos,
     operation::ExecutableDefinition,
-    operation_ext::{ExecutableDefinitionExt, ImportTarget},
+    operation_ext::{ExecutableDefinitionExt, ImportDefinition, ImportTarget},
 };
-use nitrogql_error::PositionedError;
+use nitrogql_error::{PositionedError, codes};
 use thiserror::Error;
 
 use self::operation_extension::{Import, ImportTargets, OperationExtension};
@@ -25,58 +25,11 @@ pub fn resolve_operation_extensions(
                 definitions.push(ExecutableDefinition::FragmentDefinition(def));
             }
             ExecutableDefinitionExt::Import(import) => {
-                let existing = imports
-                    .iter()
-                    .position(|i: &Import| i.path.value == import.path.value);
-                let initial = if let Some(existing) = existing {
-                    imports.remove(existing).targets
-                } else {
-                    ImportTargets::Specific(vec![])
-                };
-
-                let targets = import.targets.into_iter().try_fold(
-                    initial,
-                    |acc, target| {
-                        match (acc, target) {
-                            (ImportTargets::Wildcard, ImportTarget::Wildcard) => {
-                                Err(ExtensionError {
-                                    message: ExtensionErrorMessage::WildcardOnlyOnce {
-                                        pos: import.position,
-                                    },
-                                })
-                            }
-                            (ImportTargets::Wildcard, ImportTarget::Name(_)) => {
-                                Err(ExtensionError {
-                                    message: ExtensionErrorMessage::WildcardCannotBeCombinedWithSpecific {
-                                        pos: import.position,
-                                    },
-                                })
-                            }
-                            (ImportTargets::Specific(idents), ImportTarget::Wildcard) => {
-                                if idents.is_empty() {
-                                    Ok(ImportTargets::Wildcard)
-                                } else {
-                                    Err(ExtensionError {
-                                        message: ExtensionErrorMessage::WildcardCannotBeCombinedWithSpecific {
-                                            pos: import.position,
-                                        },
-                                    })
-                                }
-                            }
-                            (ImportTargets::Specific(mut idents), ImportTarget::Name(ident)) => {
-                                idents.push(ident);
-                                Ok(ImportTargets::Specific(idents))
-                            }
-                        }
-                    },
-                );
-                imports.push(Import {
-                    path: import.path,
-                    targets: targets?,
-                });
+                imports.push(import);
             }
         }
     }
+    let imports = resolve_import_definitions(imports)?;
     Ok((
         OperationDocument {
             definitions,
@@ -86,6 +39,61 @@ pub fn resolve_operation_extensions(
     ))
 }
 
+/// Merges `#import` statements by path, checking the usage of wildcards.
+pub fn resolve_import_definitions(
+    definitions: Vec<ImportDefinition>,
+) -> Result<Vec<Import>, ExtensionError> {
+    let mut imports = vec![];
+    for import in definitions {
+        let existing = imports
+            .iter()
+            .position(|i: &Import| i.path.value == import.path.value);
+        let initial = if let Some(existing) = existing {
+            imports.remove(existing).targets
+        } else {
+            ImportTargets::Specific(vec![])
+        };
+
+        let targets =
+            import
+                .targets
+                .into_iter()
+                .try_fold(initial, |acc, target| match (acc, target) {
+                    (ImportTargets::Wildcard, ImportTarget::Wildcard) => Err(ExtensionError {
+                        message: ExtensionErrorMessage::WildcardOnlyOnce {
+                            pos: import.position,
+                        },
+                    }),
+                    (ImportTargets::Wildcard, ImportTarget::Name(_)) => Err(ExtensionError {
+                        message: ExtensionErrorMessage::WildcardCannotBeCombinedWithSpecific {
+                            pos: import.position,
+                        },
+                    }),
+                    (ImportTargets::Specific(idents), ImportTarget::Wildcard) => {
+                        if idents.is_empty() {
+                            Ok(ImportTargets::Wildcard)
+                        } else {
+                            Err(ExtensionError {
+                                message:
+                                    ExtensionErrorMessage::WildcardCannotBeCombinedWithSpecific {
+                                        pos: import.position,
+                                    },
+                            })
+                        }
+                    }
+                    (ImportTargets::Specific(mut idents), ImportTarget::Name(ident)) => {
+                        idents.push(ident);
+                        Ok(ImportTargets::Specific(idents))
+                    }
+                });
+        imports.push(Import {
+            path: import.path,
+            targets: targets?,
+        });
+    }
+    Ok(imports)
+}
+
 #[derive(Error, Debug)]
 pub enum ExtensionErrorMessage {
     #[error("Wildcard import should be specified only once")]
@@ -109,7 +117,13 @@ impl From<ExtensionError> for PositionedError {
             ExtensionErrorMessage::WildcardOnlyOnce { .. } => vec![],
             ExtensionErrorMessage::WildcardCannotBeCombinedWithSpecific { .. } => vec![],
         };
+        let code = match &value.message {
+            ExtensionErrorMessage::WildcardOnlyOnce { .. } => codes::WILDCARD_IMPORT_ONLY_ONCE,
+            ExtensionErrorMessage::WildcardCannotBeCombinedWithSpecific { .. } => {
+                codes::WILDCARD_IMPORT_WITH_SPECIFIC
+            }
+        };
 
-        PositionedError::new(value.message.into(), Some(position), additional_info)
+        PositionedError::new(value.message.into(), Some(position), additional_info).with_code(code)
     }
 }
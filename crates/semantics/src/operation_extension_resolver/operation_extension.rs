@@ -1,12 +1,24 @@
 use nitrogql_ast::{base::Ident, value::StringValue};
 
 /// Resolved extension to operation document.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct OperationExtension<'src> {
     /// List of imports.
     pub imports: Vec<Import<'src>>,
 }
 
+impl OperationExtension<'_> {
+    /// Returns the path of the import that imports given definition by name.
+    pub fn import_path_of(&self, name: &str) -> Option<&str> {
+        self.imports.iter().find_map(|import| match import.targets {
+            ImportTargets::Specific(ref targets) if targets.iter().any(|t| t.name == name) => {
+                Some(import.path.value.as_str())
+            }
+            _ => None,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Import<'src> {
     /// Path to import from. (not resolved)
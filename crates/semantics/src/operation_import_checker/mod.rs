@@ -0,0 +1,372 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use nitrogql_ast::{
+    OperationDocument,
+    base::Pos,
+    operation::ExecutableDefinition,
+    selection_set::{Selection, SelectionSet},
+};
+use nitrogql_error::{PositionedError, codes};
+use nitrogql_utils::relative_path;
+use thiserror::Error;
+
+use crate::{ImportTargets, OperationExtension, OperationResolver};
+
+#[cfg(test)]
+mod tests;
+
+/// Options for checking `#import` statements.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImportCheckOptions {
+    /// Whether import cycles are reported.
+    pub no_import_cycles: bool,
+    /// Whether unused imports are reported.
+    pub no_unused_imports: bool,
+}
+
+/// Options of the generated code, used to check re-exports of imported operations.
+#[derive(Debug, Clone, Copy)]
+pub struct ReexportOptions {
+    /// Whether an operation is default exported when it is the only operation in the document.
+    pub default_export_for_operation: bool,
+    /// Whether an operation is named exported.
+    pub named_export_for_operation: bool,
+}
+
+/// Checks `#import` statements in the given documents.
+/// Depending on `options`, reports imported fragments that are never spread in the document,
+/// wildcard imports that contribute nothing and import cycles.
+/// Each import cycle is reported once, from the first given document that is in the cycle.
+///
+/// Imports that cannot be resolved are not reported here;
+/// they are reported by `resolve_operation_imports`.
+pub fn check_operation_imports<'a, 'src: 'a>(
+    documents: impl IntoIterator<
+        Item = (
+            &'a Path,
+            &'a OperationDocument<'src>,
+            &'a OperationExtension<'src>,
+        ),
+    >,
+    operation_resolver: &impl OperationResolver<'src>,
+    options: ImportCheckOptions,
+) -> Vec<PositionedError> {
+    let mut errors = vec![];
+    let mut reported_cycles = HashSet::new();
+    for document in documents {
+        if options.no_unused_imports {
+            errors.extend(check_unused_imports(document, operation_resolver));
+        }
+        if options.no_import_cycles {
+            errors.extend(check_import_cycles(
+                document.0,
+                operation_resolver,
+                &mut reported_cycles,
+            ));
+        }
+    }
+    errors.into_iter().map(Into::into).collect()
+}
+
+/// Checks that operations imported by the given document can be re-exported.
+/// Imported files are assumed to be generated with the same options as the document,
+/// so an operation without a named export is available only as the default export
+/// of a file that has exactly one operation.
+///
+/// Imports that cannot be resolved are not reported here;
+/// they are reported by `resolve_operation_imports`.
+pub fn check_reexported_operations<'src>(
+    document: (&Path, &OperationExtension<'src>),
+    operation_resolver: &impl OperationResolver<'src>,
+    options: ReexportOptions,
+) -> Vec<PositionedError> {
+    if options.named_export_for_operation {
+        return vec![];
+    }
+    let (document_path, extensions) = document;
+    let mut errors = vec![];
+    for import in extensions.imports.iter() {
+        let ImportTargets::Specific(ref targets) = import.targets else {
+            continue;
+        };
+        let Some((imported_doc, _)) = operation_resolver
+            .resolve_import_path(document_path, &import.path.value)
+            .ok()
+            .and_then(|imported_path| operation_resolver.resolve(&imported_path))
+        else {
+            continue;
+        };
+        let operation_names = imported_doc
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                ExecutableDefinition::OperationDefinition(op) => Some(op.name),
+                ExecutableDefinition::FragmentDefinition(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if options.default_export_for_operation && operation_names.len() == 1 {
+            continue;
+        }
+        for target in targets {
+            if operation_names
+                .iter()
+                .any(|name| name.is_some_and(|name| name.name == target.name))
+            {
+                errors.push(ImportCheckError {
+                    message: ImportCheckErrorMessage::ImportedOperationNotExported {
+                        name: target.name.to_owned(),
+                        file: import.path.value.clone(),
+                        operation_count: operation_names.len(),
+                        position: target.position,
+                    },
+                });
+            }
+        }
+    }
+    errors.into_iter().map(Into::into).collect()
+}
+
+fn check_unused_imports<'src>(
+    document: (&Path, &OperationDocument<'src>, &OperationExtension<'src>),
+    operation_resolver: &impl OperationResolver<'src>,
+) -> Vec<ImportCheckError> {
+    let (document_path, document, extensions) = document;
+    let mut spread_names = HashSet::new();
+    for def in document.definitions.iter() {
+        let selection_set = match def {
+            ExecutableDefinition::OperationDefinition(op) => &op.selection_set,
+            ExecutableDefinition::FragmentDefinition(fragment) => &fragment.selection_set,
+        };
+        collect_spread_names(selection_set, &mut spread_names);
+    }
+
+    let mut errors = vec![];
+    for import in extensions.imports.iter() {
+        let Some((imported_doc, _)) = operation_resolver
+            .resolve_import_path(document_path, &import.path.value)
+            .ok()
+            .and_then(|imported_path| operation_resolver.resolve(&imported_path))
+        else {
+            continue;
+        };
+        let is_imported_fragment = |name: &str| {
+            imported_doc.definitions.iter().any(|def| match def {
+                ExecutableDefinition::FragmentDefinition(fragment) => fragment.name.name == name,
+                ExecutableDefinition::OperationDefinition(_) => false,
+            })
+        };
+        match import.targets {
+            ImportTargets::Wildcard => {
+                if !spread_names.iter().any(|name| is_imported_fragment(name)) {
+                    errors.push(ImportCheckError {
+                        message: ImportCheckErrorMessage::UnusedWildcardImport {
+                            file: import.path.value.clone(),
+                            position: import.path.position,
+                        },
+                    });
+                }
+            }
+            ImportTargets::Specific(ref targets) => {
+                // Imported operations are re-exported, so they are always used.
+                for target in targets {
+                    if is_imported_fragment(target.name) && !spread_names.contains(target.name) {
+                        errors.push(ImportCheckError {
+                            message: ImportCheckErrorMessage::UnusedImport {
+                                name: target.name.to_owned(),
+                                file: import.path.value.clone(),
+                                position: target.position,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+    }
+    errors
+}
+
+fn collect_spread_names<'a>(selection_set: &SelectionSet<'a>, names: &mut HashSet<&'a str>) {
+    for selection in selection_set.selections.iter() {
+        match selection {
+            Selection::Field(field) => {
+                if let Some(selection_set) = field.selection_set.as_ref() {
+                    collect_spread_names(selection_set, names);
+                }
+            }
+            Selection::FragmentSpread(spread) => {
+                names.insert(spread.fragment_name.name);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                collect_spread_names(&inline_fragment.selection_set, names);
+            }
+        }
+    }
+}
+
+/// Reports import cycles that go through given document.
+/// Cycles already in `reported_cycles` are skipped. Cycles are stored rotated so that
+/// they start with the smallest path, so the same cycle found from another file is equal.
+fn check_import_cycles<'src>(
+    document_path: &Path,
+    operation_resolver: &impl OperationResolver<'src>,
+    reported_cycles: &mut HashSet<Vec<PathBuf>>,
+) -> Vec<ImportCheckError> {
+    let Some((_, extensions)) = operation_resolver.resolve(document_path) else {
+        return vec![];
+    };
+    let mut errors = vec![];
+    for import in extensions.imports.iter() {
+        let Ok(imported_path) =
+            operation_resolver.resolve_import_path(document_path, &import.path.value)
+        else {
+            continue;
+        };
+        let Some(rest) = find_import_chain(
+            &imported_path,
+            document_path,
+            operation_resolver,
+            &mut HashSet::new(),
+        ) else {
+            continue;
+        };
+        // Files that import the next one, ending with the document itself
+        let importers = std::iter::once(imported_path.as_path())
+            .chain(rest.iter().map(|(path, _)| path.as_path()))
+            .collect::<Vec<_>>();
+        let mut normalized = importers
+            .iter()
+            .map(|path| path.to_path_buf())
+            .collect::<Vec<_>>();
+        let start = normalized
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, path)| *path)
+            .map_or(0, |(index, _)| index);
+        normalized.rotate_left(start);
+        if !reported_cycles.insert(normalized) {
+            continue;
+        }
+        let display = |path: &Path| relative_path(document_path, path).display().to_string();
+        let cycle = std::iter::once(display(document_path))
+            .chain(importers.iter().map(|path| display(path)))
+            .collect();
+        let imports = rest
+            .iter()
+            .zip(importers.iter())
+            .map(|((path, position), from)| (*position, display(from), display(path)))
+            .collect();
+        errors.push(ImportCheckError {
+            message: ImportCheckErrorMessage::ImportCycle {
+                cycle,
+                position: import.path.position,
+                imports,
+            },
+        });
+    }
+    errors
+}
+
+/// Finds a chain of imports from `from` to `target`.
+/// Returns the list of (imported file, position of the import) for each step.
+fn find_import_chain<'src>(
+    from: &Path,
+    target: &Path,
+    operation_resolver: &impl OperationResolver<'src>,
+    visited: &mut HashSet<PathBuf>,
+) -> Option<Vec<(PathBuf, Pos)>> {
+    if from == target {
+        return Some(vec![]);
+    }
+    if !visited.insert(from.to_owned()) {
+        return None;
+    }
+    let (_, extensions) = operation_resolver.resolve(from)?;
+    for import in extensions.imports.iter() {
+        let Ok(imported_path) = operation_resolver.resolve_import_path(from, &import.path.value)
+        else {
+            continue;
+        };
+        if let Some(mut chain) =
+            find_import_chain(&imported_path, target, operation_resolver, visited)
+        {
+            chain.insert(0, (imported_path, import.path.position));
+            return Some(chain);
+        }
+    }
+    None
+}
+
+#[derive(Error, Debug)]
+pub enum ImportCheckErrorMessage {
+    #[error("'{name}' is imported from '{file}' but never used.")]
+    UnusedImport {
+        name: String,
+        file: String,
+        position: Pos,
+    },
+    #[error("Nothing imported from '{file}' is used.")]
+    UnusedWildcardImport { file: String, position: Pos },
+    #[error("Import cycle detected: {}.", cycle.join(" -> "))]
+    ImportCycle {
+        /// Files in the cycle, starting and ending with the importing file.
+        cycle: Vec<String>,
+        position: Pos,
+        /// Imports in other files that form the cycle: (position, importing file, imported file).
+        imports: Vec<(Pos, String, String)>,
+    },
+    #[error(
+        "Operation '{name}' cannot be re-exported because '{file}' has {operation_count} operations and its generated code exports none of them."
+    )]
+    ImportedOperationNotExported {
+        name: String,
+        file: String,
+        operation_count: usize,
+        position: Pos,
+    },
+}
+
+#[derive(Debug)]
+pub struct ImportCheckError {
+    pub message: ImportCheckErrorMessage,
+}
+
+impl From<ImportCheckError> for PositionedError {
+    fn from(value: ImportCheckError) -> Self {
+        let position = match &value.message {
+            ImportCheckErrorMessage::UnusedImport { position, .. } => *position,
+            ImportCheckErrorMessage::UnusedWildcardImport { position, .. } => *position,
+            ImportCheckErrorMessage::ImportCycle { position, .. } => *position,
+            ImportCheckErrorMessage::ImportedOperationNotExported { position, .. } => *position,
+        };
+        let additional_info = match &value.message {
+            ImportCheckErrorMessage::ImportCycle { imports, .. } => imports
+                .iter()
+                .map(|(position, from, to)| (*position, format!("'{from}' imports '{to}'")))
+                .collect(),
+            _ => vec![],
+        };
+        let (code, fields) = match &value.message {
+            ImportCheckErrorMessage::UnusedImport { name, file, .. } => (
+                codes::UNUSED_IMPORT,
+                vec![("name", name.clone()), ("file", file.clone())],
+            ),
+            ImportCheckErrorMessage::UnusedWildcardImport { file, .. } => {
+                (codes::UNUSED_IMPORT, vec![("file", file.clone())])
+            }
+            ImportCheckErrorMessage::ImportCycle { cycle, .. } => {
+                (codes::IMPORT_CYCLE, vec![("cycle", cycle.join(" -> "))])
+            }
+            ImportCheckErrorMessage::ImportedOperationNotExported { name, file, .. } => (
+                codes::IMPORTED_OPERATION_NOT_EXPORTED,
+                vec![("name", name.clone()), ("file", file.clone())],
+            ),
+        };
+
+        PositionedError::new(value.message.into(), Some(position), additional_info)
+            .with_code(code)
+            .with_fields(fields)
+    }
+}
@@ -0,0 +1,283 @@
+use std::path::Path;
+
+use insta::assert_debug_snapshot;
+use nitrogql_ast::OperationDocument;
+use nitrogql_error::PositionedError;
+use nitrogql_parser::parse_operation_document;
+
+use crate::{
+    ImportCheckOptions, OperationExtension, OperationResolver, ReexportOptions,
+    check_operation_imports, check_reexported_operations, resolve_operation_extensions,
+};
+
+struct TestOperationResolver;
+impl OperationResolver<'static> for TestOperationResolver {
+    fn resolve(
+        &self,
+        path: &Path,
+    ) -> Option<(&OperationDocument<'static>, &OperationExtension<'static>)> {
+        match path.to_str().unwrap() {
+            "/path/to/frags.graphql" => Some(static_parse(
+                r#"
+fragment Frag1 on Foo { bar }
+fragment Frag2 on Foo { baz }
+query Query1 { foo { ...Frag1 } }
+"#,
+            )),
+            "/path/to/empty.graphql" => Some(static_parse(
+                r#"
+query Query2 { foo { bar } }
+"#,
+            )),
+            "/path/to/queries.graphql" => Some(static_parse(
+                r#"
+query Query3 { foo { bar } }
+query Query4 { foo { baz } }
+"#,
+            )),
+            "/path/to/cycle/a.graphql" => Some(static_parse(
+                r#"
+#import B from "./b.graphql"
+fragment A on Foo { bar ...B }
+"#,
+            )),
+            "/path/to/cycle/b.graphql" => Some(static_parse(
+                r#"
+#import C from "./c.graphql"
+fragment B on Foo { bar ...C }
+"#,
+            )),
+            "/path/to/cycle/c.graphql" => Some(static_parse(
+                r#"
+#import A from "./a.graphql"
+fragment C on Foo { bar ...A }
+"#,
+            )),
+            "/path/to/shortcut/a.graphql" => Some(static_parse(
+                r#"
+#import B from "./b.graphql"
+#import C from "./c.graphql"
+fragment A on Foo { bar ...B ...C }
+"#,
+            )),
+            "/path/to/shortcut/b.graphql" => Some(static_parse(
+                r#"
+#import C from "./c.graphql"
+fragment B on Foo { bar ...C }
+"#,
+            )),
+            "/path/to/shortcut/c.graphql" => Some(static_parse(
+                r#"
+#import A from "./a.graphql"
+fragment C on Foo { bar ...A }
+"#,
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn static_parse(
+    code: &'static str,
+) -> (
+    &'static OperationDocument<'static>,
+    &'static OperationExtension<'static>,
+) {
+    let doc = parse_operation_document(code).unwrap();
+    let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+    let (doc, extensions) = (Box::leak(Box::new(doc)), Box::leak(Box::new(extensions)));
+    (doc, extensions)
+}
+
+fn check(path: &str, code: &'static str, options: ImportCheckOptions) -> Vec<PositionedError> {
+    let (doc, extensions) = static_parse(code);
+    check_operation_imports(
+        [(Path::new(path), doc, extensions)],
+        &TestOperationResolver,
+        options,
+    )
+}
+
+fn check_files(paths: &[&str], options: ImportCheckOptions) -> Vec<PositionedError> {
+    let documents = paths.iter().map(|path| {
+        let path = Path::new(path);
+        let (doc, extensions) = TestOperationResolver.resolve(path).unwrap();
+        (path, doc, extensions)
+    });
+    check_operation_imports(documents, &TestOperationResolver, options)
+}
+
+fn check_reexports(code: &'static str, options: ReexportOptions) -> Vec<PositionedError> {
+    let (_, extensions) = static_parse(code);
+    check_reexported_operations(
+        (Path::new("/path/to/main.graphql"), extensions),
+        &TestOperationResolver,
+        options,
+    )
+}
+
+/// Returns the `cycle` field of each error.
+fn cycles(errors: &[PositionedError]) -> Vec<&str> {
+    errors
+        .iter()
+        .flat_map(|error| error.fields())
+        .filter(|(key, _)| *key == "cycle")
+        .map(|(_, value)| value.as_str())
+        .collect()
+}
+
+const UNUSED_IMPORTS: ImportCheckOptions = ImportCheckOptions {
+    no_import_cycles: false,
+    no_unused_imports: true,
+};
+
+const IMPORT_CYCLES: ImportCheckOptions = ImportCheckOptions {
+    no_import_cycles: true,
+    no_unused_imports: false,
+};
+
+const DEFAULT_EXPORT: ReexportOptions = ReexportOptions {
+    default_export_for_operation: true,
+    named_export_for_operation: false,
+};
+
+const NAMED_EXPORT: ReexportOptions = ReexportOptions {
+    default_export_for_operation: false,
+    named_export_for_operation: true,
+};
+
+#[test]
+fn all_imports_used() {
+    let errors = check(
+        "/path/to/main.graphql",
+        r#"
+#import * from "./frags.graphql"
+#import Query2 from "./empty.graphql"
+query Foo { foo { ...Frag1 ...Frag2 } }
+"#,
+        ImportCheckOptions::default(),
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unused_import_allowed_by_default() {
+    let errors = check(
+        "/path/to/main.graphql",
+        r#"
+#import Frag1, Frag2 from "./frags.graphql"
+query Foo { foo { ...Frag1 } }
+"#,
+        ImportCheckOptions::default(),
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unused_import() {
+    let errors = check(
+        "/path/to/main.graphql",
+        r#"
+#import Frag1, Frag2 from "./frags.graphql"
+query Foo { foo { ...Frag1 } }
+"#,
+        UNUSED_IMPORTS,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn unused_wildcard_import() {
+    let errors = check(
+        "/path/to/main.graphql",
+        r#"
+#import * from "./empty.graphql"
+#import Frag1 from "./frags.graphql"
+query Foo { foo { ...Frag1 } }
+"#,
+        UNUSED_IMPORTS,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn import_cycle_allowed_by_default() {
+    let errors = check_files(&["/path/to/cycle/a.graphql"], ImportCheckOptions::default());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn import_cycle() {
+    let errors = check_files(
+        &[
+            "/path/to/cycle/a.graphql",
+            "/path/to/cycle/b.graphql",
+            "/path/to/cycle/c.graphql",
+        ],
+        IMPORT_CYCLES,
+    );
+    // reported only once for the whole cycle
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn import_cycle_through_unchecked_file() {
+    // a.graphql is not checked (e.g. outside `documents`)
+    let errors = check_files(
+        &["/path/to/cycle/b.graphql", "/path/to/cycle/c.graphql"],
+        IMPORT_CYCLES,
+    );
+    assert_eq!(
+        cycles(&errors),
+        vec!["./b.graphql -> ./c.graphql -> ./a.graphql -> ./b.graphql"]
+    );
+}
+
+#[test]
+fn import_cycles_sharing_files() {
+    // a -> b -> c -> a and a -> c -> a
+    let errors = check_files(&["/path/to/shortcut/a.graphql"], IMPORT_CYCLES);
+    assert_eq!(
+        cycles(&errors),
+        vec![
+            "./a.graphql -> ./b.graphql -> ./c.graphql -> ./a.graphql",
+            "./a.graphql -> ./c.graphql -> ./a.graphql",
+        ]
+    );
+}
+
+#[test]
+fn reexport_default_exported_operation() {
+    let errors = check_reexports(
+        r#"
+#import Query2 from "./empty.graphql"
+#import Frag1, Query1 from "./frags.graphql"
+"#,
+        DEFAULT_EXPORT,
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn reexport_from_file_with_two_operations() {
+    let errors = check_reexports(
+        r#"
+#import Query3, Query4 from "./queries.graphql"
+query Foo { foo { bar } }
+"#,
+        DEFAULT_EXPORT,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn reexport_named_exported_operations() {
+    let errors = check_reexports(
+        r#"
+#import Query3, Query4 from "./queries.graphql"
+#import * from "./frags.graphql"
+"#,
+        NAMED_EXPORT,
+    );
+    assert!(errors.is_empty());
+}
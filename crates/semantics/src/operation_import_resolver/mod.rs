@@ -4,9 +4,14 @@ use std::{
 };
 
 use log::debug;
-use nitrogql_ast::{OperationDocument, base::Pos, operation::ExecutableDefinition};
-use nitrogql_error::PositionedError;
-use nitrogql_utils::resolve_relative_path;
+use nitrogql_ast::{
+    OperationDocument,
+    base::{HasPos, Pos},
+    operation::ExecutableDefinition,
+    selection_set::{Selection, SelectionSet},
+};
+use nitrogql_error::{PositionedError, codes};
+use nitrogql_utils::did_you_mean;
 use thiserror::Error;
 
 use crate::{ImportTargets, OperationExtension};
@@ -18,15 +23,24 @@ mod path_resolver;
 mod tests;
 
 /// Resolves operation imports in the given document.
-/// Currently, only Fragments can be imported and
-/// imported fragments are appended to the document.
+/// Imported fragments and operations are appended to the document,
+/// together with fragments they depend on.
+/// Wildcard imports only import fragments.
+/// Operations are only imported by imports in the given document itself;
+/// operations imported by imported files are not.
 pub fn resolve_operation_imports<'src>(
     document: (&Path, &OperationDocument<'src>, &OperationExtension<'src>),
     operation_resolver: &impl OperationResolver<'src>,
 ) -> Result<OperationDocument<'src>, ExtensionError> {
     let mut definitions: Vec<_> = document.1.definitions.to_vec();
     let mut visited = HashSet::new();
-    resolve_operation_imports_rec(document, operation_resolver, &mut visited, &mut definitions)?;
+    resolve_operation_imports_rec(
+        document,
+        operation_resolver,
+        true,
+        &mut visited,
+        &mut definitions,
+    )?;
     let result = OperationDocument {
         definitions,
         position: document.1.position,
@@ -37,15 +51,21 @@ pub fn resolve_operation_imports<'src>(
 fn resolve_operation_imports_rec<'src>(
     document: (&Path, &OperationDocument<'src>, &OperationExtension<'src>),
     operation_resolver: &impl OperationResolver<'src>,
+    import_operations: bool,
     visited: &mut HashSet<PathBuf>,
     definitions: &mut Vec<ExecutableDefinition<'src>>,
 ) -> Result<(), ExtensionError> {
     let (document_path, _, extensions) = document;
     for import in extensions.imports.iter() {
-        let imported_path = resolve_relative_path(document_path, Path::new(&import.path.value));
-        if visited.contains(&imported_path) {
-            continue;
-        }
+        let imported_path = operation_resolver
+            .resolve_import_path(document_path, &import.path.value)
+            .map_err(|err| ExtensionError {
+                message: ExtensionErrorMessage::UnresolvedImport {
+                    file: import.path.value.clone(),
+                    reason: err.to_string(),
+                    position: import.path.position,
+                },
+            })?;
         let Some(imported_op) = operation_resolver.resolve(&imported_path) else {
             return Err(ExtensionError {
                 message: ExtensionErrorMessage::FileNotFound {
@@ -54,79 +74,200 @@ fn resolve_operation_imports_rec<'src>(
                 },
             });
         };
-        visited.insert(imported_path.clone());
-        resolve_operation_imports_rec(
-            (&imported_path, imported_op.0, imported_op.1),
-            operation_resolver,
-            visited,
-            definitions,
-        )?;
+        // A file may be imported both directly and through other files,
+        // so `visited` only prevents resolving its imports again.
+        if visited.insert(imported_path.clone()) {
+            resolve_operation_imports_rec(
+                (&imported_path, imported_op.0, imported_op.1),
+                operation_resolver,
+                false,
+                visited,
+                definitions,
+            )?;
+        }
         match import.targets {
             ImportTargets::Wildcard => {
-                definitions.extend(
-                    imported_op
-                        .0
-                        .definitions
-                        .iter()
-                        .filter(|def| matches!(def, ExecutableDefinition::FragmentDefinition(_)))
-                        .cloned(),
-                );
+                for def in imported_op.0.definitions.iter() {
+                    if matches!(def, ExecutableDefinition::FragmentDefinition(_)) {
+                        push_definition(definitions, def);
+                    }
+                }
             }
             ImportTargets::Specific(ref targets) => {
-                let mut count = 0;
-                let target_fragments = imported_op
-                    .0
-                    .definitions
-                    .iter()
-                    .filter(|def| match def {
-                        ExecutableDefinition::FragmentDefinition(def) => {
-                            debug!("targets: {:?} def.name.name: {}", targets, def.name.name);
-                            debug!("def: {:?}", def);
-                            targets.iter().any(|target| target.name == def.name.name)
-                        }
-                        _ => false,
-                    })
-                    .cloned();
-                for target in target_fragments {
-                    definitions.push(target);
-                    count += 1;
-                }
-                if count < targets.len() {
-                    // figure out which targets are missing (first one)
-                    let missing_target = targets
+                let imported_definitions = &imported_op.0.definitions;
+                // figure out which targets are missing (first one)
+                if let Some(missing_target) = targets.iter().find(|target| {
+                    !imported_definitions
                         .iter()
-                        .find(|target| {
-                            !imported_op.0.definitions.iter().any(|def| match def {
-                                ExecutableDefinition::FragmentDefinition(def) => {
-                                    target.name == def.name.name
-                                }
-                                _ => false,
-                            })
-                        })
-                        .expect("missing target not found");
+                        .any(|def| definition_name(def) == Some(target.name))
+                }) {
+                    let candidates = imported_definitions.iter().filter_map(definition_name);
                     return Err(ExtensionError {
-                        message: ExtensionErrorMessage::FragmentNotFound {
+                        message: ExtensionErrorMessage::TargetNotFound {
                             name: missing_target.name.to_string(),
                             file: import.path.value.clone(),
                             position: missing_target.position,
+                            suggestions: did_you_mean(missing_target.name, candidates),
                         },
                     });
                 }
+                let is_target = |def: &ExecutableDefinition| {
+                    let is_operation = matches!(def, ExecutableDefinition::OperationDefinition(_));
+                    (import_operations || !is_operation)
+                        && targets
+                            .iter()
+                            .any(|target| definition_name(def) == Some(target.name))
+                };
+                // Fragments used by imported definitions are imported together.
+                let mut fragment_names = HashSet::new();
+                for def in imported_definitions.iter() {
+                    debug!("targets: {:?} def: {:?}", targets, definition_name(def));
+                    if is_target(def) {
+                        collect_fragment_names(def, imported_definitions, &mut fragment_names);
+                    }
+                }
+                for def in imported_definitions.iter() {
+                    match def {
+                        ExecutableDefinition::OperationDefinition(op) => {
+                            let Some(name) = op.name else {
+                                continue;
+                            };
+                            if !is_target(def) {
+                                continue;
+                            }
+                            let conflicting = definitions.iter().find(|other| match other {
+                                ExecutableDefinition::OperationDefinition(other) => {
+                                    other.name.is_some_and(|other| other.name == name.name)
+                                        && other.position != op.position
+                                }
+                                ExecutableDefinition::FragmentDefinition(_) => false,
+                            });
+                            if let Some(conflicting) = conflicting {
+                                let target = targets
+                                    .iter()
+                                    .find(|target| target.name == name.name)
+                                    .expect("target not found");
+                                return Err(ExtensionError {
+                                    message: ExtensionErrorMessage::OperationNameConflict {
+                                        name: name.name.to_string(),
+                                        file: import.path.value.clone(),
+                                        position: target.position,
+                                        other_position: *conflicting.position(),
+                                    },
+                                });
+                            }
+                            push_definition(definitions, def);
+                        }
+                        ExecutableDefinition::FragmentDefinition(fragment) => {
+                            if fragment_names.contains(fragment.name.name) {
+                                push_definition(definitions, def);
+                            }
+                        }
+                    }
+                }
             }
         }
     }
     Ok(())
 }
 
+/// Appends given definition unless it has already been imported.
+fn push_definition<'src>(
+    definitions: &mut Vec<ExecutableDefinition<'src>>,
+    def: &ExecutableDefinition<'src>,
+) {
+    let imported = definitions.iter().any(|other| {
+        other.position() == def.position() && definition_name(other) == definition_name(def)
+    });
+    if !imported {
+        definitions.push(def.clone());
+    }
+}
+
+/// Returns the name of given definition.
+fn definition_name<'a>(def: &ExecutableDefinition<'a>) -> Option<&'a str> {
+    match def {
+        ExecutableDefinition::OperationDefinition(op) => op.name.map(|name| name.name),
+        ExecutableDefinition::FragmentDefinition(fragment) => Some(fragment.name.name),
+    }
+}
+
+/// Collects names of fragments that given definition depends on.
+/// If given definition is a fragment, its own name is also collected.
+/// Only fragments defined in `definitions` are followed.
+fn collect_fragment_names<'a>(
+    def: &ExecutableDefinition<'a>,
+    definitions: &[ExecutableDefinition<'a>],
+    names: &mut HashSet<&'a str>,
+) {
+    let selection_set = match def {
+        ExecutableDefinition::OperationDefinition(op) => &op.selection_set,
+        ExecutableDefinition::FragmentDefinition(fragment) => {
+            if !names.insert(fragment.name.name) {
+                return;
+            }
+            &fragment.selection_set
+        }
+    };
+    collect_rec(selection_set, definitions, names);
+
+    fn collect_rec<'a>(
+        selection_set: &SelectionSet<'a>,
+        definitions: &[ExecutableDefinition<'a>],
+        names: &mut HashSet<&'a str>,
+    ) {
+        for selection in selection_set.selections.iter() {
+            match selection {
+                Selection::Field(field) => {
+                    if let Some(selection_set) = field.selection_set.as_ref() {
+                        collect_rec(selection_set, definitions, names);
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    let fragment = definitions.iter().find(|def| match def {
+                        ExecutableDefinition::FragmentDefinition(fragment) => {
+                            fragment.name.name == spread.fragment_name.name
+                        }
+                        ExecutableDefinition::OperationDefinition(_) => false,
+                    });
+                    if let Some(fragment) = fragment {
+                        collect_fragment_names(fragment, definitions, names);
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    collect_rec(&inline_fragment.selection_set, definitions, names);
+                }
+            }
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ExtensionErrorMessage {
     #[error("File '{file}' not found.")]
     FileNotFound { file: String, position: Pos },
-    #[error("'{name}' is not found in the imported file '{file}'.")]
-    FragmentNotFound {
+    #[error("Cannot resolve '{file}': {reason}.")]
+    UnresolvedImport {
+        file: String,
+        reason: String,
+        position: Pos,
+    },
+    #[error("No operation or fragment named '{name}' is found in the imported file '{file}'.")]
+    TargetNotFound {
+        name: String,
+        file: String,
+        position: Pos,
+        /// Names suggested as a fix for this error.
+        suggestions: Vec<String>,
+    },
+    #[error(
+        "Operation '{name}' imported from '{file}' conflicts with another operation of the same name."
+    )]
+    OperationNameConflict {
         name: String,
         file: String,
         position: Pos,
+        other_position: Pos,
     },
 }
 
@@ -139,18 +280,44 @@ impl From<ExtensionError> for PositionedError {
     fn from(value: ExtensionError) -> Self {
         let position = match &value.message {
             ExtensionErrorMessage::FileNotFound { position, .. } => *position,
-            ExtensionErrorMessage::FragmentNotFound { position, .. } => *position,
+            ExtensionErrorMessage::UnresolvedImport { position, .. } => *position,
+            ExtensionErrorMessage::TargetNotFound { position, .. } => *position,
+            ExtensionErrorMessage::OperationNameConflict { position, .. } => *position,
         };
         let additional_info = match &value.message {
-            ExtensionErrorMessage::FileNotFound { .. } => vec![
-                (
-                    Pos::builtin(),
-                    "Hint: imported file must be included in the 'documents' option of the config file.".into(),
-                )
-            ],
+            ExtensionErrorMessage::FileNotFound { .. } => vec![(
+                Pos::builtin(),
+                "Hint: make sure that the imported file exists.".into(),
+            )],
+            ExtensionErrorMessage::OperationNameConflict {
+                name,
+                other_position,
+                ..
+            } => vec![(*other_position, format!("Another definition of '{name}'"))],
+            _ => vec![],
+        };
+        let suggestions = match &value.message {
+            ExtensionErrorMessage::TargetNotFound { suggestions, .. } => suggestions.clone(),
             _ => vec![],
         };
+        let (code, fields) = match &value.message {
+            ExtensionErrorMessage::FileNotFound { file, .. }
+            | ExtensionErrorMessage::UnresolvedImport { file, .. } => {
+                (codes::IMPORT_FILE_NOT_FOUND, vec![("file", file.clone())])
+            }
+            ExtensionErrorMessage::TargetNotFound { name, file, .. } => (
+                codes::IMPORT_TARGET_NOT_FOUND,
+                vec![("name", name.clone()), ("file", file.clone())],
+            ),
+            ExtensionErrorMessage::OperationNameConflict { name, file, .. } => (
+                codes::IMPORTED_OPERATION_NAME_CONFLICT,
+                vec![("name", name.clone()), ("file", file.clone())],
+            ),
+        };
 
         PositionedError::new(value.message.into(), Some(position), additional_info)
+            .with_code(code)
+            .with_fields(fields)
+            .with_suggestions(suggestions)
     }
 }
@@ -1,12 +1,23 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use nitrogql_ast::OperationDocument;
+use nitrogql_utils::resolve_relative_path;
 
-use crate::OperationExtension;
+use crate::{ImportPathError, OperationExtension};
 
 /// Trait for resolving paths to operations.
 pub trait OperationResolver<'src> {
     /// Resolve a full path to an operation.
     fn resolve(&self, path: &Path)
     -> Option<(&OperationDocument<'src>, &OperationExtension<'src>)>;
+
+    /// Resolve a path written in `#import` of `from_file` to a full path.
+    /// By default, the path is resolved relative to `from_file`.
+    fn resolve_import_path(
+        &self,
+        from_file: &Path,
+        import_path: &str,
+    ) -> Result<PathBuf, ImportPathError> {
+        Ok(resolve_relative_path(from_file, Path::new(import_path)))
+    }
 }
@@ -41,6 +41,39 @@ fragment Frag2 on Foo {
 fragment Frag3 on Foo {
     baz
 }
+"#,
+            )),
+            "/path/to/base.graphql" => Some(static_parse(
+                r#"
+#import Frag3 from "./frag3.graphql"
+query Base {
+    foo {
+        ...BaseFrag
+    }
+}
+query Other {
+    foo {
+        bar
+    }
+}
+fragment BaseFrag on Foo {
+    ...Frag3
+    ...BaseFrag2
+}
+fragment BaseFrag2 on Foo {
+    bar
+}
+fragment Unused on Foo {
+    bar
+}
+"#,
+            )),
+            "/path/to/feature.graphql" => Some(static_parse(
+                r#"
+#import Base from "./base.graphql"
+fragment Feature on Foo {
+    bar
+}
 "#,
             )),
             "/path/to/rec/frag1.graphql" => Some(static_parse(
@@ -245,6 +278,82 @@ fn error_import_fragment_nonexistent() {
     assert_debug_snapshot!(err);
 }
 
+#[test]
+fn import_operation() {
+    let doc = parse_operation_document(
+        r#"
+        #import Base from "./base.graphql"
+        query Foo {
+            foo {
+                bar
+            }
+        }
+        "#,
+    )
+    .unwrap();
+    let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+    let doc = (Path::new("/path/to/main.graphql"), &doc, &extensions);
+    let resolved = resolve_operation_imports(doc, &TestOperationResolver).unwrap();
+    assert_snapshot!(print_document(&resolved));
+}
+
+#[test]
+fn transitive_import_operation() {
+    let doc = parse_operation_document(
+        r#"
+        #import Feature from "./feature.graphql"
+        query Foo {
+            foo {
+                ...Feature
+            }
+        }
+        "#,
+    )
+    .unwrap();
+    let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+    let doc = (Path::new("/path/to/main.graphql"), &doc, &extensions);
+    let resolved = resolve_operation_imports(doc, &TestOperationResolver).unwrap();
+    assert_snapshot!(print_document(&resolved));
+}
+
+#[test]
+fn error_import_operation_nonexistent() {
+    let doc = parse_operation_document(
+        r#"
+        #import Bsae from "./base.graphql"
+        query Foo {
+            foo {
+                bar
+            }
+        }
+        "#,
+    )
+    .unwrap();
+    let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+    let doc = (Path::new("/path/to/main.graphql"), &doc, &extensions);
+    let err = resolve_operation_imports(doc, &TestOperationResolver).unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
+#[test]
+fn error_import_operation_name_conflict() {
+    let doc = parse_operation_document(
+        r#"
+        #import Base, Other from "./base.graphql"
+        query Other {
+            foo {
+                bar
+            }
+        }
+        "#,
+    )
+    .unwrap();
+    let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+    let doc = (Path::new("/path/to/main.graphql"), &doc, &extensions);
+    let err = resolve_operation_imports(doc, &TestOperationResolver).unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
 fn print_document(document: &OperationDocument) -> String {
     let mut buffer = String::new();
     let mut printer = JustWriter::new(&mut buffer);
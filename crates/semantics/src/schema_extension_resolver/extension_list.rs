@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 use thiserror::Error;
 
 use nitrogql_ast::base::{HasPos, Pos};
-use nitrogql_error::PositionedError;
+use nitrogql_error::{PositionedError, codes};
 
 pub struct ExtensionList<'a, OriginalType: HasPos, ExtensionType: HasPos> {
     name_of_elem: &'a str,
@@ -58,8 +58,22 @@ impl From<ExtensionError> for PositionedError {
             }
             ExtensionErrorMessage::NoOriginal { .. } => vec![],
         };
+        let (code, fields) = match &value.message {
+            ExtensionErrorMessage::DuplicateOriginal {
+                name_of_elem, name, ..
+            } => (
+                codes::DUPLICATED_DECLARATION,
+                vec![("kind", name_of_elem.clone()), ("name", name.clone())],
+            ),
+            ExtensionErrorMessage::NoOriginal { name_of_elem, .. } => (
+                codes::EXTENSION_WITHOUT_ORIGINAL,
+                vec![("kind", name_of_elem.clone())],
+            ),
+        };
 
         PositionedError::new(value.message.into(), Some(position), additional_info)
+            .with_code(code)
+            .with_fields(fields)
     }
 }
 
@@ -0,0 +1,565 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use nitrogql_ast::{
+    base::{Ident, Pos},
+    directive::Directive,
+    r#type::Type,
+    type_system::{
+        ArgumentsDefinition, FieldDefinition, InputValueDefinition, TypeDefinition, TypeExtension,
+        TypeSystemDefinitionOrExtension, TypeSystemOrExtensionDocument,
+    },
+};
+use nitrogql_error::{PositionedError, codes};
+use nitrogql_utils::{did_you_mean, relative_path, resolve_relative_path};
+use thiserror::Error;
+
+use crate::{
+    Import, ImportPathError, ImportTargets,
+    operation_extension_resolver::resolve_import_definitions,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Trait for resolving paths to schema files.
+pub trait SchemaResolver<'src> {
+    /// Resolve a full path to a schema document.
+    fn resolve(&self, path: &Path) -> Option<&TypeSystemOrExtensionDocument<'src>>;
+
+    /// Resolve a path written in `#import` of `from_file` to a full path.
+    /// By default, the path is resolved relative to `from_file`.
+    fn resolve_import_path(
+        &self,
+        from_file: &Path,
+        import_path: &str,
+    ) -> Result<PathBuf, ImportPathError> {
+        Ok(resolve_relative_path(from_file, Path::new(import_path)))
+    }
+}
+
+/// Resolves the module graph of schema files starting from `entry`.
+/// Returns the paths of all schema files reachable from `entry`.
+/// Imported files come before importing files. Import cycles are reported as errors.
+///
+/// Each file may only use types and directives that are defined in or imported into the file.
+/// Names that are not defined in any file (such as built-in scalars) are not checked here.
+pub fn resolve_schema_imports<'src>(
+    entry: &Path,
+    resolver: &impl SchemaResolver<'src>,
+) -> Result<Vec<PathBuf>, Vec<PositionedError>> {
+    let Some(document) = resolver.resolve(entry) else {
+        return Err(vec![
+            SchemaImportError {
+                message: SchemaImportErrorMessage::FileNotFound {
+                    file: entry.display().to_string(),
+                    position: Pos::builtin(),
+                },
+            }
+            .into(),
+        ]);
+    };
+    let mut graph = ModuleGraph {
+        modules: vec![],
+        visited: HashSet::new(),
+        stack: vec![],
+        errors: vec![],
+    };
+    graph.visited.insert(entry.to_owned());
+    graph.visit(entry.to_owned(), document, resolver);
+    let ModuleGraph {
+        modules,
+        mut errors,
+        ..
+    } = graph;
+
+    let definitions: HashMap<&Path, Definitions> = modules
+        .iter()
+        .map(|module| (module.path.as_path(), Definitions::new(module.document)))
+        .collect();
+    let mut all_types = HashMap::new();
+    let mut all_directives = HashMap::new();
+    for module in modules.iter() {
+        let defs = &definitions[module.path.as_path()];
+        for (name, pos) in defs.types.iter() {
+            all_types.entry(*name).or_insert(*pos);
+        }
+        for (name, pos) in defs.directives.iter() {
+            all_directives.entry(*name).or_insert(*pos);
+        }
+    }
+
+    for module in modules.iter() {
+        let own = &definitions[module.path.as_path()];
+        let mut visible_types: HashSet<&str> = own.types.keys().copied().collect();
+        let mut visible_directives: HashSet<&str> = own.directives.keys().copied().collect();
+        for (import, imported_path) in module.imports.iter() {
+            let imported = &definitions[imported_path.as_path()];
+            match import.targets {
+                ImportTargets::Wildcard => {
+                    visible_types.extend(imported.types.keys());
+                    visible_directives.extend(imported.directives.keys());
+                }
+                ImportTargets::Specific(ref targets) => {
+                    for target in targets {
+                        let is_type = imported.types.contains_key(target.name);
+                        let is_directive = imported.directives.contains_key(target.name);
+                        if is_type {
+                            visible_types.insert(target.name);
+                        }
+                        if is_directive {
+                            visible_directives.insert(target.name);
+                        }
+                        if !is_type && !is_directive {
+                            let candidates = imported
+                                .types
+                                .keys()
+                                .chain(imported.directives.keys())
+                                .copied();
+                            errors.push(
+                                SchemaImportError {
+                                    message: SchemaImportErrorMessage::TargetNotFound {
+                                        name: target.name.to_owned(),
+                                        file: import.path.value.clone(),
+                                        position: target.position,
+                                        suggestions: did_you_mean(target.name, candidates),
+                                    },
+                                }
+                                .into(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut references = vec![];
+        for def in module.document.definitions.iter() {
+            collect_references(def, &mut references);
+        }
+        for (ident, kind) in references {
+            let (visible, all) = match kind {
+                ReferenceKind::Type => (&visible_types, &all_types),
+                ReferenceKind::Directive => (&visible_directives, &all_directives),
+            };
+            if visible.contains(ident.name) {
+                continue;
+            }
+            if let Some(definition_position) = all.get(ident.name) {
+                errors.push(
+                    SchemaImportError {
+                        message: SchemaImportErrorMessage::NotImported {
+                            name: match kind {
+                                ReferenceKind::Type => ident.name.to_owned(),
+                                ReferenceKind::Directive => format!("@{}", ident.name),
+                            },
+                            position: ident.position,
+                            definition_position: *definition_position,
+                        },
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(modules.into_iter().map(|module| module.path).collect())
+    } else {
+        Err(errors)
+    }
+}
+
+struct Module<'a, 'src> {
+    path: PathBuf,
+    document: &'a TypeSystemOrExtensionDocument<'src>,
+    /// Successfully resolved imports with the full path of imported file.
+    imports: Vec<(Import<'src>, PathBuf)>,
+}
+
+struct ModuleGraph<'a, 'src> {
+    /// Visited modules in post-order.
+    modules: Vec<Module<'a, 'src>>,
+    visited: HashSet<PathBuf>,
+    /// Files being visited, with the position of the import that is being followed in each.
+    stack: Vec<(PathBuf, Pos)>,
+    errors: Vec<PositionedError>,
+}
+
+impl<'a, 'src> ModuleGraph<'a, 'src> {
+    fn visit(
+        &mut self,
+        path: PathBuf,
+        document: &'a TypeSystemOrExtensionDocument<'src>,
+        resolver: &'a impl SchemaResolver<'src>,
+    ) {
+        let imports = match resolve_import_definitions(document.imports.clone()) {
+            Ok(imports) => imports,
+            Err(err) => {
+                self.errors.push(err.into());
+                vec![]
+            }
+        };
+        let mut resolved_imports = vec![];
+        for import in imports {
+            let imported_path = match resolver.resolve_import_path(&path, &import.path.value) {
+                Ok(imported_path) => imported_path,
+                Err(err) => {
+                    self.errors.push(
+                        SchemaImportError {
+                            message: SchemaImportErrorMessage::UnresolvedImport {
+                                file: import.path.value.clone(),
+                                reason: err.to_string(),
+                                position: import.path.position,
+                            },
+                        }
+                        .into(),
+                    );
+                    continue;
+                }
+            };
+            let Some(imported_document) = resolver.resolve(&imported_path) else {
+                self.errors.push(
+                    SchemaImportError {
+                        message: SchemaImportErrorMessage::FileNotFound {
+                            file: import.path.value.clone(),
+                            position: import.path.position,
+                        },
+                    }
+                    .into(),
+                );
+                continue;
+            };
+            if imported_path == path
+                || self
+                    .stack
+                    .iter()
+                    .any(|(visiting, _)| *visiting == imported_path)
+            {
+                self.errors
+                    .push(self.import_cycle(&path, &imported_path, import.path.position));
+            } else if self.visited.insert(imported_path.clone()) {
+                self.stack.push((path.clone(), import.path.position));
+                self.visit(imported_path.clone(), imported_document, resolver);
+                self.stack.pop();
+            }
+            resolved_imports.push((import, imported_path));
+        }
+        self.modules.push(Module {
+            path,
+            document,
+            imports: resolved_imports,
+        });
+    }
+}
+
+impl ModuleGraph<'_, '_> {
+    /// Generates an error for an import from `path` to `imported_path`, which is being visited.
+    fn import_cycle(&self, path: &Path, imported_path: &Path, position: Pos) -> PositionedError {
+        let start = self
+            .stack
+            .iter()
+            .position(|(visiting, _)| visiting == imported_path)
+            .unwrap_or(self.stack.len());
+        let chain = &self.stack[start..];
+        let display = |file: &Path| relative_path(path, file).display().to_string();
+        let cycle = chain
+            .iter()
+            .map(|(file, _)| file.as_path())
+            .chain([path, imported_path])
+            .map(display)
+            .collect();
+        let imports = chain
+            .iter()
+            .zip(
+                chain
+                    .iter()
+                    .skip(1)
+                    .map(|(file, _)| file.as_path())
+                    .chain([path]),
+            )
+            .map(|((from, position), to)| (*position, display(from), display(to)))
+            .collect();
+        SchemaImportError {
+            message: SchemaImportErrorMessage::ImportCycle {
+                cycle,
+                position,
+                imports,
+            },
+        }
+        .into()
+    }
+}
+
+/// Types and directives defined in one file.
+struct Definitions<'a> {
+    types: HashMap<&'a str, Pos>,
+    directives: HashMap<&'a str, Pos>,
+}
+
+impl<'a> Definitions<'a> {
+    fn new(document: &'a TypeSystemOrExtensionDocument) -> Self {
+        let mut types = HashMap::new();
+        let mut directives = HashMap::new();
+        for def in document.definitions.iter() {
+            match def {
+                TypeSystemDefinitionOrExtension::TypeDefinition(def) => {
+                    let name = def.name();
+                    types.insert(name.name, name.position);
+                }
+                TypeSystemDefinitionOrExtension::DirectiveDefinition(def) => {
+                    directives.insert(def.name.name, def.name.position);
+                }
+                _ => {}
+            }
+        }
+        Definitions { types, directives }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum ReferenceKind {
+    Type,
+    Directive,
+}
+
+/// Collects names of types and directives referenced from given definition.
+fn collect_references<'a, 'src>(
+    def: &'a TypeSystemDefinitionOrExtension<'src>,
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    match def {
+        TypeSystemDefinitionOrExtension::SchemaDefinition(def) => {
+            collect_directives(&def.directives, references);
+            for (_, ty) in def.definitions.iter() {
+                references.push((ty, ReferenceKind::Type));
+            }
+        }
+        TypeSystemDefinitionOrExtension::SchemaExtension(def) => {
+            collect_directives(&def.directives, references);
+            for (_, ty) in def.definitions.iter() {
+                references.push((ty, ReferenceKind::Type));
+            }
+        }
+        TypeSystemDefinitionOrExtension::TypeDefinition(def) => match def {
+            TypeDefinition::Scalar(def) => {
+                collect_directives(&def.directives, references);
+            }
+            TypeDefinition::Object(def) => {
+                collect_implements(&def.implements, references);
+                collect_directives(&def.directives, references);
+                collect_fields(&def.fields, references);
+            }
+            TypeDefinition::Interface(def) => {
+                collect_implements(&def.implements, references);
+                collect_directives(&def.directives, references);
+                collect_fields(&def.fields, references);
+            }
+            TypeDefinition::Union(def) => {
+                collect_directives(&def.directives, references);
+                collect_implements(&def.members, references);
+            }
+            TypeDefinition::Enum(def) => {
+                collect_directives(&def.directives, references);
+                for value in def.values.iter() {
+                    collect_directives(&value.directives, references);
+                }
+            }
+            TypeDefinition::InputObject(def) => {
+                collect_directives(&def.directives, references);
+                collect_input_values(&def.fields, references);
+            }
+        },
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(def) => {
+            collect_arguments(&def.arguments, references);
+        }
+        TypeSystemDefinitionOrExtension::TypeExtension(def) => match def {
+            TypeExtension::Scalar(def) => {
+                references.push((&def.name, ReferenceKind::Type));
+                collect_directives(&def.directives, references);
+            }
+            TypeExtension::Object(def) => {
+                references.push((&def.name, ReferenceKind::Type));
+                collect_implements(&def.implements, references);
+                collect_directives(&def.directives, references);
+                collect_fields(&def.fields, references);
+            }
+            TypeExtension::Interface(def) => {
+                references.push((&def.name, ReferenceKind::Type));
+                collect_implements(&def.implements, references);
+                collect_directives(&def.directives, references);
+                collect_fields(&def.fields, references);
+            }
+            TypeExtension::Union(def) => {
+                references.push((&def.name, ReferenceKind::Type));
+                collect_directives(&def.directives, references);
+                collect_implements(&def.members, references);
+            }
+            TypeExtension::Enum(def) => {
+                references.push((&def.name, ReferenceKind::Type));
+                collect_directives(&def.directives, references);
+                for value in def.values.iter() {
+                    collect_directives(&value.directives, references);
+                }
+            }
+            TypeExtension::InputObject(def) => {
+                references.push((&def.name, ReferenceKind::Type));
+                collect_directives(&def.directives, references);
+                collect_input_values(&def.fields, references);
+            }
+        },
+    }
+}
+
+fn collect_implements<'a, 'src>(
+    names: &'a [Ident<'src>],
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    references.extend(names.iter().map(|name| (name, ReferenceKind::Type)));
+}
+
+fn collect_directives<'a, 'src>(
+    directives: &'a [Directive<'src>],
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    references.extend(
+        directives
+            .iter()
+            .map(|directive| (&directive.name, ReferenceKind::Directive)),
+    );
+}
+
+fn collect_fields<'a, 'src>(
+    fields: &'a [FieldDefinition<'src>],
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    for field in fields {
+        collect_arguments(&field.arguments, references);
+        collect_type(&field.r#type, references);
+        collect_directives(&field.directives, references);
+    }
+}
+
+fn collect_arguments<'a, 'src>(
+    arguments: &'a Option<ArgumentsDefinition<'src>>,
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    if let Some(arguments) = arguments {
+        collect_input_values(&arguments.input_values, references);
+    }
+}
+
+fn collect_input_values<'a, 'src>(
+    input_values: &'a [InputValueDefinition<'src>],
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    for input_value in input_values {
+        collect_type(&input_value.r#type, references);
+        collect_directives(&input_value.directives, references);
+    }
+}
+
+fn collect_type<'a, 'src>(
+    ty: &'a Type<'src>,
+    references: &mut Vec<(&'a Ident<'src>, ReferenceKind)>,
+) {
+    match ty {
+        Type::Named(name) => references.push((&name.name, ReferenceKind::Type)),
+        Type::NonNull(inner) => collect_type(&inner.r#type, references),
+        Type::List(inner) => collect_type(&inner.r#type, references),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SchemaImportErrorMessage {
+    #[error("File '{file}' not found.")]
+    FileNotFound { file: String, position: Pos },
+    #[error("Cannot resolve '{file}': {reason}.")]
+    UnresolvedImport {
+        file: String,
+        reason: String,
+        position: Pos,
+    },
+    #[error("No type or directive named '{name}' is found in the imported file '{file}'.")]
+    TargetNotFound {
+        name: String,
+        file: String,
+        position: Pos,
+        /// Names suggested as a fix for this error.
+        suggestions: Vec<String>,
+    },
+    #[error("'{name}' is used without being imported.")]
+    NotImported {
+        name: String,
+        position: Pos,
+        definition_position: Pos,
+    },
+    #[error("Import cycle detected: {}.", cycle.join(" -> "))]
+    ImportCycle {
+        /// Files in the cycle, starting and ending with the same file.
+        cycle: Vec<String>,
+        position: Pos,
+        /// Imports in other files that form the cycle: (position, importing file, imported file).
+        imports: Vec<(Pos, String, String)>,
+    },
+}
+
+#[derive(Debug)]
+pub struct SchemaImportError {
+    pub message: SchemaImportErrorMessage,
+}
+
+impl From<SchemaImportError> for PositionedError {
+    fn from(value: SchemaImportError) -> Self {
+        let value = value.message;
+        let position = match &value {
+            SchemaImportErrorMessage::FileNotFound { position, .. } => *position,
+            SchemaImportErrorMessage::UnresolvedImport { position, .. } => *position,
+            SchemaImportErrorMessage::TargetNotFound { position, .. } => *position,
+            SchemaImportErrorMessage::NotImported { position, .. } => *position,
+            SchemaImportErrorMessage::ImportCycle { position, .. } => *position,
+        };
+        let additional_info = match &value {
+            SchemaImportErrorMessage::NotImported {
+                name,
+                definition_position,
+                ..
+            } => vec![(*definition_position, format!("'{name}' is defined here"))],
+            SchemaImportErrorMessage::ImportCycle { imports, .. } => imports
+                .iter()
+                .map(|(position, from, to)| (*position, format!("'{from}' imports '{to}'")))
+                .collect(),
+            _ => vec![],
+        };
+        let suggestions = match &value {
+            SchemaImportErrorMessage::TargetNotFound { suggestions, .. } => suggestions.clone(),
+            _ => vec![],
+        };
+        let (code, fields) = match &value {
+            SchemaImportErrorMessage::FileNotFound { file, .. }
+            | SchemaImportErrorMessage::UnresolvedImport { file, .. } => (
+                codes::SCHEMA_IMPORT_FILE_NOT_FOUND,
+                vec![("file", file.clone())],
+            ),
+            SchemaImportErrorMessage::TargetNotFound { name, file, .. } => (
+                codes::SCHEMA_IMPORT_TARGET_NOT_FOUND,
+                vec![("name", name.clone()), ("file", file.clone())],
+            ),
+            SchemaImportErrorMessage::NotImported { name, .. } => {
+                (codes::NOT_IMPORTED, vec![("name", name.clone())])
+            }
+            SchemaImportErrorMessage::ImportCycle { cycle, .. } => (
+                codes::SCHEMA_IMPORT_CYCLE,
+                vec![("cycle", cycle.join(" -> "))],
+            ),
+        };
+        let position = (!position.builtin).then_some(position);
+
+        PositionedError::new(value.into(), position, additional_info)
+            .with_code(code)
+            .with_fields(fields)
+            .with_suggestions(suggestions)
+    }
+}
@@ -0,0 +1,178 @@
+use std::path::{Path, PathBuf};
+
+use insta::assert_debug_snapshot;
+use nitrogql_ast::type_system::TypeSystemOrExtensionDocument;
+use nitrogql_parser::parse_type_system_document;
+
+use crate::{SchemaResolver, resolve_schema_imports};
+
+struct TestSchemaResolver;
+impl SchemaResolver<'static> for TestSchemaResolver {
+    fn resolve(&self, path: &Path) -> Option<&TypeSystemOrExtensionDocument<'static>> {
+        match path.to_str().unwrap() {
+            "/path/to/schema.graphql" => Some(static_parse(
+                r#"
+#import Post from "./post.graphql"
+#import User from "./user.graphql"
+#import * from "./directives.graphql"
+type Query {
+    me: User @auth
+    posts: [Post!]!
+    version: String!
+}
+"#,
+            )),
+            "/path/to/user.graphql" => Some(static_parse(
+                r#"
+type User {
+    id: ID!
+}
+"#,
+            )),
+            "/path/to/post.graphql" => Some(static_parse(
+                r#"
+#import User from "./user.graphql"
+type Post {
+    author: User!
+}
+"#,
+            )),
+            "/path/to/cycle/a.graphql" => Some(static_parse(
+                r#"
+#import B from "./b.graphql"
+type A {
+    b: B
+}
+"#,
+            )),
+            "/path/to/cycle/b.graphql" => Some(static_parse(
+                r#"
+#import C from "./c.graphql"
+type B {
+    c: C
+}
+"#,
+            )),
+            "/path/to/cycle/c.graphql" => Some(static_parse(
+                r#"
+#import A from "./a.graphql"
+type C {
+    a: A
+}
+"#,
+            )),
+            "/path/to/directives.graphql" => Some(static_parse(
+                r#"
+directive @auth on FIELD_DEFINITION
+"#,
+            )),
+            "/path/to/missing_type.graphql" => Some(static_parse(
+                r#"
+#import * from "./directives.graphql"
+#import Post from "./post.graphql"
+type Query {
+    me: User @auth
+    posts: [Post!]!
+}
+"#,
+            )),
+            "/path/to/missing_directive.graphql" => Some(static_parse(
+                r#"
+#import Query from "./schema.graphql"
+#import User from "./user.graphql"
+extend type Query {
+    friend: User @auth
+}
+"#,
+            )),
+            "/path/to/typo.graphql" => Some(static_parse(
+                r#"
+#import Usr from "./user.graphql"
+type Query {
+    version: String!
+}
+"#,
+            )),
+            "/path/to/nonexistent.graphql" => Some(static_parse(
+                r#"
+#import * from "./nowhere.graphql"
+type Query {
+    version: String!
+}
+"#,
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn static_parse(source: &'static str) -> &'static TypeSystemOrExtensionDocument<'static> {
+    let doc = parse_type_system_document(source).unwrap();
+    Box::leak(Box::new(doc))
+}
+
+#[test]
+fn resolve_modules() {
+    let paths =
+        resolve_schema_imports(Path::new("/path/to/schema.graphql"), &TestSchemaResolver).unwrap();
+    assert_eq!(
+        paths,
+        vec![
+            PathBuf::from("/path/to/user.graphql"),
+            PathBuf::from("/path/to/post.graphql"),
+            PathBuf::from("/path/to/directives.graphql"),
+            PathBuf::from("/path/to/schema.graphql"),
+        ]
+    );
+}
+
+#[test]
+fn error_entry_nonexistent() {
+    let err = resolve_schema_imports(Path::new("/path/to/nowhere.graphql"), &TestSchemaResolver)
+        .unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
+#[test]
+fn error_import_nonexistent() {
+    let err = resolve_schema_imports(
+        Path::new("/path/to/nonexistent.graphql"),
+        &TestSchemaResolver,
+    )
+    .unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
+#[test]
+fn error_type_not_imported() {
+    let err = resolve_schema_imports(
+        Path::new("/path/to/missing_type.graphql"),
+        &TestSchemaResolver,
+    )
+    .unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
+#[test]
+fn error_directive_not_imported() {
+    let err = resolve_schema_imports(
+        Path::new("/path/to/missing_directive.graphql"),
+        &TestSchemaResolver,
+    )
+    .unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
+#[test]
+fn error_import_target_nonexistent() {
+    let err = resolve_schema_imports(Path::new("/path/to/typo.graphql"), &TestSchemaResolver)
+        .unwrap_err();
+    assert_debug_snapshot!(err);
+}
+
+#[test]
+fn error_import_cycle() {
+    let err = resolve_schema_imports(Path::new("/path/to/cycle/a.graphql"), &TestSchemaResolver)
+        .unwrap_err();
+    assert_debug_snapshot!(err);
+}
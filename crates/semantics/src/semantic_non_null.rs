@@ -0,0 +1,67 @@
+use nitrogql_ast::{base::Pos, directive::Directive, value::Value};
+
+/// One level given to the `@semanticNonNull` directive.
+#[derive(Copy, Clone, Debug)]
+pub struct SemanticNonNullLevel<'a> {
+    pub position: Pos,
+    /// Source representation of the level.
+    pub value: &'a str,
+}
+
+impl SemanticNonNullLevel<'_> {
+    /// Returns the level as a number. None if it is not a valid level.
+    pub fn level(&self) -> Option<usize> {
+        self.value.parse().ok()
+    }
+}
+
+/// Reads levels of `@semanticNonNull` applied to a field definition.
+/// Returns None if the directive is not applied.
+/// Non-integer values are skipped as they are reported by the argument type check.
+pub fn semantic_non_null_levels<'a>(
+    directives: &[Directive<'a>],
+) -> Option<Vec<SemanticNonNullLevel<'a>>> {
+    let directive = directives
+        .iter()
+        .find(|dir| dir.name.name == "semanticNonNull")?;
+    let levels = directive
+        .arguments
+        .iter()
+        .flat_map(|args| args.arguments.iter())
+        .find(|(name, _)| name.name == "levels")
+        .map(|(_, value)| value);
+    let Some(levels) = levels else {
+        // Default value is [0]
+        return Some(vec![SemanticNonNullLevel {
+            position: directive.position,
+            value: "0",
+        }]);
+    };
+    let values = match levels {
+        Value::ListValue(list) => list.values.iter().collect(),
+        // input coercion of a single value to a list
+        value => vec![value],
+    };
+    Some(
+        values
+            .into_iter()
+            .filter_map(|value| match value {
+                Value::IntValue(int) => Some(SemanticNonNullLevel {
+                    position: int.position,
+                    value: int.value,
+                }),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Returns valid levels of `@semanticNonNull` applied to a field definition.
+/// Empty if the directive is not applied.
+pub fn semantic_non_null_level_numbers(directives: &[Directive]) -> Vec<usize> {
+    semantic_non_null_levels(directives)
+        .into_iter()
+        .flatten()
+        .filter_map(|level| level.level())
+        .collect()
+}
@@ -1,12 +1,13 @@
 use std::ops::Deref;
 
 use graphql_type_system::{Node, Schema, Text};
+
+use crate::type_system_utils::convert_type_to_ast;
 use nitrogql_ast::{
     TypeSystemDocument,
     base::{Ident, Keyword, Pos},
     directive::Directive,
     operation::OperationType,
-    r#type::{ListType, NamedType, NonNullType, Type},
     type_system::{
         ArgumentsDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
         InputObjectTypeDefinition, InputValueDefinition, InterfaceTypeDefinition,
@@ -167,22 +168,7 @@ fn convert_field<S: Deref<Target = str>, D>(
         name: convert_node_to_ident(&field.name),
         arguments: convert_arguments(&field.arguments),
         directives: vec![],
-        r#type: convert_type(&field.r#type),
-    }
-}
-
-fn convert_type<S: Deref<Target = str>, D>(ty: &graphql_type_system::Type<S, D>) -> Type<'_> {
-    match ty {
-        graphql_type_system::Type::Named(named) => Type::Named(NamedType {
-            name: convert_node_to_ident(named),
-        }),
-        graphql_type_system::Type::List(list) => Type::List(Box::new(ListType {
-            position: Pos::default(),
-            r#type: convert_type(list),
-        })),
-        graphql_type_system::Type::NonNull(non_null) => Type::NonNull(Box::new(NonNullType {
-            r#type: convert_type(non_null),
-        })),
+        r#type: convert_type_to_ast(&field.r#type),
     }
 }
 
@@ -205,7 +191,7 @@ fn convert_input_value<S: Deref<Target = str>, D>(
         description: convert_description(&input_value.description),
         position: Pos::default(),
         name: convert_node_to_ident(&input_value.name),
-        r#type: convert_type(&input_value.r#type),
+        r#type: convert_type_to_ast(&input_value.r#type),
         // TODO: cannot convert default value
         default_value: input_value.default_value.as_ref().map(|_| {
             Value::NullValue(NullValue {
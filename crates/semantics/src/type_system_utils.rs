@@ -1,7 +1,12 @@
+use std::ops::Deref;
+
 use graphql_type_system::{ListType, NamedType, Node, NonNullType, Type};
 use nitrogql_ast::{
     base::{Ident, Pos},
-    r#type::Type as AstType,
+    r#type::{
+        ListType as AstListType, NamedType as AstNamedType, NonNullType as AstNonNullType,
+        Type as AstType,
+    },
 };
 
 /// Convert AST type to Type System type.
@@ -15,6 +20,25 @@ pub fn convert_type<'a, 'src, R: From<&'src str>>(ty: &'a AstType<'src>) -> Type
     }
 }
 
+/// Convert Type System type to AST type.
+pub fn convert_type_to_ast<S: Deref<Target = str>, D>(ty: &Type<S, D>) -> AstType<'_> {
+    match ty {
+        Type::Named(named) => AstType::Named(AstNamedType {
+            name: Ident {
+                name: named,
+                position: Pos::default(),
+            },
+        }),
+        Type::List(list) => AstType::List(Box::new(AstListType {
+            position: Pos::default(),
+            r#type: convert_type_to_ast(list),
+        })),
+        Type::NonNull(non_null) => AstType::NonNull(Box::new(AstNonNullType {
+            r#type: convert_type_to_ast(non_null),
+        })),
+    }
+}
+
 pub fn ident_to_node<'src, T: From<&'src str>>(ident: &Ident<'src>) -> Node<T, Pos> {
     Node::from(ident.name, ident.position)
 }
@@ -0,0 +1,77 @@
+use nitrogql_ast::{
+    OperationDocument,
+    base::{Ident, Pos},
+    operation::ExecutableDefinition,
+    selection_set::{Field, Selection, SelectionSet},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Inserts `__typename` into every selection set of composite type in the given document.
+/// Selection sets of root operation types, that is, those directly under operations and
+/// those of fragments on one of `root_type_names`, are left untouched.
+/// Selection sets that already select `__typename` are not changed.
+pub fn add_typename<'src>(
+    mut document: OperationDocument<'src>,
+    root_type_names: &[&str],
+) -> OperationDocument<'src> {
+    for def in document.definitions.iter_mut() {
+        match def {
+            ExecutableDefinition::OperationDefinition(def) => {
+                visit_selections(&mut def.selection_set);
+            }
+            ExecutableDefinition::FragmentDefinition(def)
+                if root_type_names.contains(&def.type_condition.name) =>
+            {
+                visit_selections(&mut def.selection_set);
+            }
+            ExecutableDefinition::FragmentDefinition(def) => {
+                insert_typename(&mut def.selection_set);
+            }
+        }
+    }
+    document
+}
+
+/// Inserts `__typename` into selection sets of fields in the given selection set.
+fn visit_selections(selection_set: &mut SelectionSet) {
+    for selection in selection_set.selections.iter_mut() {
+        match selection {
+            Selection::Field(field) => {
+                if let Some(selection_set) = field.selection_set.as_mut() {
+                    insert_typename(selection_set);
+                }
+            }
+            Selection::FragmentSpread(_) => {}
+            Selection::InlineFragment(fragment) => {
+                visit_selections(&mut fragment.selection_set);
+            }
+        }
+    }
+}
+
+fn insert_typename(selection_set: &mut SelectionSet) {
+    visit_selections(selection_set);
+    let has_typename = selection_set.selections.iter().any(|selection| {
+        matches!(
+            selection,
+            Selection::Field(field)
+                if field.name.name == "__typename"
+                    && field.alias.is_none()
+                    && field.directives.is_empty()
+        )
+    });
+    if !has_typename {
+        selection_set.selections.push(Selection::Field(Field {
+            alias: None,
+            name: Ident {
+                name: "__typename",
+                position: Pos::builtin(),
+            },
+            arguments: None,
+            directives: vec![],
+            selection_set: None,
+        }));
+    }
+}
@@ -0,0 +1,99 @@
+use insta::assert_snapshot;
+use nitrogql_parser::parse_operation_document;
+use nitrogql_printer::GraphQLPrinter;
+use sourcemap_writer::JustWriter;
+
+use crate::{add_typename, resolve_operation_extensions};
+
+const ROOT_TYPE_NAMES: &[&str] = &["Query", "Mutation", "Subscription"];
+
+fn print_with_typename(source: &str) -> String {
+    print_with_root_types(source, ROOT_TYPE_NAMES)
+}
+
+fn print_with_root_types(source: &str, root_type_names: &[&str]) -> String {
+    let document = parse_operation_document(source).unwrap();
+    let (document, _) = resolve_operation_extensions(document).unwrap();
+    let document = add_typename(document, root_type_names);
+    let mut buf = String::new();
+    let mut writer = JustWriter::new(&mut buf);
+    document.print_graphql(&mut writer);
+    buf
+}
+
+#[test]
+fn nested_selections() {
+    assert_snapshot!(print_with_typename(
+        "
+query Q {
+    me {
+        id
+        posts {
+            title
+            ... on Post {
+                author { name }
+            }
+        }
+    }
+}
+"
+    ));
+}
+
+#[test]
+fn fragments() {
+    assert_snapshot!(print_with_typename(
+        "
+query Q {
+    ...F
+}
+fragment F on Query {
+    me {
+        ...G
+    }
+}
+fragment G on User {
+    name
+}
+"
+    ));
+}
+
+#[test]
+fn existing_typename() {
+    assert_snapshot!(print_with_typename(
+        "
+query Q {
+    __typename
+    me {
+        __typename
+        id
+        friend {
+            typename: __typename
+            id
+        }
+    }
+}
+"
+    ));
+}
+
+#[test]
+fn fragments_on_custom_root_types() {
+    assert_snapshot!(print_with_root_types(
+        "
+subscription S {
+    ...F
+}
+fragment F on RootSubscription {
+    postAdded {
+        id
+    }
+}
+fragment G on Subscription {
+    id
+}
+",
+        &["RootQuery", "RootSubscription"]
+    ));
+}
@@ -213,6 +213,10 @@ pub struct Field<Str, OriginalNode> {
     pub arguments: Vec<InputValue<Str, OriginalNode>>,
     /// If deprecated, contains the reason.
     pub deprecation: Option<Str>,
+    /// Levels of the type marked as semantically non-null by `@semanticNonNull`.
+    pub semantic_non_null_levels: Vec<usize>,
+    /// Brand given to the type by `@brand`.
+    pub brand: Option<Str>,
 }
 
 impl<Str, OriginalNode> Field<Str, OriginalNode>
@@ -226,6 +230,8 @@ where
             r#type: self.r#type.map_str(&f),
             arguments: self.arguments.iter().map(|x| x.map_str(&f)).collect(),
             deprecation: self.deprecation.as_ref().map(&f),
+            semantic_non_null_levels: self.semantic_non_null_levels.clone(),
+            brand: self.brand.as_ref().map(&f),
         }
     }
 }
@@ -40,6 +40,36 @@ where
     }
 }
 
+impl<Str, OriginalNode> Type<Str, OriginalNode>
+where
+    Str: Clone,
+    OriginalNode: Clone,
+{
+    /// Returns a copy of this type in which positions at given levels are non-null.
+    /// Level 0 is the type itself and each list wrapper adds one level.
+    pub fn with_non_null_levels(&self, levels: &[usize]) -> Self {
+        self.with_non_null_levels_impl(0, levels)
+    }
+
+    fn with_non_null_levels_impl(&self, level: usize, levels: &[usize]) -> Self {
+        let (ty, non_null) = match self {
+            Type::NonNull(inner) => (inner.as_inner(), true),
+            _ => (self, false),
+        };
+        let ty = match ty {
+            Type::List(inner) => Type::List(Box::new(ListType::from(
+                inner.with_non_null_levels_impl(level + 1, levels),
+            ))),
+            _ => ty.clone(),
+        };
+        if non_null || levels.contains(&level) {
+            Type::NonNull(Box::new(NonNullType::from(ty)))
+        } else {
+            ty
+        }
+    }
+}
+
 impl<Str: Display, OriginalNode> Display for Type<Str, OriginalNode> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
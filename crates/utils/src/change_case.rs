@@ -0,0 +1,140 @@
+use crate::capitalize;
+
+/// Converts given identifier to PascalCase.
+/// Leading underscores are kept as is.
+pub fn pascal_case(s: &str) -> String {
+    let (prefix, rest) = split_leading_underscores(s);
+    let words = split_words(rest)
+        .iter()
+        .map(|word| capitalize(&word.to_lowercase()))
+        .collect::<String>();
+    format!("{prefix}{words}")
+}
+
+/// Converts given identifier to camelCase.
+/// Leading underscores are kept as is.
+pub fn camel_case(s: &str) -> String {
+    let (prefix, rest) = split_leading_underscores(s);
+    let words = split_words(rest)
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            if index == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize(&word.to_lowercase())
+            }
+        })
+        .collect::<String>();
+    format!("{prefix}{words}")
+}
+
+/// Converts given identifier to SCREAMING_SNAKE_CASE.
+/// Leading underscores are kept as is.
+pub fn screaming_snake_case(s: &str) -> String {
+    let (prefix, rest) = split_leading_underscores(s);
+    let words = split_words(rest)
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    format!("{prefix}{words}")
+}
+
+/// Splits leading underscores from an identifier.
+/// They are kept so that e.g. `_value` and `value` do not become the same name.
+fn split_leading_underscores(s: &str) -> (&str, &str) {
+    let rest = s.trim_start_matches('_');
+    (&s[..s.len() - rest.len()], rest)
+}
+
+/// Splits an identifier into words.
+/// Words are separated by underscores and changes of case,
+/// e.g. `HTTPServer_error` is split into `HTTP`, `Server` and `error`.
+fn split_words(s: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let chars = s.char_indices().collect::<Vec<_>>();
+    let mut start = None;
+    for (i, &(index, c)) in chars.iter().enumerate() {
+        if c == '_' {
+            if let Some(start) = start.take() {
+                words.push(&s[start..index]);
+            }
+            continue;
+        }
+        let Some(word_start) = start else {
+            start = Some(index);
+            continue;
+        };
+        let prev = chars[i - 1].1;
+        let next = chars.get(i + 1).map(|&(_, c)| c);
+        let is_boundary = (prev.is_lowercase() || prev.is_ascii_digit()) && c.is_uppercase()
+            || prev.is_uppercase() && c.is_uppercase() && next.is_some_and(|c| c.is_lowercase());
+        if is_boundary {
+            words.push(&s[word_start..index]);
+            start = Some(index);
+        }
+    }
+    if let Some(start) = start {
+        words.push(&s[start..]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words("user"), vec!["user"]);
+        assert_eq!(split_words("NORMAL_USER"), vec!["NORMAL", "USER"]);
+        assert_eq!(split_words("normalUser"), vec!["normal", "User"]);
+        assert_eq!(split_words("NormalUser"), vec!["Normal", "User"]);
+        assert_eq!(split_words("snake__case_"), vec!["snake", "case"]);
+        assert_eq!(split_words("v2Api"), vec!["v2", "Api"]);
+    }
+
+    #[test]
+    fn test_split_words_acronyms() {
+        assert_eq!(split_words("HTTPServer"), vec!["HTTP", "Server"]);
+        assert_eq!(
+            split_words("HTTPServer_error"),
+            vec!["HTTP", "Server", "error"]
+        );
+        assert_eq!(
+            split_words("getHTTPResponse"),
+            vec!["get", "HTTP", "Response"]
+        );
+        assert_eq!(split_words("HTTP2Server"), vec!["HTTP2", "Server"]);
+        assert_eq!(split_words("URL"), vec!["URL"]);
+    }
+
+    #[test]
+    fn test_change_case() {
+        assert_eq!(pascal_case("NORMAL_USER"), "NormalUser");
+        assert_eq!(camel_case("NORMAL_USER"), "normalUser");
+        assert_eq!(screaming_snake_case("normalUser"), "NORMAL_USER");
+        assert_eq!(pascal_case("HTTPServer"), "HttpServer");
+        assert_eq!(camel_case("HTTPServer"), "httpServer");
+        assert_eq!(screaming_snake_case("HTTPServer"), "HTTP_SERVER");
+    }
+
+    #[test]
+    fn test_change_case_leading_underscores() {
+        assert_eq!(pascal_case("_internal_value"), "_InternalValue");
+        assert_eq!(camel_case("__INTERNAL"), "__internal");
+        assert_eq!(screaming_snake_case("_internalValue"), "_INTERNAL_VALUE");
+        // a name that would otherwise start with a digit stays a valid identifier
+        assert_eq!(pascal_case("_1st"), "_1st");
+        assert_eq!(camel_case("_"), "_");
+    }
+
+    #[test]
+    fn test_change_case_collisions() {
+        // different names may become the same name after conversion;
+        // users of these functions need to detect it
+        assert_eq!(pascal_case("FOO_BAR"), pascal_case("fooBar"));
+        assert_ne!(pascal_case("_foo"), pascal_case("foo"));
+    }
+}
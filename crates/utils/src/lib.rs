@@ -1,11 +1,15 @@
 mod capitalize;
+mod change_case;
 mod chars;
 mod clone_into;
 mod cwd;
 mod relative_path;
+mod suggestion;
 
 pub use capitalize::capitalize;
+pub use change_case::{camel_case, pascal_case, screaming_snake_case};
 pub use chars::{first_non_space_byte_index, skip_chars};
 pub use clone_into::clone_into;
 pub use cwd::get_cwd;
 pub use relative_path::{normalize_path, relative_path, resolve_relative_path};
+pub use suggestion::{did_you_mean, edit_distance};
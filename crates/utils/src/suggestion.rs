@@ -0,0 +1,109 @@
+/// Calculates the edit distance between two strings.
+/// Transposition of two adjacent characters counts as one edit.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // distances[i][j] is the distance between a[..i] and b[..j]
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in distances[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// Maximum number of suggestions returned by `did_you_mean`.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Finds candidates that look like a misspelling of given name.
+/// Returned names are ordered from the most similar one.
+pub fn did_you_mean<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let threshold = name.chars().count().max(3) / 3;
+    let lowercase_name = name.to_lowercase();
+    let mut matches: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        // internal names are not suggested unless user is looking for one
+        .filter(|candidate| !candidate.starts_with("__") || name.starts_with("__"))
+        .filter_map(|candidate| {
+            if candidate.to_lowercase() == lowercase_name {
+                return Some((0, candidate));
+            }
+            let distance = edit_distance(name, candidate);
+            (distance <= threshold).then_some((distance, candidate))
+        })
+        .collect();
+    matches.sort();
+    matches.dedup();
+    matches
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("name", "name"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("abc", ""), 3);
+        // substitution, insertion and deletion
+        assert_eq!(edit_distance("name", "nome"), 1);
+        assert_eq!(edit_distance("name", "names"), 1);
+        assert_eq!(edit_distance("names", "name"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        // transposition of adjacent characters
+        assert_eq!(edit_distance("nmae", "name"), 1);
+        assert_eq!(edit_distance("ab", "ba"), 1);
+        // distance is counted in characters, not bytes
+        assert_eq!(edit_distance("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let candidates = ["name", "names", "age", "email", "__typename"];
+        assert_eq!(did_you_mean("nmae", candidates), vec!["name"]);
+        assert_eq!(did_you_mean("emial", candidates), vec!["email"]);
+        // case-insensitive match counts as an exact match
+        assert_eq!(did_you_mean("NAMES", candidates), vec!["names"]);
+        assert_eq!(did_you_mean("zzz", candidates), Vec::<String>::new());
+        // exact match is not suggested
+        assert_eq!(did_you_mean("age", candidates), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_did_you_mean_internal_names() {
+        let candidates = ["typename", "__typename"];
+        // internal names are not suggested for ordinary names
+        assert_eq!(did_you_mean("typenam", candidates), vec!["typename"]);
+        assert_eq!(
+            did_you_mean("__typenam", candidates),
+            vec!["__typename", "typename"]
+        );
+    }
+
+    #[test]
+    fn test_did_you_mean_limit() {
+        let candidates = ["ab", "ac", "ad", "ae", "af"];
+        assert_eq!(did_you_mean("aa", candidates), vec!["ab", "ac", "ad"]);
+    }
+}
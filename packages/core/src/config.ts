@@ -4,6 +4,7 @@
 
 import { pathToFileURL } from "node:url";
 import { execFileSync } from "node:child_process";
+import { existsSync, readFileSync } from "node:fs";
 import { readString, utf8Len, writeString } from "./memory.js";
 import { getCommandClient } from "./command/commandClient.js";
 
@@ -40,6 +41,21 @@ stdout.write(JSON.stringify(config.default ?? config));
 `);
 }
 
+/**
+ * Reads given config file. Config files written in JavaScript are executed.
+ * @returns source of the config file, or undefined if the file does not exist.
+ */
+export function readConfigFileSync(
+  configFilePath: string,
+): string | undefined {
+  if (!existsSync(configFilePath)) {
+    return undefined;
+  }
+  return /\.[cm]?[jt]s$/.test(configFilePath)
+    ? executeConfigFileSync(configFilePath)
+    : readFileSync(configFilePath, "utf-8");
+}
+
 export type NitrogqlConfigNamespace = {
   /**
    * Executes given JavaScript (or TypeScript) code.
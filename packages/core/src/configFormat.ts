@@ -1,12 +1,21 @@
 export type NitrogqlConfig = {
+  /**
+   * Config file that this config file extends.
+   * Either a relative path or a path in a package.
+   */
+  extends?: string | undefined;
   /**
    * List of paths to schema files.
    */
-  schema: string | readonly string[];
+  schema?: string | readonly string[] | undefined;
   /**
    * List of paths to operation files.
    */
   documents?: string | readonly string[] | undefined;
+  /**
+   * List of patterns of files excluded from schema and operations.
+   */
+  exclude?: string | readonly string[] | undefined;
   extensions?:
     | (Record<string, unknown> & {
         nitrogql?: NitrogqlExtension | undefined;
@@ -32,6 +41,11 @@ export type NitrogqlExtension = {
    * List of plugins to use.
    */
   plugins?: readonly string[] | undefined;
+  /**
+   * Whether files ignored by git are excluded from schema and operations.
+   * @default false
+   */
+  respectGitignore?: boolean | undefined;
   /**
    * Config related to the 'generate' command.
    */
@@ -45,12 +59,18 @@ export type NitrogqlExtension = {
           | "with-loader-ts-5.0"
           | "with-loader-ts-4.0"
           | "standalone-ts-4.0"
+          | "client-preset"
           | undefined;
         /**
          * Path to the output schema type definition file.
          * Needed if you want to generate schema types.
          */
         schemaOutput?: string | undefined;
+        /**
+         * Path to the output module that contains all operations.
+         * Required in the client-preset mode.
+         */
+        clientPresetOutput?: string | undefined;
         /**
          * Path to the output GraphQl source file for use by a GraphQL server.
          * Allows you to emit processed GraphQL source as one string.
@@ -62,6 +82,11 @@ export type NitrogqlExtension = {
          * Needed if you want to generate resolvers types.
          */
         resolversOutput?: string | undefined;
+        /**
+         * Path to the output file that contains Zod validators
+         * of input types and operation variables.
+         */
+        validatorsOutput?: string | undefined;
         /**
          * Module specifier for importing schema types from operations.
          * Defaults to relative paths if not specified.
@@ -76,12 +101,66 @@ export type NitrogqlExtension = {
                * Mapping from GraphQL scalar types to TypeScript types.
                */
               scalarTypes?: Record<string, ScalarTypeConfig> | undefined;
+              /**
+               * Mapping from GraphQL scalar types to Zod validators.
+               * Used for `generate.validatorsOutput`.
+               */
+              scalarValidators?: Record<string, string> | undefined;
               /**
                * Whether to allow undefined as input value
                * for nullable fields.
                * @default true
                */
               allowUndefinedAsOptionalInput?: boolean | undefined;
+              /**
+               * Whether fragment spreads are masked in result types.
+               * Fields selected by a fragment can then be read only through
+               * `useFragment` from `@nitrogql/core/fragment`.
+               * @default false
+               */
+              fragmentMasking?: boolean | undefined;
+              /**
+               * Whether the client throws on field errors instead of
+               * returning null. If true, positions marked by
+               * `@semanticNonNull` are typed as non-null in operation results.
+               * @default false
+               */
+              throwOnFieldError?: boolean | undefined;
+              /**
+               * Whether to add a catch-all branch to result types of
+               * unions and interfaces for object types unknown at build time.
+               * @default false
+               */
+              futureProofAbstractTypes?: boolean | undefined;
+              /**
+               * Scalars whose TypeScript types are branded with their names.
+               * @default []
+               */
+              brandedScalars?: string[] | undefined;
+              /**
+               * How enums are emitted.
+               * `union` emits unions of string literal types.
+               * `union-with-fallback` also allows unknown strings in operation results.
+               * `const-object` additionally emits a const object for each enum.
+               * `enum` emits TypeScript enums.
+               * Defaults to `const-object` if `emitSchemaRuntime` is true and `union` otherwise.
+               */
+              enumStyle?:
+                | "union"
+                | "union-with-fallback"
+                | "const-object"
+                | "enum"
+                | undefined;
+              /**
+               * Casing of member names of TypeScript enums and keys of const objects.
+               * @default "keep"
+               */
+              enumMemberCase?:
+                | "keep"
+                | "pascal-case"
+                | "camel-case"
+                | "screaming-snake-case"
+                | undefined;
             }
           | undefined;
         /**
@@ -151,12 +230,97 @@ export type NitrogqlExtension = {
               variablesType?: boolean | undefined;
             }
           | undefined;
+        /**
+         * Config related to the generated resolvers file.
+         */
+        resolvers?:
+          | {
+              /**
+               * Name of the root resolvers type.
+               * @default "Resolvers"
+               */
+              rootTypeName?: string | undefined;
+              /**
+               * Name of the resolver output type.
+               * @default "ResolverOutput"
+               */
+              outputTypeName?: string | undefined;
+              /**
+               * Name of the namespace that schema types are imported as.
+               * @default "Schema"
+               */
+              schemaNamespace?: string | undefined;
+              /**
+               * Default type of context passed to resolvers.
+               */
+              contextType?: TypeImport | undefined;
+              /**
+               * Type of the `info` argument of resolvers.
+               * Defaults to `GraphQLResolveInfo` from `graphql`.
+               */
+              infoType?: TypeImport | undefined;
+            }
+          | undefined;
         /**
          * Whether to generate runtime code for schema types.
          * If true, an object is emitted for each enum.
          * @default false
          */
         emitSchemaRuntime?: boolean | undefined;
+        /**
+         * Whether to insert `__typename` into every selection set of operations
+         * except root operation selections.
+         * Useful for clients with normalized caches, such as Apollo Client and urql.
+         * @default false
+         */
+        addTypename?: boolean | undefined;
+        /**
+         * Format of operation documents emitted as runtime values.
+         * `document-node` emits a JSON `DocumentNode` typed as `TypedDocumentNode`.
+         * `string` emits printed GraphQL source typed as `TypedDocumentString`.
+         * `minified-string` is `string` without insignificant whitespace.
+         * @default "document-node"
+         */
+        documentFormat?:
+          | "document-node"
+          | "string"
+          | "minified-string"
+          | undefined;
       }
     | undefined;
+  /**
+   * Overrides of 'generate' config for operation files matching patterns.
+   * The most specific matching override wins.
+   */
+  overrides?: readonly GenerateOverride[] | undefined;
+};
+
+/**
+ * A type exported from a module.
+ */
+export type TypeImport = {
+  /**
+   * Module specifier to import the type from.
+   */
+  module: string;
+  /**
+   * Exported name of the type.
+   */
+  name: string;
+};
+
+type GenerateConfig = NonNullable<NitrogqlExtension["generate"]>;
+
+/**
+ * Override of 'generate' config for specific files.
+ */
+export type GenerateOverride = {
+  /**
+   * Pattern(s) of operation files relative to the config file.
+   */
+  files: string | readonly string[];
+  generate: Pick<
+    GenerateConfig,
+    "mode" | "schemaModuleSpecifier" | "name" | "export"
+  >;
 };
@@ -0,0 +1,48 @@
+/**
+ * Runtime helpers for fragment masking (`generate.type.fragmentMasking`).
+ *
+ * With fragment masking, a fragment spread in a result type becomes a masked
+ * reference to the fragment. `useFragment` turns such a reference into the
+ * data of the fragment.
+ */
+
+/**
+ * Document of a fragment. Compatible with `TypedDocumentNode` from
+ * `@graphql-typed-document-node/core`.
+ */
+export type FragmentDocument<Fragment> = {
+  readonly __apiType?: (variables: never) => Fragment;
+};
+
+/**
+ * Masked reference to given fragment type.
+ */
+export type FragmentRef<Fragment> = Fragment extends {
+  readonly " $fragmentName"?: infer Name extends string;
+}
+  ? { readonly " $fragmentRefs": { readonly [K in Name]: true } }
+  : never;
+
+/**
+ * Reads data of a fragment from a masked reference.
+ * This is an identity function at runtime; it only changes the type.
+ */
+export function useFragment<Fragment>(
+  fragment: FragmentDocument<Fragment>,
+  data: FragmentRef<Fragment>,
+): Fragment;
+export function useFragment<Fragment>(
+  fragment: FragmentDocument<Fragment>,
+  data: FragmentRef<Fragment> | null | undefined,
+): Fragment | null | undefined;
+export function useFragment<Fragment>(
+  fragment: FragmentDocument<Fragment>,
+  data: readonly FragmentRef<Fragment>[],
+): readonly Fragment[];
+export function useFragment<Fragment>(
+  fragment: FragmentDocument<Fragment>,
+  data: readonly FragmentRef<Fragment>[] | null | undefined,
+): readonly Fragment[] | null | undefined;
+export function useFragment(_fragment: unknown, data: unknown): unknown {
+  return data;
+}
@@ -2,6 +2,7 @@ import {
   initConfigNamespace,
   executeConfigFileSync,
   executeNodeSync,
+  readConfigFileSync,
 } from "./config.js";
 import { NitrogqlConfig, NitrogqlExtension } from "./configFormat.js";
 import { loadSchemaJs } from "./loader.js";
@@ -15,6 +16,7 @@ export {
   initConfigNamespace,
   executeNodeSync,
   executeConfigFileSync,
+  readConfigFileSync,
   loadSchemaJs,
 };
 
@@ -2,12 +2,14 @@
 
 import path from "node:path";
 import { readFile } from "node:fs/promises";
-import { executeConfigFileSync } from "@nitrogql/core";
+import { executeConfigFileSync, readConfigFileSync } from "@nitrogql/core";
 import { init } from "@nitrogql/loader-core";
 
 const { initiateTask, getLog } = await init();
 
 let lastLoadedConfigPath = undefined;
+/** @type {string[]} */
+let lastLoadedConfigFiles = [];
 
 /**
  * @type {import('webpack').LoaderDefinitionFunction}
@@ -29,9 +31,17 @@ export default function graphQLLoader(source) {
       const configFileSource = configFileIsJS(configFilePath)
         ? executeConfigFileSync(configFilePath)
         : await readFile(configFilePath, "utf-8");
-      task.loadConfig(configFileSource);
+      lastLoadedConfigFiles = task.loadConfig(
+        configFilePath,
+        configFileSource,
+        readConfigFileSync,
+      );
     }
     lastLoadedConfigPath = configFilePath;
+    // config files extended by the config file are also dependencies
+    for (const file of lastLoadedConfigFiles) {
+      this.addDependency(file);
+    }
 
     // Load all required files
     while (true) {
@@ -42,8 +52,15 @@ export default function graphQLLoader(source) {
           await Promise.all(
             requiredFiles.map(async (requiredFile) => {
               this.addDependency(requiredFile);
-              const requiredFileSource = await readFile(requiredFile, "utf-8");
-              task.supplyFile(requiredFile, requiredFileSource);
+              const requiredFileSource = await readFile(
+                requiredFile,
+                "utf-8",
+              ).catch(ignoreNotFound);
+              if (requiredFileSource === undefined) {
+                task.supplyMissingFile(requiredFile);
+              } else {
+                task.supplyFile(requiredFile, requiredFileSource);
+              }
             }),
           );
           break;
@@ -70,6 +87,17 @@ export default function graphQLLoader(source) {
     });
 }
 
+/**
+ * @param {unknown} error
+ * @returns {undefined}
+ */
+function ignoreNotFound(error) {
+  if (error instanceof Error && "code" in error && error.code === "ENOENT") {
+    return undefined;
+  }
+  throw error;
+}
+
 function configFileIsJS(configFile) {
   return /\.[cm]?[jt]s$/.test(configFile);
 }
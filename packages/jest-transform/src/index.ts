@@ -1,10 +1,11 @@
-import { readFileSync } from "node:fs";
+import { existsSync, readFileSync } from "node:fs";
+import path from "node:path";
 import type {
   SyncTransformer,
   TransformedSource,
   TransformerCreator,
 } from "@jest/transform";
-import { executeConfigFileSync } from "@nitrogql/core";
+import { executeConfigFileSync, readConfigFileSync } from "@nitrogql/core";
 import { init } from "@nitrogql/loader-core";
 
 export type TransformerConfig = {
@@ -46,7 +47,11 @@ const createTransformer: TransformerCreator<
         const configFileSource = configFileIsJS(configFile)
           ? executeConfigFileSync(configFile)
           : readFileSync(configFile, "utf-8");
-        task.loadConfig(configFileSource);
+        task.loadConfig(
+          path.resolve(configFile),
+          configFileSource,
+          readConfigFileSync,
+        );
       }
       lastLoadedConfigPath = configFile;
 
@@ -57,6 +62,10 @@ const createTransformer: TransformerCreator<
           case "fileRequired": {
             const requiredFiles = status.files;
             for (const requiredFile of requiredFiles) {
+              if (!existsSync(requiredFile)) {
+                task.supplyMissingFile(requiredFile);
+                continue;
+              }
               const requiredFileSource = readFileSync(requiredFile, "utf-8");
               task.supplyFile(requiredFile, requiredFileSource);
             }
@@ -24,16 +24,58 @@ export class Task {
   }
 
   /**
-   * Load configuration from given JSON string.
+   * Load configuration from given config file.
+   * Config files it extends are read by `readConfigFile`, which returns undefined for nonexistent files.
+   * Paths in the config are relative to the directory of the config file.
    * TODO: in current implementation config is internally shared among all tasks.
+   * @returns paths of all files read for loading the config.
    */
-  loadConfig(configString: string) {
-    const configFilePathString = this.#bin.alloc.allocString(configString);
+  loadConfig(
+    configFilePath: string,
+    configString: string,
+    readConfigFile: (filePath: string) => string | undefined,
+  ): string[] {
+    const pathString = this.#bin.alloc.allocString(configFilePath);
+    const configSourceString = this.#bin.alloc.allocString(configString);
     this.#bin.exports.load_config(
-      configFilePathString.ptr,
-      configFilePathString.size,
+      pathString.ptr,
+      pathString.size,
+      configSourceString.ptr,
+      configSourceString.size,
     );
-    configFilePathString.free();
+    pathString.free();
+    configSourceString.free();
+    const loadedFiles = [configFilePath];
+    while (true) {
+      if (!this.#bin.exports.get_required_config_files()) {
+        throw new WasmError("graphql-loader failed to load config", this.#bin);
+      }
+      const requiredFiles = this.#bin.readResult().split("\n").filter(Boolean);
+      if (requiredFiles.length === 0) {
+        return loadedFiles;
+      }
+      for (const requiredFile of requiredFiles) {
+        const source = readConfigFile(requiredFile);
+        const fileString = this.#bin.alloc.allocString(requiredFile);
+        if (source === undefined) {
+          this.#bin.exports.load_missing_config_file(
+            fileString.ptr,
+            fileString.size,
+          );
+        } else {
+          loadedFiles.push(requiredFile);
+          const sourceString = this.#bin.alloc.allocString(source);
+          this.#bin.exports.load_config_file(
+            fileString.ptr,
+            fileString.size,
+            sourceString.ptr,
+            sourceString.size,
+          );
+          sourceString.free();
+        }
+        fileString.free();
+      }
+    }
   }
 
   /**
@@ -79,6 +121,25 @@ export class Task {
     }
   }
 
+  /**
+   * Tell the task that a required file does not exist.
+   */
+  supplyMissingFile(filePath: string) {
+    const filePathString = this.#bin.alloc.allocString(filePath);
+    try {
+      const result = this.#bin.exports.load_missing_file(
+        this.taskId,
+        filePathString.ptr,
+        filePathString.size,
+      );
+      if (!result) {
+        throw new WasmError("graphql-loader failed to load file", this.#bin);
+      }
+    } finally {
+      filePathString.free();
+    }
+  }
+
   /**
    * Emit JavaScript.
    */
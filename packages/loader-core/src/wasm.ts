@@ -8,7 +8,20 @@ export type WASMInterface = {
   free_string(ptr: number, size: number): void;
   get_result_ptr(): number;
   get_result_size(): number;
-  load_config(input_ptr: number, input_size: number): number;
+  load_config(
+    path_ptr: number,
+    path_size: number,
+    input_ptr: number,
+    input_size: number,
+  ): void;
+  get_required_config_files(): number;
+  load_config_file(
+    filename_ptr: number,
+    filename_size: number,
+    input_ptr: number,
+    input_size: number,
+  ): void;
+  load_missing_config_file(filename_ptr: number, filename_size: number): void;
   initiate_task(
     filename_ptr: number,
     filename_size: number,
@@ -23,6 +36,11 @@ export type WASMInterface = {
     input_ptr: number,
     input_size: number,
   ): number;
+  load_missing_file(
+    task_id: number,
+    filename_ptr: number,
+    filename_size: number,
+  ): number;
   emit_js(task_id: number): number;
   free_task(task_id: number): void;
   get_log(): void;
@@ -2,13 +2,15 @@
 
 import path from "node:path";
 import { readFile } from "node:fs/promises";
-import { executeConfigFileSync } from "@nitrogql/core";
+import { executeConfigFileSync, readConfigFileSync } from "@nitrogql/core";
 import { init } from "@nitrogql/loader-core";
 import { createFilter } from "@rollup/pluginutils";
 
 const { initiateTask, getLog } = await init();
 
 let lastLoadedConfigPath = undefined;
+/** @type {string[]} */
+let lastLoadedConfigFiles = [];
 
 /**
  * @type {import('rollup').PluginImpl<{
@@ -45,9 +47,17 @@ export default function NitrogqlRollupPlugin(options) {
         const configFileSource = configFileIsJS(configFilePath)
           ? executeConfigFileSync(configFilePath)
           : await readFile(configFilePath, "utf-8");
-        task.loadConfig(configFileSource);
+        lastLoadedConfigFiles = task.loadConfig(
+          configFilePath,
+          configFileSource,
+          readConfigFileSync,
+        );
       }
       lastLoadedConfigPath = configFilePath;
+      // config files extended by the config file are also watched
+      for (const file of lastLoadedConfigFiles) {
+        this.addWatchFile(file);
+      }
 
       while (true) {
         const status = task.status();
@@ -60,8 +70,12 @@ export default function NitrogqlRollupPlugin(options) {
                 const requiredFileSource = await readFile(
                   requiredFile,
                   "utf-8",
-                );
-                task.supplyFile(requiredFile, requiredFileSource);
+                ).catch(ignoreNotFound);
+                if (requiredFileSource === undefined) {
+                  task.supplyMissingFile(requiredFile);
+                } else {
+                  task.supplyFile(requiredFile, requiredFileSource);
+                }
               }),
             );
             break;
@@ -77,6 +91,17 @@ export default function NitrogqlRollupPlugin(options) {
   };
 }
 
+/**
+ * @param {unknown} error
+ * @returns {undefined}
+ */
+function ignoreNotFound(error) {
+  if (error instanceof Error && "code" in error && error.code === "ENOENT") {
+    return undefined;
+  }
+  throw error;
+}
+
 function configFileIsJS(configFile) {
   return /\.[cm]?[jt]s$/.test(configFile);
 }
@@ -1,3 +1,4 @@
+import Link from "next/link";
 import { Hint } from "@/app/_utils/Hint";
 import { Highlight } from "@/app/_utils/Highlight";
 import { Toc } from "../_toc";
@@ -33,11 +34,20 @@ export default function CLIUsage() {
             <code>generate</code>: Generate types from your schema and
             operations.
           </li>
+          <li>
+            <code>explain</code>: Print a detailed explanation of given error
+            codes (e.g. <code>nitrogql explain NGQL0218</code>).
+          </li>
         </ul>
         <Hint>
           💡 <code>generate</code> also implies <code>check</code>. GraphQL code
           is checked before types are generated.
         </Hint>
+        <p>
+          Every error reported by nitrogql has a stable error code such as{" "}
+          <code>NGQL0218</code>. Pass the code to <code>explain</code> to learn
+          more about the error.
+        </p>
 
         <h3 id="options">options</h3>
 
@@ -66,6 +76,21 @@ export default function CLIUsage() {
           operation path specified in the configuration file.
         </p>
 
+        <h4 id="project">
+          <code>--project</code>
+        </h4>
+        <p>
+          Specify the name of the project to run commands for, when the
+          configuration file has{" "}
+          <Link href="/configuration/options#projects">
+            <code>projects</code>
+          </Link>
+          . By default, commands are run for all projects and the results are
+          reported per project. A configuration file without{" "}
+          <code>projects</code> has one project named <code>default</code>.
+        </p>
+        <Highlight language="bash">{`npx nitrogql check --project admin`}</Highlight>
+
         <h4 id="output-format">
           <code>--output-format</code>
         </h4>
@@ -84,14 +109,33 @@ export default function CLIUsage() {
               rdjson
             </a>{" "}
             format to stdout. This is useful for integrating with reviewdog.
-            Makes sense only when the <code>check</code> command is run.
+            The category of each diagnostic is included in its source name,
+            e.g. <code>nitrogql/schema</code>. Makes sense only when the{" "}
+            <code>check</code> command is run.
+          </li>
+          <li>
+            <code>sarif</code>: prints &apos;check&apos; results in{" "}
+            <a
+              href="https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html"
+              target="_blank"
+            >
+              SARIF 2.1.0
+            </a>{" "}
+            format to stdout. Every error code is listed as a rule of the tool,
+            and errors without a code are reported under the{" "}
+            <code>nitrogql</code> rule. File paths are relative to the
+            directory of the configuration file. Makes sense only when the <code>check</code> command is run.
           </li>
           <li>
             <code>json</code>: prints nitrogql specific JSON output to stdout.
           </li>
         </ul>
         <p>
-          The signature of the <code>json</code> output is:
+          The signature of the <code>json</code> output is as follows. When the
+          configuration file has <code>projects</code>, the output is instead{" "}
+          <code>{`{ projects: (CLIOutput & { name: string })[] }`}</code>, with
+          one entry for each project. In the <code>sarif</code> output, each
+          project is reported as a separate run.
         </p>
         <Highlight language="typescript">{`interface CLIOutput {
   /**
@@ -102,6 +146,11 @@ export default function CLIUsage() {
      * Command name that had error.
      */
     command: string | null;
+    /**
+     * Error code such as "NGQL0403".
+     */
+    code: string | null;
+    category: "config" | "cli" | null;
     /**
      * Error message.
      */
@@ -123,9 +172,37 @@ export default function CLIUsage() {
         line: number;
         column: number;
       }
+      /**
+       * Error code such as "NGQL0218".
+       */
+      code: string | null;
+      /**
+       * Where the error was found.
+       */
+      category: "syntax" | "schema" | "operation" | "plugin" | null;
+      /**
+       * Structured data of the error, such as the names of involved types and fields.
+       */
+      fields: Record<string, string>;
+      /**
+       * Names suggested as a fix for this error.
+       */
+      suggestions: string[];
       message: string;
     }[]
   }
+  /**
+   * Exists when the 'explain' command is run.
+   */
+  explain?: {
+    codes: {
+      code: string;
+      category: "syntax" | "schema" | "operation" | "plugin" | "config" | "cli";
+      name: string;
+      summary: string;
+      explanation: string;
+    }[];
+  }
   /**
    * Exists when the 'generate' command is run.
    */
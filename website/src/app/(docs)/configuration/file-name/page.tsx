@@ -108,6 +108,34 @@ const config: NitrogqlConfig = {
 
 export default config;`}
         </Highlight>
+
+        <h3 id="validation">Validation and editor support</h3>
+        <p>
+          The configuration file is validated when it is loaded. Unknown keys
+          at the top level and under <code>extensions.nitrogql</code> are
+          reported as errors with their location in the file, along with a
+          suggestion if the key looks like a misspelled option. Keys under
+          extensions for other tools are not checked.
+        </p>
+        <p>
+          The <code>@nitrogql/cli</code> package ships a JSON Schema of the
+          configuration file so that your editor can complete and validate it.
+          For a YAML configuration file, add a comment for the YAML language
+          server:
+        </p>
+        <Highlight language="yaml">
+          {`# yaml-language-server: $schema=./node_modules/@nitrogql/cli/config-schema.json
+schema: "./schema/*.graphql"`}
+        </Highlight>
+        <p>
+          For a JSON configuration file, use the <code>$schema</code> key:
+        </p>
+        <Highlight language="json">
+          {`{
+  "$schema": "./node_modules/@nitrogql/cli/config-schema.json",
+  "schema": "./schema/*.graphql"
+}`}
+        </Highlight>
       </main>
     </Toc>
   );
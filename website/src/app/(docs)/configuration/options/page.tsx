@@ -55,12 +55,118 @@ documents:
         </p>
         <p>
           <strong>
-            <code>schema</code> is always required.
+            <code>schema</code> is required
           </strong>{" "}
+          unless <a href="#schemaEntry">
+            <code>schemaEntry</code>
+          </a>{" "}
+          is specified.{" "}
           <code>documents</code> is optional. If you only have schema, you can
           still use nitrogql to check your schema.
         </p>
 
+        <h4 id="exclude">Excluding files</h4>
+        <p>
+          A pattern starting with <code>!</code> in <code>schema</code> or{" "}
+          <code>documents</code> excludes files matched by it. Patterns in the{" "}
+          <code>exclude</code> top-level field exclude files from both schema
+          and operations.
+        </p>
+        <Highlight language="yaml">
+          {`schema: "./schema/*.graphql"
+documents:
+  - "./app/**/*.graphql"
+  - "!**/__generated__/**"
+exclude: "./test/fixtures/**"`}
+        </Highlight>
+        <p>
+          To also exclude files ignored by git, enable{" "}
+          <a href="#respectGitignore">
+            <code>respectGitignore</code>
+          </a>
+          .
+        </p>
+        <p>
+          A warning is reported if a pattern in <code>schema</code> or{" "}
+          <code>documents</code> matches no files after exclusion, as it usually
+          means a typo in the configuration.
+        </p>
+
+        <h4 id="projects">projects</h4>
+        <p>
+          To have more than one schema in a repository, use the{" "}
+          <code>projects</code> top-level field. Each project has its own{" "}
+          <code>schema</code>, <code>documents</code> and{" "}
+          <code>extensions.nitrogql</code>. Top-level <code>schema</code> and{" "}
+          <code>documents</code> are ignored when <code>projects</code> is
+          specified.
+        </p>
+        <Highlight language="yaml">
+          {`projects:
+  public:
+    schema: "./public/schema/*.graphql"
+    documents: "./public/app/**/*.graphql"
+    extensions:
+      nitrogql:
+        generate:
+          schemaOutput: "./public/generated/schema.d.ts"
+  admin:
+    schema: "./admin/schema/*.graphql"
+    documents: "./admin/app/**/*.graphql"
+    extensions:
+      nitrogql:
+        generate:
+          schemaOutput: "./admin/generated/schema.d.ts"`}
+        </Highlight>
+        <p>
+          The CLI runs commands for all projects unless the{" "}
+          <Link href="/cli#project">
+            <code>--project</code>
+          </Link>{" "}
+          option is given. Loaders and plugins for bundlers use the first
+          project whose <code>documents</code> include the loaded file. Files
+          not included in any project use the project named{" "}
+          <code>default</code>.
+        </p>
+
+        <h4 id="extends">extends</h4>
+        <p>
+          A configuration file can extend another configuration file with the{" "}
+          <code>extends</code> top-level field. The value is either a relative
+          path from the configuration file or a path in a package (such as{" "}
+          <code>@company/graphql-config/base</code>), which respects{" "}
+          <code>exports</code> in the package&apos;s <code>package.json</code>.
+          An extended configuration file can extend yet another one.
+        </p>
+        <Highlight language="yaml">
+          {`# packages/web/graphql.config.yaml
+extends: ../../graphql.config.base.yaml
+schema: ./schema/*.graphql
+documents: ./src/**/*.graphql`}
+        </Highlight>
+        <p>The configurations are merged as follows:</p>
+        <ul>
+          <li>
+            Objects are merged deeply; other values in the extending
+            configuration replace those in the extended one.
+          </li>
+          <li>
+            <code>plugins</code> and <code>overrides</code> are concatenated,
+            with those of the extended configuration first.
+          </li>
+          <li>
+            <code>generate.type.scalarTypes</code> is merged per scalar type; a
+            scalar type defined in the extending configuration replaces the
+            whole definition of that scalar type.
+          </li>
+          <li>Projects are merged by name.</li>
+        </ul>
+        <p>
+          Relative paths are resolved against the configuration file that
+          defines them. For example, <code>schemaOutput</code> defined in an
+          extended configuration file is relative to that file.
+        </p>
+
         <h4 id="schema-file-types">Schema file types</h4>
         <p>Nitrogql supports three types of schema files:</p>
         <ul>
@@ -123,6 +229,106 @@ documents:
       - nitrogql:model-plugin`}
         </Highlight>
 
+        <h3 id="paths">paths</h3>
+        <p>
+          Aliases for paths in the{" "}
+          <Link href="/references/syntax-import">
+            <code>#import</code> syntax
+          </Link>
+          . The format is the same as <code>compilerOptions.paths</code> of
+          tsconfig.json: each key is a pattern that may contain one{" "}
+          <code>*</code>, and each value is a target path (or a list of target
+          paths) relative to the configuration file.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    paths:
+      "~/*": "./src/*"
+      "@fragments/*":
+        - "./fragments/*"
+        - "./shared/fragments/*"`}
+        </Highlight>
+        <p>
+          When more than one pattern matches, the one with the longest prefix
+          is used. When a pattern has multiple targets, the first one that
+          exists is used.
+        </p>
+
+        <h3 id="schemaEntry">schemaEntry</h3>
+        <p>
+          Path to the entry file of a modular schema. When specified, schema
+          files are loaded by following the{" "}
+          <Link href="/references/syntax-import#modular-schema">
+            <code>#import</code> syntax
+          </Link>{" "}
+          from this file, and the <code>schema</code> field may be omitted.
+          Each schema file must import types and directives defined in other
+          files before using them.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`documents: "./app/**/*.graphql"
+extensions:
+  nitrogql:
+    schemaEntry: "./schema/index.graphql"`}
+        </Highlight>
+        <p>
+          This option only supports GraphQL files. Passing{" "}
+          <code>--schema</code> on the command line overrides this option.
+        </p>
+
+        <h3 id="respectGitignore">respectGitignore</h3>
+        <p>
+          When set to <code>true</code>, files ignored by <code>.gitignore</code>{" "}
+          files are excluded from schema and operations. <code>.gitignore</code>{" "}
+          files are read from the root of the git repository down to each file.
+          Defaults to <code>false</code>.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    respectGitignore: true`}
+        </Highlight>
+
+        <h3 id="check.noImportCycles">check.noImportCycles</h3>
+        <p>
+          When set to <code>true</code>, the <code>check</code> command reports
+          an error when operation documents import each other through the{" "}
+          <Link href="/references/syntax-import">
+            <code>#import</code> syntax
+          </Link>
+          . The error shows the files that form the cycle. Defaults to{" "}
+          <code>false</code>.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    check:
+      noImportCycles: true`}
+        </Highlight>
+
+        <h3 id="check.noUnusedImports">check.noUnusedImports</h3>
+        <p>
+          When set to <code>true</code>, the <code>check</code> command reports
+          an error when a fragment imported with the{" "}
+          <Link href="/references/syntax-import">
+            <code>#import</code> syntax
+          </Link>{" "}
+          is never spread in the importing document, or when a wildcard import
+          provides no fragment that is spread. Defaults to <code>false</code>.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    check:
+      noUnusedImports: true`}
+        </Highlight>
+
         <h3 id="generate.schemaOutput">generate.schemaOutput</h3>
         <p>
           Where to output the generated schema types. Generated file is depended
@@ -211,6 +417,51 @@ extensions:
       resolversOutput: "./app/generated/resolvers.ts"`}
         </Highlight>
 
+        <h3 id="generate.validatorsOutput">generate.validatorsOutput</h3>
+        <p>
+          When set, the <code>generate</code> command will generate a single
+          TypeScript file which contains{" "}
+          <a href="https://zod.dev/" target="_blank">
+            Zod
+          </a>{" "}
+          validators for input types, enums and scalars in the schema, as
+          well as for variables of every named operation in{" "}
+          <code>documents</code>. This is useful for validating untrusted
+          input, such as form data, before sending it as variables.
+        </p>
+        <p>
+          Validators are exported under the names of schema types, and
+          validators of variables are named like{" "}
+          <code>SearchUsersVariables</code> following{" "}
+          <Link href="#generate.name.variablesTypeSuffix">
+            <code>generate.name.variablesTypeSuffix</code>
+          </Link>
+          . Since all validators are exported from one file, two operations
+          in <code>documents</code> with the same name, or an operation whose
+          validator name equals a schema type name, are reported as an error.
+          Custom scalars are validated by{" "}
+          <Link href="#generate.type.scalarValidators">
+            <code>generate.type.scalarValidators</code>
+          </Link>
+          .
+        </p>
+        <p>
+          This file imports <code>zod</code> and the generated schema types.
+          Therefore, you need to install <code>zod</code> and configure either{" "}
+          <code>generate.schemaOutput</code> or{" "}
+          <code>generate.schemaModuleSpecifier</code> to use this option.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`schema: "./schema/*.graphql"
+documents: "./app/**/*.graphql"
+extensions:
+  nitrogql:
+    generate:
+      schemaOutput: "./app/generated/schema.d.ts"
+      validatorsOutput: "./app/generated/validators.ts"`}
+        </Highlight>
+
         <h3 id="generate.mode">generate.mode</h3>
         <p>
           Configures how types for operations are generated. Possible values
@@ -226,6 +477,9 @@ extensions:
           <li>
             <code>standalone-ts-4.0</code>
           </li>
+          <li>
+            <code>client-preset</code>
+          </li>
         </ul>
         <p>Example:</p>
         <Highlight language="yaml">
@@ -285,6 +539,68 @@ extensions:
           runtime code so you do not need to configure your bundler.
         </p>
 
+        <h4>client-preset</h4>
+        <p>
+          Generates a single TypeScript module which contains all operations.
+          The module is written to the path given by{" "}
+          <Link href="#generate.clientPresetOutput">
+            <code>generate.clientPresetOutput</code>
+          </Link>
+          . This mode is useful when you cannot configure a loader for{" "}
+          <code>.graphql</code> files.
+        </p>
+        <p>
+          The generated module exports each operation as a{" "}
+          <code>TypedDocumentNode</code> along with a <code>graphql</code>{" "}
+          function. Passing the content of an operation file to{" "}
+          <code>graphql</code> returns the document defined in that file.
+          The return type is inferred when the given string is exactly the
+          content of the file. At runtime, comments, including{" "}
+          <code>#import</code> lines, and differences in whitespace are ignored
+          when looking up documents.
+        </p>
+        <Highlight language="typescript">
+          {`import { graphql } from "./app/generated/graphql";
+
+const query = graphql(\`#import UserProfile from "./user-profile.graphql"
+query Me {
+  me {
+    id
+    ...UserProfile
+  }
+}
+\`);`}
+        </Highlight>
+        <p>
+          Since all operations and fragments are exported by name from one
+          module, two files cannot define operations or fragments with the
+          same name.
+        </p>
+        <Hint>
+          💡 Only files that contain exactly one operation (or exactly one
+          fragment and no operation) can be looked up with <code>graphql</code>
+          . Other files are reported as a warning. Their operations are still
+          exported by name.
+        </Hint>
+
+        <h3 id="generate.clientPresetOutput">generate.clientPresetOutput</h3>
+        <p>
+          Path to the TypeScript module generated by the{" "}
+          <code>client-preset</code> mode. Required when{" "}
+          <code>generate.mode</code> is <code>client-preset</code>.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`schema: "./schema/*.graphql"
+documents: "./app/**/*.graphql"
+extensions:
+  nitrogql:
+    generate:
+      mode: client-preset
+      schemaOutput: "./app/generated/schema.d.ts"
+      clientPresetOutput: "./app/generated/graphql.ts"`}
+        </Highlight>
+
         <h3 id="generate.schemaModuleSpecifier">
           generate.schemaModuleSpecifier
         </h3>
@@ -358,6 +674,78 @@ export const UserType = {
   PremiumUser: "PremiumUser",
 } as const;`}
         </Highlight>
+        <p>
+          How enums are emitted can be changed by the{" "}
+          <a href="#generate.type.enumStyle">enumStyle</a> option.
+        </p>
+
+        <h3 id="generate.addTypename">generate.addTypename</h3>
+        <p>
+          If <code>true</code>, <code>__typename</code> is inserted into every
+          selection set of operations and fragments, except the root selection
+          set of each operation. Default is <code>false</code>.
+        </p>
+        <p>
+          This is useful for GraphQL clients with normalized caches, such as
+          Apollo Client and urql, which need <code>__typename</code> of every
+          object. Inserted fields are included both in the runtime documents
+          (including those emitted by the webpack loader) and in generated
+          result types, where <code>__typename</code> is typed as a union of
+          possible type names.
+        </p>
+        <p>
+          Fragments on root operation types are treated like the root selection
+          set of an operation. Note that the webpack loader does not read the
+          schema, so it always assumes the default root type names{" "}
+          <code>Query</code>, <code>Mutation</code> and{" "}
+          <code>Subscription</code>. If your schema renames root types with a{" "}
+          <code>schema</code> definition, runtime documents emitted by the
+          loader get <code>__typename</code> in fragments on the renamed root
+          types, while the generated types do not have it.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      addTypename: true`}
+        </Highlight>
+
+        <h3 id="generate.documentFormat">generate.documentFormat</h3>
+        <p>
+          Configures the format of operation documents emitted as runtime
+          values. This applies to the <code>standalone-ts-4.0</code> and{" "}
+          <code>client-preset</code> modes as well as to the webpack loader.
+          Possible values are:
+        </p>
+        <ul>
+          <li>
+            <code>document-node</code> (default): a JSON representation of{" "}
+            <code>DocumentNode</code>, typed as <code>TypedDocumentNode</code>.
+          </li>
+          <li>
+            <code>string</code>: printed GraphQL source, typed as{" "}
+            <code>TypedDocumentString</code>.
+          </li>
+          <li>
+            <code>minified-string</code>: same as <code>string</code> but
+            without insignificant whitespace.
+          </li>
+        </ul>
+        <p>
+          String formats result in smaller bundles and suit clients that only
+          need the query string, such as <code>fetch</code>-based clients and{" "}
+          <code>graphql-request</code>. <code>TypedDocumentString</code> is{" "}
+          <code>string</code> combined with{" "}
+          <code>DocumentTypeDecoration</code> from{" "}
+          <code>@graphql-typed-document-node/core</code>, so result and
+          variables types can be inferred from it.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      documentFormat: minified-string`}
+        </Highlight>
 
         <h3 id="generate.type">generate.type</h3>
         <p>Set of configurations about details of generated types.</p>
@@ -369,7 +757,13 @@ export const UserType = {
       type:
         # default values
         scalarTypes: {}
-        allowUndefinedAsOptionalInput: true`}
+        scalarValidators: {}
+        allowUndefinedAsOptionalInput: true
+        fragmentMasking: false
+        throwOnFieldError: false
+        futureProofAbstractTypes: false
+        brandedScalars: []
+        enumMemberCase: keep`}
         </Highlight>
 
         <h4 id="generate.type.scalarTypes">scalarTypes</h4>
@@ -467,6 +861,224 @@ export const UserType = {
           defaults to <code>true</code>.
         </p>
 
+        <h4 id="generate.type.fragmentMasking">fragmentMasking</h4>
+        <p>
+          If this option is set to <code>true</code>, fields selected by a
+          fragment spread are not included in the result type of the parent
+          selection. Instead, the parent gets a masked reference to the
+          fragment, so a component can only read fields that its own fragment
+          asks for. This option defaults to <code>false</code>.
+        </p>
+        <p>
+          Use <code>useFragment</code> from <code>@nitrogql/core/fragment</code>{" "}
+          to read the data of a fragment from a masked reference. Each
+          generated fragment also exports a <code>Ref</code> type that can be
+          used for props of components:
+        </p>
+        <Highlight language="typescript">
+          {`import { useFragment } from "@nitrogql/core/fragment";
+import { UserCard, type UserCardRef } from "./UserCard.graphql";
+
+function UserCardView({ user }: { user: UserCardRef }) {
+  const data = useFragment(UserCard, user);
+  return <p>{data.name}</p>;
+}`}
+        </Highlight>
+        <p>
+          Masking only affects generated types. Fragment data is still present
+          in the response at runtime; <code>useFragment</code> returns the
+          given object as is.
+        </p>
+
+        <h4 id="generate.type.throwOnFieldError">throwOnFieldError</h4>
+        <p>
+          Set this option to <code>true</code> if your GraphQL client throws
+          when a field in the response has an error, instead of giving you{" "}
+          <code>null</code> for that field. This option defaults to{" "}
+          <code>false</code>.
+        </p>
+        <p>
+          With this option, positions marked by the built-in{" "}
+          <code>@semanticNonNull</code> directive are typed as non-null in
+          operation result types. Such fields are nullable in the schema only
+          because they may be <code>null</code> on errors.
+        </p>
+        <Highlight language="graphql">
+          {`type User {
+  # name is typed as string, not string | null
+  name: String @semanticNonNull
+  # level 0 is the list itself and level 1 is its items
+  friends: [User] @semanticNonNull(levels: [0, 1])
+}`}
+        </Highlight>
+        <p>
+          Types for resolvers are not affected because resolvers may still
+          return <code>null</code> for these fields.
+        </p>
+
+        <h4 id="generate.type.futureProofAbstractTypes">
+          futureProofAbstractTypes
+        </h4>
+        <p>
+          By default, the result type of a selection on a union or interface
+          is a union of one branch per object type in the schema. If the
+          server later adds another object type, your client receives a{" "}
+          <code>__typename</code> that the generated types never mention.
+        </p>
+        <p>
+          If this option is set to <code>true</code>, every such result type
+          gets an additional catch-all branch for unknown object types. Its{" "}
+          <code>__typename</code> is typed as <code>&quot;%other&quot;</code>,
+          which no GraphQL type can be named, so checking{" "}
+          <code>__typename</code> still narrows the result to known branches.
+          At runtime the value is the actual name of the unknown type. The
+          catch-all branch only has fields that every possible object type
+          has, such as fields selected directly on an interface. This option
+          defaults to <code>false</code>.
+        </p>
+        <Highlight language="typescript">
+          {`switch (content.__typename) {
+  case "Post":
+    return content.title;
+  case "Tweet":
+    return content.body;
+  default:
+    // content is of an object type unknown at build time
+    return null;
+}`}
+        </Highlight>
+
+        <h4 id="generate.type.brandedScalars">brandedScalars</h4>
+        <p>
+          List of custom scalars whose TypeScript types are branded with
+          their names. A branded type cannot be assigned from a plain value
+          of the underlying type, so IDs of different kinds are not mixed up
+          by accident. This option defaults to an empty list.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      type:
+        scalarTypes:
+          UserId: string
+        brandedScalars:
+          - UserId`}
+        </Highlight>
+        <p>
+          With the above setting, <code>UserId</code> is typed as{" "}
+          <code>
+            string &amp; {"{ readonly __brand: { readonly UserId: true } }"}
+          </code>
+          . You need a type assertion to create a value of this type.
+        </p>
+        <p>
+          To brand a single field instead of a whole scalar, use the
+          built-in <code>@brand</code> directive. It can be applied to
+          fields and input fields of scalar types. Brands add up, so a field
+          of a branded scalar with <code>@brand</code> has both brands. The
+          directive is removed from the schema emitted by{" "}
+          <code>serverGraphqlOutput</code>.
+        </p>
+        <Highlight language="graphql">
+          {`type User {
+  # email is typed as string & { readonly __brand: { readonly Email: true } }
+  email: String! @brand(name: "Email")
+}`}
+        </Highlight>
+
+        <h4 id="generate.type.scalarValidators">scalarValidators</h4>
+        <p>
+          Mapping from GraphQL scalar types to Zod validators used in{" "}
+          <Link href="#generate.validatorsOutput">
+            <code>generate.validatorsOutput</code>
+          </Link>
+          . Each value is a TypeScript expression where <code>z</code> refers
+          to Zod. Built-in scalars have default validators. Custom scalars
+          used in input types or operation variables must have validators;
+          otherwise generation fails with an error.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      type:
+        scalarTypes:
+          Date: string
+        scalarValidators:
+          Date: z.string().datetime()`}
+        </Highlight>
+        <p>
+          Values of branded scalars are checked by the given validator and
+          then typed as the branded type.
+        </p>
+
+        <h4 id="generate.type.enumStyle">enumStyle</h4>
+        <p>
+          How enums are emitted in the generated schema types. Possible values
+          are:
+        </p>
+        <ul>
+          <li>
+            <code>union</code>: a union of string literal types. No runtime
+            code is emitted.
+          </li>
+          <li>
+            <code>union-with-fallback</code>: same as <code>union</code>, but
+            operation results also accept unknown strings. This keeps your code
+            working when the server adds new enum values.
+          </li>
+          <li>
+            <code>const-object</code>: a union of string literal types, plus a
+            const object that has all values.
+          </li>
+          <li>
+            <code>enum</code>: a TypeScript <code>enum</code>. Variables and
+            resolvers must use members of the enum instead of string literals.
+          </li>
+        </ul>
+        <p>
+          If not specified, this option defaults to{" "}
+          <code>const-object</code> if{" "}
+          <a href="#generate.emitSchemaRuntime">emitSchemaRuntime</a> is{" "}
+          <code>true</code> and <code>union</code> otherwise.
+        </p>
+        <Hint>
+          ⚠️ With <code>const-object</code> or <code>enum</code>, the{" "}
+          <code>schemaOutput</code> file cannot be a <code>.d.ts</code> file.
+        </Hint>
+
+        <h4 id="generate.type.enumMemberCase">enumMemberCase</h4>
+        <p>
+          Casing of member names of TypeScript enums and keys of const
+          objects. Possible values are <code>keep</code> (default),{" "}
+          <code>pascal-case</code>, <code>camel-case</code> and{" "}
+          <code>screaming-snake-case</code>. Enum values themselves are not
+          changed.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      type:
+        enumStyle: enum
+        enumMemberCase: pascal-case`}
+        </Highlight>
+        <p>With the above setting, the generated schema code will look like:</p>
+        <Highlight language="typescript">
+          {`export enum UserType {
+  NormalUser = "NORMAL_USER",
+  PremiumUser = "PREMIUM_USER",
+}`}
+        </Highlight>
+        <p>
+          Words are split at underscores and case changes, treating a run of
+          capitals like <code>HTTP</code> in <code>HTTPServer</code> as one
+          word. Leading underscores are kept. If two values of an enum get the
+          same member name after conversion, the <code>generate</code> command
+          fails with an error.
+        </p>
+
         <h3 id="generate.name">generate.name</h3>
         <p>
           Set of configurations about names of generated variables and types.
@@ -676,6 +1288,89 @@ import { GetUserVariables } from "./app/graphql/queries/getUser.graphql";`}
           <code>@graphql-typed-document-node/core</code> package to extract the
           variables type from your operation document.
         </Hint>
+
+        <h3 id="generate.resolvers">generate.resolvers</h3>
+        <p>
+          Set of configurations about the resolvers type definition file
+          emitted to <code>generate.resolversOutput</code>. All options are
+          optional:
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      resolvers:
+        rootTypeName: Resolvers
+        outputTypeName: ResolverOutput
+        schemaNamespace: Schema
+        contextType:
+          module: "@/server/context"
+          name: Context
+        infoType:
+          module: graphql
+          name: GraphQLResolveInfo
+`}
+        </Highlight>
+        <p>
+          <code>rootTypeName</code>, <code>outputTypeName</code> and{" "}
+          <code>schemaNamespace</code> change the names of the root resolvers
+          type, the resolver output type and the namespace that schema types
+          are imported as. Defaults are <code>Resolvers</code>,{" "}
+          <code>ResolverOutput</code> and <code>Schema</code>.
+        </p>
+        <p>
+          <code>contextType</code> specifies the default type of the context
+          passed to resolvers. When set, the type argument of the root
+          resolvers type can be omitted:
+        </p>
+        <Highlight language="typescript">
+          {`import type { Resolvers } from "./generated/resolvers";
+
+// same as Resolvers<Context> with contextType set
+const resolvers: Resolvers = {
+  // ...
+};`}
+        </Highlight>
+        <p>
+          <code>infoType</code> specifies the type of the <code>info</code>{" "}
+          argument of resolvers. Default is <code>GraphQLResolveInfo</code>{" "}
+          from <code>graphql</code>. Module specifiers are emitted to the
+          generated file as is.
+        </p>
+
+        <h3 id="overrides">overrides</h3>
+        <p>
+          List of overrides of the <code>generate</code> options for operation
+          files matching given patterns. Each override has <code>files</code>{" "}
+          (pattern(s) relative to the configuration file) and{" "}
+          <code>generate</code>. The following options can be overridden:{" "}
+          <code>mode</code>, <code>schemaModuleSpecifier</code>,{" "}
+          <code>name</code> and <code>export</code>.
+        </p>
+        <p>
+          When multiple overrides match a file, the most specific one wins. A
+          pattern is more specific when it has a longer part before the first
+          wildcard. Options not set by the winning override are taken from less
+          specific overrides and then from <code>generate</code>.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      mode: with-loader-ts-5.0
+      schemaOutput: ./src/generated/schema.d.ts
+    overrides:
+      - files: ./src/legacy/**
+        generate:
+          mode: standalone-ts-4.0
+          export:
+            defaultExportForOperation: false`}
+        </Highlight>
+        <p>
+          Overrides are respected by both the <code>generate</code> command and
+          the webpack loader.
+        </p>
       </main>
     </Toc>
   );
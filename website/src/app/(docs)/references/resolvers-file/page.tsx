@@ -63,6 +63,32 @@ const resolvers: Resolvers<Context> = {
 const queryResolvers: Resolvers<Context>["Query"] = { /* ... */ };`}
         </Highlight>
 
+        <h4 id="subscriptions-and-abstract-types">
+          Subscriptions and abstract types
+        </h4>
+        <p>
+          Fields of the subscription root type have the shape that GraphQL.js
+          expects: an object with a <code>subscribe</code> function that
+          returns an <code>AsyncIterable</code>, and an optional{" "}
+          <code>resolve</code> function. If <code>resolve</code> is omitted,
+          each payload from <code>subscribe</code> must be an object that has
+          the field name as a key.
+        </p>
+        <Highlight language="typescript">
+          {`const subscriptionResolvers: Resolvers<Context>["Subscription"] = {
+  postAdded: {
+    // payloads are typed as { postAdded: ResolverOutput<"Post"> }
+    subscribe: (_parent, args) => pubsub.subscribe(\`post:\${args.authorId}\`),
+  },
+};`}
+        </Highlight>
+        <p>
+          Interfaces and unions need a <code>__resolveType</code> resolver that
+          returns one of the names of their possible types. Object types that
+          implement an interface or belong to a union may also have an
+          optional <code>__isTypeOf</code> resolver instead.
+        </p>
+
         <h4 id="resolver-output">ResolverOutput</h4>
         <p>
           By <code>ResolverOutput&lt;T&gt;</code> we mean the TypeScript type of
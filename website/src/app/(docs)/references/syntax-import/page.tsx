@@ -22,7 +22,8 @@ export default function ImportSyntax() {
         </h2>
         <p>
           The <code>#import</code> syntax is an extension to the GraphQL
-          language that allows you to import fragments from other GraphQL
+          language that allows you to import fragments and operations from other
+          GraphQL
           documents. This syntax is similar to{" "}
           <a
             href="https://the-guild.dev/graphql/tools/docs/schema-loading"
@@ -39,10 +40,10 @@ export default function ImportSyntax() {
           allows you to overcome this limitation.
         </p>
         <Hint>
-          💡 The <code>#import</code> syntax is not available in schema
-          definition language (SDL) files. This is because unlike operation
-          documents, SDL files are not scoped. All SDL files share the same,
-          global scope.
+          💡 By default, SDL files are not scoped and all SDL files share the
+          same, global scope. <code>#import</code> in SDL files is only
+          effective when you opt in to{" "}
+          <a href="#modular-schema">modular schema</a>.
         </Hint>
 
         <h3 id="usage">Usage</h3>
@@ -65,9 +66,132 @@ export default function ImportSyntax() {
           The above imports all fragments from <code>path/to/file.graphql</code>
           .
         </p>
+
+        <p>
+          The <code>check</code> command reports an imported fragment that is
+          never spread in the importing document, as well as a wildcard import
+          that provides no fragment spread in the document. Fragments used by
+          an imported fragment are imported together, so you do not need to
+          import them yourself.
+        </p>
+
+        <h3 id="importing-operations">Importing operations</h3>
+        <p>
+          Named operations can be imported in the same way as fragments.
+          Fragments used by an imported operation are imported together.
+        </p>
+        <Highlight language="graphql">{`#import BaseQuery from "./base.graphql"`}</Highlight>
+        <p>
+          An imported operation is re-exported from the importing document
+          with an <code>export ... from</code> statement, so its{" "}
+          <code>TypedDocumentNode</code> is available from the generated code
+          of both documents. The operation is re-exported by name, and its
+          result and variables types are re-exported too if they are exported
+          by the configuration. An imported operation does not affect the
+          default export of the importing document. An imported operation must
+          not have the same name as another operation in the importing
+          document.
+        </p>
+        <Highlight language="typescript">{`export { default as BaseQuery } from "./base.graphql";`}</Highlight>
+        <p>
+          The imported file is assumed to be generated with the same options as
+          the importing document. With the default options, an operation is
+          exported only as the default export of a file that has exactly one
+          operation. Importing an operation from a file with more operations is
+          an error unless{" "}
+          <code>generate.export.defaultExportForOperation</code> is{" "}
+          <code>false</code>, in which case operations are re-exported by name.
+        </p>
+        <p>
+          Only operations imported by the document itself are re-exported.
+          Operations imported by an imported file are not.
+        </p>
+        <Hint>
+          💡 The <code>*</code> wildcard only imports fragments. Operations
+          must be imported by name.
+        </Hint>
+
+        <h3 id="import-paths">Import paths</h3>
+        <p>The path in <code>#import</code> is resolved as follows:</p>
+        <ul>
+          <li>
+            Paths starting with <code>./</code> or <code>../</code> are
+            relative to the importing file.
+          </li>
+          <li>
+            Paths matching an alias in the{" "}
+            <Link href="/configuration/options#paths">
+              <code>paths</code> option
+            </Link>{" "}
+            are resolved with the alias.
+          </li>
+          <li>
+            Other paths are resolved as packages in <code>node_modules</code>,
+            in the same way as Node.js. The <code>exports</code> field of{" "}
+            <code>package.json</code> is respected, preferring the{" "}
+            <code>graphql</code> condition.
+          </li>
+        </ul>
+        <Highlight language="graphql">{`#import UserFragment from "@acme/ui-fragments/user.graphql"
+#import PostFragment from "~/fragments/post.graphql"`}</Highlight>
+        <p>
+          Imported files do not need to be included in the{" "}
+          <code>documents</code> option. Types are generated only for files
+          included in <code>documents</code>.
+        </p>
+        <Hint>
+          💡 For compatibility, a path like <code>file.graphql</code> that
+          does not name an installed package is treated as relative to the
+          importing file.
+        </Hint>
+
+        <h3 id="modular-schema">Modular schema</h3>
+        <p>
+          When the{" "}
+          <Link href="/configuration/options#schemaEntry">
+            <code>schemaEntry</code> option
+          </Link>{" "}
+          is set, schema files are loaded by following <code>#import</code>{" "}
+          from the entry file instead of the <code>schema</code> option. In
+          this mode, each schema file may only use types and directives that
+          are defined in the file or imported into it.
+        </p>
+        <Highlight language="graphql">{`# schema/index.graphql
+#import User from "./user.graphql"
+#import * from "./directives.graphql"
+
+type Query {
+  me: User @auth
+}`}</Highlight>
+        <p>
+          Import paths are resolved in the same way as in operation documents.
+          Imports are not re-exported; a file must import a type from the file
+          that defines it. Built-in scalars and directives do not need to be
+          imported.
+        </p>
+        <p>
+          Imports between schema files must not form a cycle. The error shows
+          the files that form the cycle. When types in different files refer to
+          each other, add the field that closes the cycle with{" "}
+          <code>extend type</code> in a file that imports both types.
+        </p>
+        <Highlight language="graphql">{`# schema/user-posts.graphql
+#import User from "./user.graphql"
+#import Post from "./post.graphql"
+
+extend type User {
+  posts: [Post!]!
+}`}</Highlight>
+        <p>
+          Without <code>schemaEntry</code>, schema files are loaded by the{" "}
+          <code>schema</code> option. <code>#import</code> in these files also
+          loads the imported files, even if they are not matched by the{" "}
+          <code>schema</code> option. Names are not checked in this mode.
+        </p>
+
         <p>
           The <code>#import</code> syntax can be used in the top level of an
-          operation document. If the syntax is used elsewhere, it will be a
+          operation document or a schema file. If the syntax is used elsewhere, it will be a
           syntax error.
         </p>
         <Highlight language="graphql">{`